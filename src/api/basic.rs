@@ -4,9 +4,9 @@ use log::info;
 use serde::Serialize;
 
 use crate::api::endpoints::math_endpoints::{
-    euclid_endpoint, exponentiation, modular_inverse_endpoint, shanks_endpoint,
+    euclid_endpoint, exponentiation, modular_inverse_endpoint, prng_test_endpoint, shanks_endpoint,
 };
-use crate::api::endpoints::{mv, rsa};
+use crate::api::endpoints::{bbs, dsa, el_gamal, key_exchange, mv, paillier, rabin, rsa, schnorr};
 use crate::api::serializable_models::SingleStringResponse;
 
 #[derive(Serialize)]
@@ -23,7 +23,9 @@ pub fn config_app(cfg: &mut web::ServiceConfig) {
                 .route("/decrypt", web::post().to(rsa::decrypt))
                 .route("/sign", web::post().to(rsa::sign))
                 .route("/verify", web::post().to(rsa::verify))
-                .route("/multiplication", web::post().to(rsa::multiplication)),
+                .route("/multiplication", web::post().to(rsa::multiplication))
+                .route("/exportKey", web::post().to(rsa::export_key))
+                .route("/importKey", web::post().to(rsa::import_key)),
         )
         .service(
             web::scope("/menezesVanstone")
@@ -33,12 +35,94 @@ pub fn config_app(cfg: &mut web::ServiceConfig) {
                 .route("/sign", web::post().to(mv::sign))
                 .route("/verify", web::post().to(mv::verify)),
         )
+        .service(
+            web::scope("/elGamal")
+                .route("/createKeyPair", web::post().to(el_gamal::create_key_pair))
+                .route("/encrypt", web::post().to(el_gamal::encrypt))
+                .route("/decrypt", web::post().to(el_gamal::decrypt))
+                .route("/encryptText", web::post().to(el_gamal::encrypt_text))
+                .route("/decryptText", web::post().to(el_gamal::decrypt_text))
+                .route("/sign", web::post().to(el_gamal::sign))
+                .route("/verify", web::post().to(el_gamal::verify)),
+        )
+        .service(
+            web::scope("/paillier")
+                .route("/createKeyPair", web::post().to(paillier::create_key_pair))
+                .route("/encrypt", web::post().to(paillier::encrypt))
+                .route("/decrypt", web::post().to(paillier::decrypt))
+                .route("/add", web::post().to(paillier::add))
+                .route("/scalarMultiply", web::post().to(paillier::scalar_multiply))
+                .route("/addition", web::post().to(paillier::addition)),
+        )
+        .service(
+            web::scope("/rabin")
+                .route("/createKeyPair", web::post().to(rabin::create_key_pair))
+                .route("/encrypt", web::post().to(rabin::encrypt))
+                .route("/decrypt", web::post().to(rabin::decrypt))
+                .route("/sign", web::post().to(rabin::sign))
+                .route("/verify", web::post().to(rabin::verify)),
+        )
+        .service(
+            web::scope("/bbs")
+                .route("/createKey", web::post().to(bbs::create_key))
+                .route("/encrypt", web::post().to(bbs::encrypt))
+                .route("/decrypt", web::post().to(bbs::decrypt)),
+        )
+        .service(
+            web::scope("/dsa")
+                .route("/createKeyPair", web::post().to(dsa::create_key_pair))
+                .route("/sign", web::post().to(dsa::sign))
+                .route("/verify", web::post().to(dsa::verify))
+                .route(
+                    "/verifyDomainParameters",
+                    web::post().to(dsa::verify_domain_parameters),
+                ),
+        )
+        .service(
+            web::scope("/schnorr")
+                .route("/createKeyPair", web::post().to(schnorr::create_key_pair))
+                .route("/sign", web::post().to(schnorr::sign))
+                .route("/verify", web::post().to(schnorr::verify))
+                .route("/commit", web::post().to(schnorr::commit))
+                .route("/challenge", web::post().to(schnorr::challenge))
+                .route("/respond", web::post().to(schnorr::respond))
+                .route("/verifyResponse", web::post().to(schnorr::verify_response)),
+        )
+        .service(
+            web::scope("/keyExchange")
+                .route(
+                    "/createParameters",
+                    web::post().to(key_exchange::create_parameters),
+                )
+                .route(
+                    "/createPublicValue",
+                    web::post().to(key_exchange::create_public_value),
+                )
+                .route(
+                    "/computeSharedKey",
+                    web::post().to(key_exchange::compute_shared_key),
+                )
+                .route("/createCurve", web::post().to(key_exchange::create_curve))
+                .route(
+                    "/createEcPublicValue",
+                    web::post().to(key_exchange::create_ec_public_value),
+                )
+                .route(
+                    "/computeEcSharedKey",
+                    web::post().to(key_exchange::compute_ec_shared_key),
+                )
+                .route(
+                    "/mitmSimulation",
+                    web::post().to(key_exchange::mitm_simulation),
+                ),
+        )
         .service(
             web::scope("/math")
                 .route("/exponentiation", web::post().to(exponentiation))
                 .route("/extended_euclid", web::post().to(euclid_endpoint))
                 .route("/shanks", web::post().to(shanks_endpoint))
-                .route("/modular_inverse", web::post().to(modular_inverse_endpoint)),
+                .route("/modular_inverse", web::post().to(modular_inverse_endpoint))
+                .route("/prngTest", web::post().to(prng_test_endpoint)),
         )
         .default_service(web::route().to(not_found));
 }
@@ -0,0 +1,156 @@
+use crate::api::basic::call_checked_with_parsed_big_ints;
+use crate::api::serializable_models::{
+    PrimeSearchBean, PrngBackendBean, SingleStringResponse, UseFastQuery,
+};
+use crate::encryption::core::blum_blum_shub::blum_blum_shub_scheme::BlumBlumShubScheme;
+use crate::encryption::core::blum_blum_shub::keys::BlumBlumShubKey;
+use crate::encryption::symmetric_encryption_types::{SymmetricDecryptor, SymmetricEncryptor};
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
+    Fast, Slow,
+};
+use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+use crate::shared::hex::{from_hex, to_hex};
+use actix_web::web::{Json, Query};
+use actix_web::{HttpResponse, Responder};
+use anyhow::Context;
+use bigdecimal::num_bigint::ParseBigIntError;
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+pub struct BbsCreateKeyRequestBean {
+    pub modulus_width: u32,
+    pub miller_rabin_rounds: u32,
+    pub random_seed: u32,
+    #[serde(default)]
+    pub prng_backend: PrngBackendBean,
+    #[serde(default)]
+    pub prime_search: PrimeSearchBean,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct BbsKeyBean {
+    pub modulus: String,
+    pub seed: String,
+}
+
+impl BbsKeyBean {
+    /// Wandelt den serialisierten Schlüssel in einen Schlüssel der Stromchiffre um.
+    ///
+    /// # Fehler
+    /// * `ParseBigIntError` - Falls die BigInts nicht geparst werden können.
+    fn to_key(&self) -> Result<BlumBlumShubKey, ParseBigIntError> {
+        debug!("Serialisiere Bean zu BlumBlumShubKey");
+        Ok(BlumBlumShubKey {
+            modulus: self.modulus.parse()?,
+            seed: self.seed.parse()?,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+pub struct BbsEncryptRequestBean {
+    pub plaintext: String,
+    pub key: BbsKeyBean,
+}
+
+#[derive(Deserialize)]
+pub struct BbsDecryptRequestBean {
+    /// Der Geheimtext als hexadezimale Zeichenkette.
+    pub ciphertext: String,
+    pub key: BbsKeyBean,
+}
+
+/// Erstellt einen neuen Schlüssel für die Blum-Blum-Shub-Stromchiffre.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Parameter für die Erstellung des Schlüssels enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die Modulus und Seed enthält.
+pub(crate) async fn create_key(
+    req_body: Json<BbsCreateKeyRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /bbs/createKey wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: BbsCreateKeyRequestBean = req_body.into_inner();
+    let backend = req_body.prng_backend.with_seed(req_body.random_seed);
+    let service = match query.use_fast {
+        true => NumberTheoryWithPrngService::with_backend(Fast, backend),
+        false => NumberTheoryWithPrngService::with_backend(Slow, backend),
+    }
+    .with_prime_search(req_body.prime_search.into());
+
+    match BlumBlumShubScheme::generate_key(
+        req_body.modulus_width,
+        req_body.miller_rabin_rounds,
+        &service,
+    ) {
+        Ok(key) => HttpResponse::Ok().json(BbsKeyBean {
+            modulus: key.modulus.to_str_radix(10),
+            seed: key.seed.to_str_radix(10),
+        }),
+        Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: e.to_string(),
+        }),
+    }
+}
+
+/// Verschlüsselt einen Text mit der Blum-Blum-Shub-Stromchiffre.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die den Text und den Schlüssel enthält.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die den Geheimtext als hexadezimale Zeichenkette enthält.
+pub(crate) async fn encrypt(req_body: Json<BbsEncryptRequestBean>) -> impl Responder {
+    info!("Endpunkt /bbs/encrypt wurde aufgerufen");
+    let req_body: BbsEncryptRequestBean = req_body.into_inner();
+
+    call_checked_with_parsed_big_ints(|| {
+        let key = req_body.key.to_key()?;
+
+        match BlumBlumShubScheme::encrypt(req_body.plaintext.as_bytes(), &key) {
+            Ok(ciphertext) => Ok(HttpResponse::Ok().json(SingleStringResponse {
+                message: to_hex(&ciphertext),
+            })),
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
+
+/// Entschlüsselt einen Geheimtext der Blum-Blum-Shub-Stromchiffre.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die den Geheimtext und den Schlüssel enthält.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die den Klartext enthält.
+pub(crate) async fn decrypt(req_body: Json<BbsDecryptRequestBean>) -> impl Responder {
+    info!("Endpunkt /bbs/decrypt wurde aufgerufen");
+    let req_body: BbsDecryptRequestBean = req_body.into_inner();
+
+    call_checked_with_parsed_big_ints(|| {
+        let key = req_body.key.to_key()?;
+
+        let plaintext = from_hex(&req_body.ciphertext)
+            .and_then(|ciphertext| BlumBlumShubScheme::decrypt(&ciphertext, &key))
+            .and_then(|plaintext| {
+                String::from_utf8(plaintext).context("Plaintext is not valid UTF-8")
+            });
+        match plaintext {
+            Ok(plaintext) => {
+                Ok(HttpResponse::Ok().json(SingleStringResponse { message: plaintext }))
+            }
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
@@ -0,0 +1,310 @@
+use crate::api::basic::call_checked_with_parsed_big_ints;
+use crate::api::serializable_models::{PrngBackendBean, SingleStringResponse, UseFastQuery};
+use crate::encryption::asymmetric_encryption_types::{Signer, Verifier};
+use crate::encryption::core::dsa::domain_parameters::DsaDomainParameters;
+use crate::encryption::core::dsa::dsa_scheme::{DsaScheme, DsaSignature};
+use crate::encryption::core::dsa::keys::{DsaKeyPair, DsaPrivateKey, DsaPublicKey};
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
+    Fast, Slow,
+};
+use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+use actix_web::web::{Json, Query};
+use actix_web::{HttpResponse, Responder};
+use bigdecimal::num_bigint::ParseBigIntError;
+use log::info;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+pub struct DsaCreateKeyPairRequestBean {
+    pub modulus_width: u32,
+    pub subgroup_width: u32,
+    pub miller_rabin_rounds: u32,
+    pub random_seed: u32,
+    #[serde(default)]
+    pub prng_backend: PrngBackendBean,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct DsaDomainParametersBean {
+    pub p: String,
+    pub q: String,
+    pub g: String,
+    pub domain_parameter_seed: String,
+    pub counter: u32,
+}
+
+impl From<DsaDomainParameters> for DsaDomainParametersBean {
+    fn from(params: DsaDomainParameters) -> Self {
+        DsaDomainParametersBean {
+            p: params.p.to_string(),
+            q: params.q.to_string(),
+            g: params.g.to_string(),
+            domain_parameter_seed: params.domain_parameter_seed.to_string(),
+            counter: params.counter,
+        }
+    }
+}
+
+impl TryFrom<DsaDomainParametersBean> for DsaDomainParameters {
+    type Error = ParseBigIntError;
+
+    /// Mapped die Bean in das Domain-Modell
+    fn try_from(bean: DsaDomainParametersBean) -> Result<Self, Self::Error> {
+        Ok(DsaDomainParameters {
+            p: bean.p.parse()?,
+            q: bean.q.parse()?,
+            g: bean.g.parse()?,
+            domain_parameter_seed: bean.domain_parameter_seed.parse()?,
+            counter: bean.counter,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct DsaPublicKeyBean {
+    pub domain_parameters: DsaDomainParametersBean,
+    pub y: String,
+}
+
+impl From<DsaPublicKey> for DsaPublicKeyBean {
+    fn from(key: DsaPublicKey) -> Self {
+        DsaPublicKeyBean {
+            domain_parameters: key.domain_parameters.into(),
+            y: key.y.to_string(),
+        }
+    }
+}
+
+impl TryFrom<DsaPublicKeyBean> for DsaPublicKey {
+    type Error = ParseBigIntError;
+
+    /// Mapped die Bean in das Domain-Modell
+    fn try_from(bean: DsaPublicKeyBean) -> Result<Self, Self::Error> {
+        Ok(DsaPublicKey {
+            domain_parameters: bean.domain_parameters.try_into()?,
+            y: bean.y.parse()?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct DsaPrivateKeyBean {
+    pub domain_parameters: DsaDomainParametersBean,
+    pub x: String,
+}
+
+impl From<DsaPrivateKey> for DsaPrivateKeyBean {
+    fn from(key: DsaPrivateKey) -> Self {
+        DsaPrivateKeyBean {
+            domain_parameters: key.domain_parameters.into(),
+            x: key.x.to_string(),
+        }
+    }
+}
+
+impl TryFrom<DsaPrivateKeyBean> for DsaPrivateKey {
+    type Error = ParseBigIntError;
+
+    /// Mapped die Bean in das Domain-Modell
+    fn try_from(bean: DsaPrivateKeyBean) -> Result<Self, Self::Error> {
+        Ok(DsaPrivateKey {
+            domain_parameters: bean.domain_parameters.try_into()?,
+            x: bean.x.parse()?,
+        })
+    }
+}
+
+#[derive(Serialize)]
+pub struct DsaKeyPairBean {
+    pub public_key: DsaPublicKeyBean,
+    pub private_key: DsaPrivateKeyBean,
+}
+
+impl From<DsaKeyPair> for DsaKeyPairBean {
+    fn from(key_pair: DsaKeyPair) -> Self {
+        DsaKeyPairBean {
+            public_key: key_pair.public_key.into(),
+            private_key: key_pair.private_key.into(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct DsaSignatureBean {
+    pub r: String,
+    pub s: String,
+}
+
+impl From<DsaSignature> for DsaSignatureBean {
+    fn from(signature: DsaSignature) -> Self {
+        DsaSignatureBean {
+            r: signature.r.to_string(),
+            s: signature.s.to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct DsaSignRequestBean {
+    pub private_key: DsaPrivateKeyBean,
+    pub message: String,
+    pub random_seed: u32,
+    #[serde(default)]
+    pub prng_backend: PrngBackendBean,
+}
+
+#[derive(Deserialize)]
+pub struct DsaVerifyRequestBean {
+    pub public_key: DsaPublicKeyBean,
+    pub message: String,
+    pub signature: DsaSignatureBean,
+}
+
+#[derive(Deserialize)]
+pub struct DsaVerifyDomainParametersRequestBean {
+    pub domain_parameters: DsaDomainParametersBean,
+    pub miller_rabin_rounds: u32,
+}
+
+/// Erstellt neue Domain-Parameter nach FIPS 186-4 und ein Schlüsselpaar für DSA.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Bit-Breiten von `p` und `q` enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die das Schlüsselpaar inklusive Domain-Parametern enthält.
+pub(crate) async fn create_key_pair(
+    req_body: Json<DsaCreateKeyPairRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /dsa/createKeyPair wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: DsaCreateKeyPairRequestBean = req_body.into_inner();
+    let backend = req_body.prng_backend.with_seed(req_body.random_seed);
+    let service = match query.use_fast {
+        true => NumberTheoryWithPrngService::with_backend(Fast, backend),
+        false => NumberTheoryWithPrngService::with_backend(Slow, backend),
+    };
+
+    match DsaScheme::generate_keypair(
+        req_body.modulus_width,
+        req_body.subgroup_width,
+        req_body.miller_rabin_rounds,
+        &service,
+    ) {
+        Ok(key_pair) => HttpResponse::Ok().json(DsaKeyPairBean::from(key_pair)),
+        Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: e.to_string(),
+        }),
+    }
+}
+
+/// Signiert eine Nachricht mit DSA.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Nachricht und den privaten Schlüssel enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die die Signatur enthält.
+pub(crate) async fn sign(
+    req_body: Json<DsaSignRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /dsa/sign wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: DsaSignRequestBean = req_body.into_inner();
+    let backend = req_body.prng_backend.with_seed(req_body.random_seed);
+    let service = match query.use_fast {
+        true => NumberTheoryWithPrngService::with_backend(Fast, backend),
+        false => NumberTheoryWithPrngService::with_backend(Slow, backend),
+    };
+
+    call_checked_with_parsed_big_ints(|| {
+        let private_key = DsaPrivateKey::try_from(req_body.private_key.clone())?;
+        // Ohne Untergruppe primer Ordnung findet sich kein gültiges k.
+        if let Err(e) = private_key.domain_parameters.check_subgroup(20, &service) {
+            return Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            }));
+        }
+
+        let signature = DsaScheme::sign(&private_key, &req_body.message, &service);
+        Ok(HttpResponse::Ok().json(DsaSignatureBean::from(signature)))
+    })
+}
+
+/// Überprüft eine DSA-Signatur.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Nachricht, die Signatur und den öffentlichen Schlüssel enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, ob die Signatur gültig ist.
+pub(crate) async fn verify(
+    req_body: Json<DsaVerifyRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /dsa/verify wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: DsaVerifyRequestBean = req_body.into_inner();
+    // Hier wird ein Dummy-Seed verwendet, weil die Verifikation keine Zufallszahlen benötigt.
+    let service = match query.use_fast {
+        true => NumberTheoryWithPrngService::new(Fast, 13),
+        false => NumberTheoryWithPrngService::new(Slow, 13),
+    };
+
+    call_checked_with_parsed_big_ints(|| {
+        let public_key = DsaPublicKey::try_from(req_body.public_key.clone())?;
+        let signature = DsaSignature {
+            r: req_body.signature.r.parse()?,
+            s: req_body.signature.s.parse()?,
+        };
+
+        let is_valid = DsaScheme::verify(&public_key, &signature, &req_body.message, &service);
+        Ok(HttpResponse::Ok().json(SingleStringResponse {
+            message: is_valid.to_string(),
+        }))
+    })
+}
+
+/// Überprüft, ob Domain-Parameter verifizierbar aus ihrem Seed und Zähler erzeugt wurden.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Domain-Parameter enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, ob die Domain-Parameter gültig sind.
+pub(crate) async fn verify_domain_parameters(
+    req_body: Json<DsaVerifyDomainParametersRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /dsa/verifyDomainParameters wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: DsaVerifyDomainParametersRequestBean = req_body.into_inner();
+    // Der Seed wird nur für die Zeugen des Miller-Rabin-Tests verwendet.
+    let service = match query.use_fast {
+        true => NumberTheoryWithPrngService::new(Fast, 13),
+        false => NumberTheoryWithPrngService::new(Slow, 13),
+    };
+
+    call_checked_with_parsed_big_ints(|| {
+        let domain_parameters = DsaDomainParameters::try_from(req_body.domain_parameters.clone())?;
+
+        let is_valid = domain_parameters.verify(req_body.miller_rabin_rounds, &service);
+        Ok(HttpResponse::Ok().json(SingleStringResponse {
+            message: is_valid.to_string(),
+        }))
+    })
+}
@@ -0,0 +1,439 @@
+use actix_web::web::{Json, Query};
+use actix_web::{HttpResponse, Responder};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::Zero;
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+use crate::api::basic::call_checked_with_parsed_big_ints;
+use crate::api::serializable_models::{PrngBackendBean, SingleStringResponse, UseFastQuery};
+use crate::encryption::asymmetric_encryption_types::{
+    AsymmetricDecryptor, AsymmetricEncryptor, KeyGenerator, Signer, Verifier,
+};
+use crate::encryption::core::el_gamal::el_gamal_scheme::{
+    ElGamalKeyGenConfig, ElGamalScheme, ElGamalSignature,
+};
+use crate::encryption::core::el_gamal::keys::{
+    ElGamalKeyPair, ElGamalPrivateKey, ElGamalPublicKey,
+};
+use crate::encryption::string_schemes::el_gamal::el_gamal_string_scheme::ElGamalStringScheme;
+use crate::encryption::string_schemes::el_gamal::keys::{
+    ElGamalStringPrivateKey, ElGamalStringPublicKey,
+};
+use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
+    Fast, Slow,
+};
+use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+use crate::math_core::random_source::PrngRandomSource;
+
+#[derive(Deserialize, Clone)]
+pub struct ElGamalCreateKeyPairRequestBean {
+    pub modulus_width: u32,
+    pub miller_rabin_rounds: u32,
+    pub random_seed: u32,
+    #[serde(default)]
+    pub prng_backend: PrngBackendBean,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ElGamalPublicKeyBean {
+    pub p: String,
+    pub g: String,
+    pub y: String,
+}
+
+impl From<ElGamalPublicKey> for ElGamalPublicKeyBean {
+    fn from(key: ElGamalPublicKey) -> Self {
+        ElGamalPublicKeyBean {
+            p: key.p.to_string(),
+            g: key.g.to_string(),
+            y: key.y.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ElGamalPrivateKeyBean {
+    pub p: String,
+    pub g: String,
+    pub x: String,
+}
+
+impl From<ElGamalPrivateKey> for ElGamalPrivateKeyBean {
+    fn from(key: ElGamalPrivateKey) -> Self {
+        ElGamalPrivateKeyBean {
+            p: key.p.to_string(),
+            g: key.g.to_string(),
+            x: key.x.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Clone)]
+pub struct ElGamalKeyPairBean {
+    pub public_key: ElGamalPublicKeyBean,
+    pub private_key: ElGamalPrivateKeyBean,
+}
+
+impl From<ElGamalKeyPair> for ElGamalKeyPairBean {
+    fn from(key_pair: ElGamalKeyPair) -> Self {
+        ElGamalKeyPairBean {
+            public_key: ElGamalPublicKeyBean::from(key_pair.public_key),
+            private_key: ElGamalPrivateKeyBean::from(key_pair.private_key),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct ElGamalEncryptRequestBean {
+    pub public_key: ElGamalPublicKeyBean,
+    pub message: String,
+    pub random_seed: u32,
+    #[serde(default)]
+    pub prng_backend: PrngBackendBean,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ElGamalCipherTextBean {
+    pub a: String,
+    pub b: String,
+}
+
+#[derive(Deserialize)]
+pub struct ElGamalDecryptRequestBean {
+    pub private_key: ElGamalPrivateKeyBean,
+    pub cipher_text: ElGamalCipherTextBean,
+}
+
+#[derive(Deserialize)]
+pub struct ElGamalEncryptTextRequestBean {
+    pub public_key: ElGamalPublicKeyBean,
+    pub message: String,
+    pub radix: u32,
+    pub random_seed: u32,
+    #[serde(default)]
+    pub prng_backend: PrngBackendBean,
+}
+
+#[derive(Deserialize)]
+pub struct ElGamalDecryptTextRequestBean {
+    pub private_key: ElGamalPrivateKeyBean,
+    pub cipher_text: String,
+    pub radix: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ElGamalSignatureBean {
+    pub r: String,
+    pub s: String,
+}
+
+impl From<ElGamalSignature> for ElGamalSignatureBean {
+    fn from(signature: ElGamalSignature) -> Self {
+        ElGamalSignatureBean {
+            r: signature.r.to_string(),
+            s: signature.s.to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct ElGamalSignRequestBean {
+    pub private_key: ElGamalPrivateKeyBean,
+    pub message: String,
+    pub random_seed: u32,
+    #[serde(default)]
+    pub prng_backend: PrngBackendBean,
+}
+
+#[derive(Deserialize)]
+pub struct ElGamalVerifyRequestBean {
+    pub public_key: ElGamalPublicKeyBean,
+    pub message: String,
+    pub signature: ElGamalSignatureBean,
+}
+
+/// Erstellt ein neues Schlüsselpaar für das ElGamal-Kryptosystem in primen Restklassengruppen.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die die Parameter für die Erstellung des Schlüsselpaares enthält.
+///
+/// # Returns
+/// * `HttpResponse` - Die Antwort, die das Schlüsselpaar enthält.
+pub(crate) async fn create_key_pair(
+    req_body: Json<ElGamalCreateKeyPairRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /elGamal/createKeyPair wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: ElGamalCreateKeyPairRequestBean = req_body.into_inner();
+
+    let number_theory_service = match query.use_fast {
+        true => NumberTheoryService::new(Fast),
+        false => NumberTheoryService::new(Slow),
+    };
+
+    let config = ElGamalKeyGenConfig {
+        modulus_width: req_body.modulus_width,
+        miller_rabin_iterations: req_body.miller_rabin_rounds,
+        random_seed: req_body.random_seed,
+        number_theory_service,
+    };
+
+    let random = PrngRandomSource::new(PseudoRandomNumberGenerator::with_backend(
+        req_body.prng_backend.with_seed(req_body.random_seed),
+    ));
+    let key_pair = match ElGamalScheme::generate_keypair_with_source(&config, &random) {
+        Ok(key_pair) => key_pair,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })
+        }
+    };
+    HttpResponse::Ok().json(ElGamalKeyPairBean::from(key_pair))
+}
+
+/// Verschlüsselt eine Zahl mit dem ElGamal-Kryptosystem.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die die Nachricht und den öffentlichen Schlüssel enthält.
+///
+/// # Returns
+/// * `HttpResponse` - Die Antwort, die das Chiffretext-Tupel `(a, b)` enthält.
+pub(crate) async fn encrypt(
+    req_body: Json<ElGamalEncryptRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /elGamal/encrypt wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: ElGamalEncryptRequestBean = req_body.into_inner();
+
+    call_checked_with_parsed_big_ints(|| {
+        let public_key = ElGamalPublicKey::try_from(req_body.public_key.clone())?;
+        let message = BigInt::from_str(&req_body.message)?;
+
+        // Die Nachricht muss ein Element aus Z_p sein, sonst geht sie bei der Reduktion verloren.
+        if message < BigInt::zero() || message >= public_key.p {
+            return Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: format!(
+                    "Message {} must be between 0 and p - 1 = {}",
+                    message,
+                    &public_key.p - 1
+                ),
+            }));
+        }
+
+        let backend = req_body.prng_backend.with_seed(req_body.random_seed);
+        let service = match query.use_fast {
+            true => NumberTheoryWithPrngService::with_backend(Fast, backend),
+            false => NumberTheoryWithPrngService::with_backend(Slow, backend),
+        };
+
+        let (a, b) = ElGamalScheme::encrypt(&public_key, &message, &service);
+        let response = ElGamalCipherTextBean {
+            a: a.to_string(),
+            b: b.to_string(),
+        };
+        Ok(HttpResponse::Ok().json(response))
+    })
+}
+
+/// Entschlüsselt ein Chiffretext-Tupel mit dem ElGamal-Kryptosystem.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die das Chiffretext-Tupel und den privaten Schlüssel enthält.
+///
+/// # Returns
+/// * `HttpResponse` - Die Antwort, die die entschlüsselte Zahl enthält.
+pub(crate) async fn decrypt(
+    req_body: Json<ElGamalDecryptRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /elGamal/decrypt wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: ElGamalDecryptRequestBean = req_body.into_inner();
+
+    call_checked_with_parsed_big_ints(|| {
+        let private_key = ElGamalPrivateKey::try_from(req_body.private_key.clone())?;
+        let ciphertext = (
+            BigInt::from_str(&req_body.cipher_text.a)?,
+            BigInt::from_str(&req_body.cipher_text.b)?,
+        );
+
+        // Hier wird ein Dummy-Seed verwendet, weil für die Entschlüsselung kein Zufall nötig ist.
+        let service = match query.use_fast {
+            true => NumberTheoryWithPrngService::new(Fast, 13),
+            false => NumberTheoryWithPrngService::new(Slow, 13),
+        };
+
+        let plaintext = ElGamalScheme::decrypt(&private_key, &ciphertext, &service);
+        let response = SingleStringResponse {
+            message: plaintext.to_string(),
+        };
+        Ok(HttpResponse::Ok().json(response))
+    })
+}
+
+/// Verschlüsselt einen Text blockweise mit dem ElGamal-Kryptosystem.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die den Text, die Basis und den öffentlichen Schlüssel enthält.
+///
+/// # Returns
+/// * `HttpResponse` - Die Antwort, die die verschlüsselten Paare `(a, b)` als Text enthält.
+pub(crate) async fn encrypt_text(
+    req_body: Json<ElGamalEncryptTextRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /elGamal/encryptText wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: ElGamalEncryptTextRequestBean = req_body.into_inner();
+
+    call_checked_with_parsed_big_ints(|| {
+        let public_key = ElGamalStringPublicKey {
+            el_gamal_key: ElGamalPublicKey::try_from(req_body.public_key.clone())?,
+            radix: req_body.radix,
+        };
+
+        let backend = req_body.prng_backend.with_seed(req_body.random_seed);
+        let service = match query.use_fast {
+            true => NumberTheoryWithPrngService::with_backend(Fast, backend),
+            false => NumberTheoryWithPrngService::with_backend(Slow, backend),
+        };
+
+        match ElGamalStringScheme::encrypt(&public_key, &req_body.message, &service) {
+            Ok(ciphertext) => Ok(HttpResponse::Ok().json(SingleStringResponse {
+                message: ciphertext,
+            })),
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
+
+/// Entschlüsselt einen mit `/elGamal/encryptText` verschlüsselten Text.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die den Chiffretext, die Basis und den privaten Schlüssel enthält.
+///
+/// # Returns
+/// * `HttpResponse` - Die Antwort, die den entschlüsselten Text enthält.
+pub(crate) async fn decrypt_text(
+    req_body: Json<ElGamalDecryptTextRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /elGamal/decryptText wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: ElGamalDecryptTextRequestBean = req_body.into_inner();
+
+    call_checked_with_parsed_big_ints(|| {
+        let private_key = ElGamalStringPrivateKey {
+            el_gamal_key: ElGamalPrivateKey::try_from(req_body.private_key.clone())?,
+            radix: req_body.radix,
+        };
+
+        // Hier wird ein Dummy-Seed verwendet, weil für die Entschlüsselung kein Zufall nötig ist.
+        let service = match query.use_fast {
+            true => NumberTheoryWithPrngService::new(Fast, 13),
+            false => NumberTheoryWithPrngService::new(Slow, 13),
+        };
+
+        match ElGamalStringScheme::decrypt(&private_key, &req_body.cipher_text, &service) {
+            Ok(plaintext) => {
+                Ok(HttpResponse::Ok().json(SingleStringResponse { message: plaintext }))
+            }
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
+
+/// Signiert eine Nachricht mit dem ElGamal-Signaturverfahren.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die die Nachricht und den privaten Schlüssel enthält.
+///
+/// # Returns
+/// * `HttpResponse` - Die Antwort, die die Signatur `(r, s)` enthält.
+pub(crate) async fn sign(
+    req_body: Json<ElGamalSignRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /elGamal/sign wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: ElGamalSignRequestBean = req_body.into_inner();
+
+    call_checked_with_parsed_big_ints(|| {
+        let private_key = ElGamalPrivateKey::try_from(req_body.private_key.clone())?;
+        // k wird aus [1, p - 2] gewählt, der Bereich darf nicht leer sein.
+        if private_key.p < BigInt::from(3) {
+            return Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: format!("Modulus p = {} must be at least 3", private_key.p),
+            }));
+        }
+
+        let backend = req_body.prng_backend.with_seed(req_body.random_seed);
+        let service = match query.use_fast {
+            true => NumberTheoryWithPrngService::with_backend(Fast, backend),
+            false => NumberTheoryWithPrngService::with_backend(Slow, backend),
+        };
+
+        let signature = ElGamalScheme::sign(&private_key, &req_body.message, &service);
+        Ok(HttpResponse::Ok().json(ElGamalSignatureBean::from(signature)))
+    })
+}
+
+/// Überprüft eine ElGamal-Signatur.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die die Nachricht, die Signatur und den öffentlichen Schlüssel enthält.
+///
+/// # Returns
+/// * `HttpResponse` - Die Antwort, ob die Signatur gültig ist.
+pub(crate) async fn verify(
+    req_body: Json<ElGamalVerifyRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /elGamal/verify wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: ElGamalVerifyRequestBean = req_body.into_inner();
+
+    call_checked_with_parsed_big_ints(|| {
+        let public_key = ElGamalPublicKey::try_from(req_body.public_key.clone())?;
+        let signature = ElGamalSignature {
+            r: BigInt::from_str(&req_body.signature.r)?,
+            s: BigInt::from_str(&req_body.signature.s)?,
+        };
+
+        // Hier wird ein Dummy-Seed verwendet, weil für die Verifikation kein Zufall nötig ist.
+        let service = match query.use_fast {
+            true => NumberTheoryWithPrngService::new(Fast, 13),
+            false => NumberTheoryWithPrngService::new(Slow, 13),
+        };
+
+        let is_valid = ElGamalScheme::verify(&public_key, &signature, &req_body.message, &service);
+        Ok(HttpResponse::Ok().json(SingleStringResponse {
+            message: is_valid.to_string(),
+        }))
+    })
+}
@@ -0,0 +1,625 @@
+use crate::api::basic::call_checked_with_parsed_big_ints;
+use crate::api::endpoints::mv::{EcPointBean, EllipticCurveBean};
+use crate::api::serializable_models::{
+    PrimeSearchBean, PrngBackendBean, SingleStringResponse, UseFastQuery,
+};
+use crate::encryption::asymmetric_encryption_types::KeyGenerator;
+use crate::encryption::core::key_exchange::diffie_hellman::{
+    DiffieHellmanParameters, DiffieHellmanScheme,
+};
+use crate::encryption::core::key_exchange::elliptic_curve_diffie_hellman::EcdhScheme;
+use crate::encryption::core::key_exchange::man_in_the_middle::{
+    simulate_station_to_station, simulate_unauthenticated, KeyAgreement, SignatureKeys,
+    SimulationResult, TranscriptEntry,
+};
+use crate::encryption::core::menezes_vanstone::menezes_vanstone_scheme::MenezesVanstoneScheme;
+use crate::encryption::core::rsa::rsa_scheme::{RsaKeyGenConfig, RsaScheme};
+use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
+use crate::math_core::ecc::secure_finite_field_elliptic_curve::SecureFiniteFieldEllipticCurve;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
+    Fast, Slow,
+};
+use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+use crate::shared::hex::to_hex;
+use actix_web::web::{Json, Query};
+use actix_web::{HttpResponse, Responder};
+use bigdecimal::num_bigint::{BigInt, ParseBigIntError};
+use log::info;
+use serde::{Deserialize, Serialize};
+
+/// Die größte Schlüssellänge in Bytes, die pro Anfrage abgeleitet wird.
+const MAX_KEY_LENGTH: usize = 1024;
+
+#[derive(Deserialize)]
+pub struct DhCreateParametersRequestBean {
+    pub modulus_width: u32,
+    pub miller_rabin_rounds: u32,
+    pub random_seed: u32,
+    #[serde(default)]
+    pub prng_backend: PrngBackendBean,
+    #[serde(default)]
+    pub prime_search: PrimeSearchBean,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct DhParametersBean {
+    pub p: String,
+    pub g: String,
+}
+
+impl From<DiffieHellmanParameters> for DhParametersBean {
+    fn from(parameters: DiffieHellmanParameters) -> Self {
+        DhParametersBean {
+            p: parameters.p.to_string(),
+            g: parameters.g.to_string(),
+        }
+    }
+}
+
+impl TryFrom<DhParametersBean> for DiffieHellmanParameters {
+    type Error = ParseBigIntError;
+
+    /// Mapped die Bean in das Domain-Modell
+    fn try_from(bean: DhParametersBean) -> Result<Self, Self::Error> {
+        Ok(DiffieHellmanParameters {
+            p: bean.p.parse()?,
+            g: bean.g.parse()?,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+pub struct DhCreatePublicValueRequestBean {
+    pub parameters: DhParametersBean,
+    pub random_seed: u32,
+    #[serde(default)]
+    pub prng_backend: PrngBackendBean,
+}
+
+#[derive(Serialize)]
+pub struct DhPublicValueBean {
+    pub private_value: String,
+    pub public_value: String,
+}
+
+#[derive(Deserialize)]
+pub struct DhComputeSharedKeyRequestBean {
+    pub parameters: DhParametersBean,
+    pub private_value: String,
+    pub other_public_value: String,
+    /// Die Länge des abzuleitenden Schlüssels in Bytes.
+    pub key_length: usize,
+    /// Optionale Kontextinformationen für die Schlüsselableitung.
+    #[serde(default)]
+    pub info: String,
+}
+
+#[derive(Serialize)]
+pub struct DhSharedKeyBean {
+    pub shared_secret: String,
+    /// Der abgeleitete Schlüssel als Hex-String.
+    pub key: String,
+}
+
+#[derive(Deserialize)]
+pub struct EcdhCreateCurveRequestBean {
+    pub modulus_width: u32,
+    pub miller_rabin_rounds: u32,
+    pub coef_a: i32,
+    pub random_seed: u32,
+    #[serde(default)]
+    pub prng_backend: PrngBackendBean,
+    #[serde(default)]
+    pub prime_search: PrimeSearchBean,
+}
+
+#[derive(Deserialize)]
+pub struct EcdhCreatePublicValueRequestBean {
+    pub curve: EllipticCurveBean,
+    pub random_seed: u32,
+    #[serde(default)]
+    pub prng_backend: PrngBackendBean,
+}
+
+#[derive(Serialize)]
+pub struct EcdhPublicValueBean {
+    pub private_value: String,
+    pub public_value: EcPointBean,
+}
+
+#[derive(Deserialize)]
+pub struct EcdhComputeSharedKeyRequestBean {
+    pub curve: EllipticCurveBean,
+    pub private_value: String,
+    pub other_public_value: EcPointBean,
+    /// Die Länge des abzuleitenden Schlüssels in Bytes.
+    pub key_length: usize,
+    /// Optionale Kontextinformationen für die Schlüsselableitung.
+    #[serde(default)]
+    pub info: String,
+}
+
+#[derive(Serialize)]
+pub struct EcdhSharedKeyBean {
+    pub shared_secret: EcPointBean,
+    /// Der abgeleitete Schlüssel als Hex-String.
+    pub key: String,
+}
+
+/// Die Gruppe, über der die Man-in-the-Middle-Simulation läuft.
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MitmGroupParametersBean {
+    /// Klassischer Diffie-Hellman in `Z_p^*`.
+    FiniteField { modulus_width: u32 },
+    /// ECDH auf einer sicheren elliptischen Kurve `y^2 = x^3 - n^2 x`.
+    EllipticCurve { modulus_width: u32, coef_a: i64 },
+}
+
+/// Das Signaturverfahren für die langfristigen Schlüssel im Station-to-Station-Protokoll.
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MitmSignatureSchemeBean {
+    Rsa { key_size: u32 },
+    MenezesVanstone { modulus_width: u32, coef_a: i32 },
+}
+
+#[derive(Deserialize)]
+pub struct MitmSimulationRequestBean {
+    pub group: MitmGroupParametersBean,
+    pub signature_scheme: MitmSignatureSchemeBean,
+    pub miller_rabin_rounds: u32,
+    pub random_seed: u32,
+}
+
+#[derive(Serialize)]
+pub struct TranscriptEntryBean {
+    pub sender: String,
+    /// Der Empfänger, `None` bei lokalen Berechnungen.
+    pub receiver: Option<String>,
+    pub description: String,
+    pub value: String,
+}
+
+impl From<TranscriptEntry> for TranscriptEntryBean {
+    fn from(entry: TranscriptEntry) -> Self {
+        TranscriptEntryBean {
+            sender: entry.sender.to_string(),
+            receiver: entry.receiver.map(|party| party.to_string()),
+            description: entry.description,
+            value: entry.value,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct SimulationResultBean {
+    pub transcript: Vec<TranscriptEntryBean>,
+    /// Die Schlüssel als Hex-Strings, `None` falls die Partei keinen Schlüssel hat.
+    pub alice_key: Option<String>,
+    pub bob_key: Option<String>,
+    pub eve_key_with_alice: Option<String>,
+    pub eve_key_with_bob: Option<String>,
+    pub attack_successful: bool,
+    pub attack_detected: bool,
+}
+
+impl From<SimulationResult> for SimulationResultBean {
+    fn from(result: SimulationResult) -> Self {
+        let attack_successful = result.attack_successful();
+        let hex = |key: Option<Vec<u8>>| key.map(|key| to_hex(&key));
+        SimulationResultBean {
+            transcript: result.transcript.into_iter().map(Into::into).collect(),
+            alice_key: hex(result.alice_key),
+            bob_key: hex(result.bob_key),
+            eve_key_with_alice: hex(result.eve_key_with_alice),
+            eve_key_with_bob: hex(result.eve_key_with_bob),
+            attack_successful,
+            attack_detected: result.attack_detected,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct MitmSimulationResponseBean {
+    /// Unauthentisierter Austausch mit Eve.
+    pub unauthenticated_attack: SimulationResultBean,
+    /// Station-to-Station mit Eve.
+    pub station_to_station_attack: SimulationResultBean,
+    /// Station-to-Station ohne Eve.
+    pub station_to_station_honest: SimulationResultBean,
+}
+
+/// Erstellt die öffentlichen Parameter `(p, g)` für den Diffie-Hellman-Schlüsselaustausch.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Bit-Breite von `p` enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die die Parameter enthält.
+pub(crate) async fn create_parameters(
+    req_body: Json<DhCreateParametersRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /keyExchange/createParameters wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: DhCreateParametersRequestBean = req_body.into_inner();
+    let backend = req_body.prng_backend.with_seed(req_body.random_seed);
+    let service = match query.use_fast {
+        true => NumberTheoryWithPrngService::with_backend(Fast, backend),
+        false => NumberTheoryWithPrngService::with_backend(Slow, backend),
+    }
+    .with_prime_search(req_body.prime_search.into());
+
+    let parameters = DiffieHellmanScheme::generate_parameters(
+        req_body.modulus_width,
+        req_body.miller_rabin_rounds,
+        &service,
+    );
+    HttpResponse::Ok().json(DhParametersBean::from(parameters))
+}
+
+/// Erzeugt den geheimen Exponenten und den öffentlichen Wert einer Partei.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Parameter enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die den geheimen und den öffentlichen Wert enthält.
+pub(crate) async fn create_public_value(
+    req_body: Json<DhCreatePublicValueRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /keyExchange/createPublicValue wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: DhCreatePublicValueRequestBean = req_body.into_inner();
+    let backend = req_body.prng_backend.with_seed(req_body.random_seed);
+    let service = match query.use_fast {
+        true => NumberTheoryWithPrngService::with_backend(Fast, backend),
+        false => NumberTheoryWithPrngService::with_backend(Slow, backend),
+    };
+
+    call_checked_with_parsed_big_ints(|| {
+        let parameters = DiffieHellmanParameters::try_from(req_body.parameters.clone())?;
+
+        let key_pair = DiffieHellmanScheme::generate_keypair(&parameters, &service);
+        Ok(HttpResponse::Ok().json(DhPublicValueBean {
+            private_value: key_pair.private_value.to_string(),
+            public_value: key_pair.public_value.to_string(),
+        }))
+    })
+}
+
+/// Berechnet das gemeinsame Geheimnis aus dem eigenen geheimen Exponenten und dem öffentlichen
+/// Wert der Gegenseite und leitet daraus einen Schlüssel ab.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Parameter und beide Werte enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die das Geheimnis und den Schlüssel enthält.
+pub(crate) async fn compute_shared_key(
+    req_body: Json<DhComputeSharedKeyRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /keyExchange/computeSharedKey wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: DhComputeSharedKeyRequestBean = req_body.into_inner();
+    if !(1..=MAX_KEY_LENGTH).contains(&req_body.key_length) {
+        return HttpResponse::BadRequest().json(SingleStringResponse {
+            message: format!("key_length must be between 1 and {}", MAX_KEY_LENGTH),
+        });
+    }
+    // Hier wird ein Dummy-Seed verwendet, weil keine Zufallszahlen benötigt werden.
+    let service = match query.use_fast {
+        true => NumberTheoryWithPrngService::new(Fast, 13),
+        false => NumberTheoryWithPrngService::new(Slow, 13),
+    };
+
+    call_checked_with_parsed_big_ints(|| {
+        let parameters = DiffieHellmanParameters::try_from(req_body.parameters.clone())?;
+        let private_value: BigInt = req_body.private_value.parse()?;
+        let other_public_value: BigInt = req_body.other_public_value.parse()?;
+
+        let result = DiffieHellmanScheme::compute_shared_secret(
+            &parameters,
+            &private_value,
+            &other_public_value,
+            &service,
+        )
+        .and_then(|shared_secret| {
+            let key = DiffieHellmanScheme::derive_key(
+                &parameters,
+                &shared_secret,
+                req_body.info.as_bytes(),
+                req_body.key_length,
+            )?;
+            Ok(DhSharedKeyBean {
+                shared_secret: shared_secret.to_string(),
+                key: to_hex(&key),
+            })
+        });
+
+        match result {
+            Ok(response) => Ok(HttpResponse::Ok().json(response)),
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
+
+/// Erstellt eine sichere elliptische Kurve für ECDH.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Parameter der Kurve enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die die Kurve enthält.
+pub(crate) async fn create_curve(
+    req_body: Json<EcdhCreateCurveRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /keyExchange/createCurve wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: EcdhCreateCurveRequestBean = req_body.into_inner();
+    let backend = req_body.prng_backend.with_seed(req_body.random_seed);
+    let service = match query.use_fast {
+        true => NumberTheoryWithPrngService::with_backend(Fast, backend),
+        false => NumberTheoryWithPrngService::with_backend(Slow, backend),
+    }
+    .with_prime_search(req_body.prime_search.into());
+
+    match SecureFiniteFieldEllipticCurve::new(
+        req_body.coef_a.into(),
+        req_body.modulus_width,
+        req_body.miller_rabin_rounds,
+        &service,
+    ) {
+        Ok(curve) => HttpResponse::Ok().json(EllipticCurveBean::from(curve)),
+        Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: e.to_string(),
+        }),
+    }
+}
+
+/// Erzeugt den geheimen Skalar und den öffentlichen Punkt einer Partei für ECDH.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Kurve enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die den geheimen Skalar und den öffentlichen Punkt enthält.
+pub(crate) async fn create_ec_public_value(
+    req_body: Json<EcdhCreatePublicValueRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /keyExchange/createEcPublicValue wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: EcdhCreatePublicValueRequestBean = req_body.into_inner();
+    let backend = req_body.prng_backend.with_seed(req_body.random_seed);
+    let service = match query.use_fast {
+        true => NumberTheoryWithPrngService::with_backend(Fast, backend),
+        false => NumberTheoryWithPrngService::with_backend(Slow, backend),
+    };
+    let curve = SecureFiniteFieldEllipticCurve::from(req_body.curve);
+
+    match EcdhScheme::generate_keypair(&curve, &service) {
+        Ok(key_pair) => HttpResponse::Ok().json(EcdhPublicValueBean {
+            private_value: key_pair.private_value.to_string(),
+            public_value: key_pair.public_value.into(),
+        }),
+        Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: e.to_string(),
+        }),
+    }
+}
+
+/// Berechnet den gemeinsamen Punkt aus dem eigenen geheimen Skalar und dem öffentlichen Punkt
+/// der Gegenseite und leitet daraus einen Schlüssel ab.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Kurve und beide Werte enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die den gemeinsamen Punkt und den Schlüssel enthält.
+pub(crate) async fn compute_ec_shared_key(
+    req_body: Json<EcdhComputeSharedKeyRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /keyExchange/computeEcSharedKey wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: EcdhComputeSharedKeyRequestBean = req_body.into_inner();
+    if !(1..=MAX_KEY_LENGTH).contains(&req_body.key_length) {
+        return HttpResponse::BadRequest().json(SingleStringResponse {
+            message: format!("key_length must be between 1 and {}", MAX_KEY_LENGTH),
+        });
+    }
+    // Hier wird ein Dummy-Seed verwendet, weil keine Zufallszahlen benötigt werden.
+    let service = match query.use_fast {
+        true => NumberTheoryWithPrngService::new(Fast, 13),
+        false => NumberTheoryWithPrngService::new(Slow, 13),
+    };
+    let curve = SecureFiniteFieldEllipticCurve::from(req_body.curve.clone());
+
+    call_checked_with_parsed_big_ints(|| {
+        let private_value: BigInt = req_body.private_value.parse()?;
+        let other_public_value = FiniteFieldEllipticCurvePoint {
+            x: req_body.other_public_value.x.parse()?,
+            y: req_body.other_public_value.y.parse()?,
+            is_infinite: req_body.other_public_value.is_infinite,
+        };
+
+        let result = EcdhScheme::compute_shared_secret(
+            &curve,
+            &private_value,
+            &other_public_value,
+            &service,
+        )
+        .and_then(|shared_secret| {
+            let key = EcdhScheme::derive_key(
+                &curve,
+                &shared_secret,
+                req_body.info.as_bytes(),
+                req_body.key_length,
+            )?;
+            Ok(EcdhSharedKeyBean {
+                shared_secret: shared_secret.into(),
+                key: to_hex(&key),
+            })
+        });
+
+        match result {
+            Ok(response) => Ok(HttpResponse::Ok().json(response)),
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
+
+/// Simuliert einen Man-in-the-Middle-Angriff auf den Schlüsselaustausch. Es werden drei Abläufe
+/// simuliert: der unauthentisierte Austausch mit Eve, Station-to-Station mit Eve und
+/// Station-to-Station ohne Eve.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Gruppe und das Signaturverfahren enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die die Protokolle aller drei Abläufe enthält.
+pub(crate) async fn mitm_simulation(
+    req_body: Json<MitmSimulationRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /keyExchange/mitmSimulation wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: MitmSimulationRequestBean = req_body.into_inner();
+    let service = match query.use_fast {
+        true => NumberTheoryWithPrngService::new(Fast, req_body.random_seed),
+        false => NumberTheoryWithPrngService::new(Slow, req_body.random_seed),
+    };
+    let miller_rabin_rounds = req_body.miller_rabin_rounds;
+
+    let result = create_signature_keys(
+        &req_body.signature_scheme,
+        miller_rabin_rounds,
+        req_body.random_seed,
+        &service,
+    )
+    .and_then(|(alice_keys, bob_keys)| match req_body.group {
+        MitmGroupParametersBean::FiniteField { modulus_width } => {
+            let parameters = DiffieHellmanScheme::generate_parameters(
+                modulus_width,
+                miller_rabin_rounds,
+                &service,
+            );
+            run_simulations(&parameters, &alice_keys, &bob_keys, &service)
+        }
+        MitmGroupParametersBean::EllipticCurve {
+            modulus_width,
+            coef_a,
+        } => {
+            let curve = SecureFiniteFieldEllipticCurve::new(
+                coef_a,
+                modulus_width,
+                miller_rabin_rounds,
+                &service,
+            )?;
+            run_simulations(&curve, &alice_keys, &bob_keys, &service)
+        }
+    });
+
+    match result {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: e.to_string(),
+        }),
+    }
+}
+
+/// Führt alle drei Abläufe der Simulation über der gegebenen Gruppe durch.
+fn run_simulations<K: KeyAgreement>(
+    group: &K,
+    alice_keys: &SignatureKeys,
+    bob_keys: &SignatureKeys,
+    service: &NumberTheoryWithPrngService,
+) -> anyhow::Result<MitmSimulationResponseBean> {
+    Ok(MitmSimulationResponseBean {
+        unauthenticated_attack: simulate_unauthenticated(group, service)?.into(),
+        station_to_station_attack: simulate_station_to_station(
+            group, alice_keys, bob_keys, true, service,
+        )?
+        .into(),
+        station_to_station_honest: simulate_station_to_station(
+            group, alice_keys, bob_keys, false, service,
+        )?
+        .into(),
+    })
+}
+
+/// Erzeugt die langfristigen Signaturschlüssel von Alice und Bob.
+fn create_signature_keys(
+    signature_scheme: &MitmSignatureSchemeBean,
+    miller_rabin_rounds: u32,
+    random_seed: u32,
+    service: &NumberTheoryWithPrngService,
+) -> anyhow::Result<(SignatureKeys, SignatureKeys)> {
+    match *signature_scheme {
+        MitmSignatureSchemeBean::Rsa { key_size } => {
+            let create = |random_seed: u32| -> anyhow::Result<SignatureKeys> {
+                let key_pair = RsaScheme::generate_keypair(&RsaKeyGenConfig {
+                    key_size,
+                    miller_rabin_iterations: miller_rabin_rounds,
+                    random_seed,
+                    number_theory_service: service.number_theory_service,
+                    prime_count: 2,
+                })?;
+                Ok(SignatureKeys::Rsa {
+                    private_key: key_pair.private_key,
+                    public_key: key_pair.public_key,
+                })
+            };
+            // Bob erhält einen abgeleiteten Seed, damit beide unterschiedliche Schlüssel haben.
+            Ok((create(random_seed)?, create(random_seed.wrapping_add(1))?))
+        }
+        MitmSignatureSchemeBean::MenezesVanstone {
+            modulus_width,
+            coef_a,
+        } => {
+            let create = || -> anyhow::Result<SignatureKeys> {
+                let key_pair = MenezesVanstoneScheme::generate_keypair(
+                    coef_a,
+                    modulus_width,
+                    miller_rabin_rounds,
+                    service,
+                )?;
+                Ok(SignatureKeys::MenezesVanstone {
+                    private_key: key_pair.private_key,
+                    public_key: key_pair.public_key,
+                })
+            };
+            Ok((create()?, create()?))
+        }
+    }
+}
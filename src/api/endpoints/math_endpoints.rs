@@ -1,6 +1,10 @@
 use crate::api::basic::call_checked_with_parsed_big_ints;
-use crate::api::serializable_models::{SingleStringResponse, UseFastQuery};
+use crate::api::serializable_models::{PrngBackendBean, SingleStringResponse, UseFastQuery};
 use crate::math_core::babystep_giantstep::{Shanks, ShanksResult};
+use crate::math_core::csprng::blum_blum_shub::BlumBlumShub;
+use crate::math_core::csprng::chacha20_rng::ChaCha20Rng;
+use crate::math_core::csprng::hmac_drbg::HmacDrbg;
+use crate::math_core::csprng::RandomByteGenerator;
 use crate::math_core::number_theory::extended_euclid_result::ExtendedEuclidResult;
 use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
     Fast, Slow,
@@ -8,6 +12,11 @@ use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceS
 use crate::math_core::number_theory::number_theory_service::{
     NumberTheoryService, NumberTheoryServiceTrait,
 };
+use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+use crate::math_core::random_source::PrngRandomSource;
+use crate::math_core::randomness_tests::{
+    draw_samples, run_test_suite, RandomnessTestResult, SIGNIFICANCE_LEVEL,
+};
 use actix_web::web::{Json, Query};
 use actix_web::{HttpResponse, Responder};
 use bigdecimal::num_bigint::BigInt;
@@ -73,6 +82,56 @@ pub struct ExponentiationRequest {
     pub modulus: String,
 }
 
+/// Die größte Anzahl an Stichproben, die pro Anfrage getestet wird.
+const MAX_PRNG_TEST_SAMPLES: usize = 1_000_000;
+/// Die größte Anzahl an Stichproben für Blum Blum Shub, das für jedes Bit einmal modulo einer
+/// 1024-Bit-Zahl quadriert.
+const MAX_BLUM_BLUM_SHUB_TEST_SAMPLES: usize = 5_000;
+
+/// Die größte Anzahl an Stichproben, die pro Anfrage für den Generator getestet wird.
+fn max_sample_count(generator: PrngBackendBean) -> usize {
+    match generator {
+        PrngBackendBean::BlumBlumShub => MAX_BLUM_BLUM_SHUB_TEST_SAMPLES,
+        _ => MAX_PRNG_TEST_SAMPLES,
+    }
+}
+
+#[derive(Deserialize)]
+pub struct PrngTestRequest {
+    /// Der Generator, dessen Ausgabe getestet wird.
+    pub generator: PrngBackendBean,
+    /// Der Seed des Generators. Ohne Seed wird die Weyl-Folge mit 0 und die übrigen Generatoren
+    /// mit Entropie des Betriebssystems geseedet.
+    pub random_seed: Option<u32>,
+    pub sample_count: usize,
+    /// Die Breite einer Stichprobe in Bit, zwischen 1 und 32.
+    pub bits_per_sample: u32,
+}
+
+#[derive(Serialize)]
+pub struct PrngTestResultResponse {
+    pub name: String,
+    pub p_values: Vec<f64>,
+    pub passed: bool,
+}
+
+impl From<RandomnessTestResult> for PrngTestResultResponse {
+    fn from(result: RandomnessTestResult) -> Self {
+        PrngTestResultResponse {
+            name: result.name.to_string(),
+            passed: result.passed(),
+            p_values: result.p_values,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct PrngTestResponse {
+    pub bit_count: usize,
+    pub significance_level: f64,
+    pub results: Vec<PrngTestResultResponse>,
+}
+
 impl ExtendedEuclidResponse {
     /// Erstellt eine neue Instanz der ExtendedEuclidResponse anhand eines ExtendedEuclidResult.
     fn from(result: ExtendedEuclidResult) -> ExtendedEuclidResponse {
@@ -248,3 +307,64 @@ pub(crate) async fn exponentiation(
         Ok(HttpResponse::Ok().json(response))
     })
 }
+
+/// Prüft die Ausgabe eines Zufallszahlengenerators mit einer Auswahl der statistischen Tests aus
+/// NIST SP 800-22 und einem Chi-Quadrat-Test auf Gleichverteilung.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage mit Generator, Seed und Anzahl sowie Breite der Stichproben.
+///
+/// # Returns
+/// * `HttpResponse` - Die Antwort, die die p-Werte der einzelnen Tests enthält.
+pub(crate) async fn prng_test_endpoint(req_body: Json<PrngTestRequest>) -> impl Responder {
+    let req_body: PrngTestRequest = req_body.into_inner();
+    info!(
+        "Endpunkt /math/prngTest wurde aufgerufen, Generator: {:?}, Stichproben: {}",
+        req_body.generator, req_body.sample_count
+    );
+    if !(1..=32).contains(&req_body.bits_per_sample)
+        || req_body.sample_count > max_sample_count(req_body.generator)
+    {
+        return HttpResponse::BadRequest().json(SingleStringResponse {
+            message: format!(
+                "bits_per_sample must be between 1 and 32 and sample_count at most {}",
+                max_sample_count(req_body.generator)
+            ),
+        });
+    }
+
+    let random = PrngRandomSource::new(create_test_generator(
+        req_body.generator,
+        req_body.random_seed,
+    ));
+    let samples = draw_samples(&random, req_body.sample_count, req_body.bits_per_sample);
+
+    match run_test_suite(&samples, req_body.bits_per_sample) {
+        Ok(results) => HttpResponse::Ok().json(PrngTestResponse {
+            bit_count: samples.len() * req_body.bits_per_sample as usize,
+            significance_level: SIGNIFICANCE_LEVEL,
+            results: results.into_iter().map(Into::into).collect(),
+        }),
+        Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: e.to_string(),
+        }),
+    }
+}
+
+/// Erstellt den zu testenden Generator. Mit Seed sind auch die kryptografisch sicheren
+/// Generatoren reproduzierbar, siehe `RandomByteGenerator::from_seed`.
+fn create_test_generator(
+    generator: PrngBackendBean,
+    random_seed: Option<u32>,
+) -> PseudoRandomNumberGenerator {
+    let Some(random_seed) = random_seed else {
+        return PseudoRandomNumberGenerator::with_backend(generator.with_seed(0));
+    };
+    let secure_generator: Box<dyn RandomByteGenerator> = match generator {
+        PrngBackendBean::Weyl => return PseudoRandomNumberGenerator::new(random_seed),
+        PrngBackendBean::HmacDrbg => Box::new(HmacDrbg::from_seed(random_seed)),
+        PrngBackendBean::ChaCha20 => Box::new(ChaCha20Rng::from_seed(random_seed)),
+        PrngBackendBean::BlumBlumShub => Box::new(BlumBlumShub::from_seed(random_seed)),
+    };
+    PseudoRandomNumberGenerator::with_generator(secure_generator)
+}
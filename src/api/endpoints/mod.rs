@@ -1,3 +1,10 @@
+pub mod bbs;
+pub mod dsa;
+pub mod el_gamal;
+pub mod key_exchange;
 pub mod math_endpoints;
 pub mod mv;
+pub mod paillier;
+pub mod rabin;
 pub mod rsa;
+pub mod schnorr;
@@ -1,11 +1,14 @@
 use actix_web::web::{Json, Query};
 use actix_web::{HttpResponse, Responder};
+use anyhow::{ensure, Result};
 use log::info;
 use serde::{Deserialize, Serialize};
 use std::cmp::max;
 
 use crate::api::basic::call_checked_with_parsed_big_ints;
-use crate::api::serializable_models::{SingleStringResponse, UseFastQuery};
+use crate::api::serializable_models::{
+    PrimeSearchBean, PrngBackendBean, SingleStringResponse, UseFastQuery,
+};
 use crate::encryption::asymmetric_encryption_types::{
     AsymmetricDecryptor, AsymmetricEncryptor, Signer, Verifier,
 };
@@ -24,20 +27,44 @@ use crate::encryption::string_schemes::menezes_vanstone::menezes_vanstone_string
     MenezesVanstoneStringScheme, MvStringCiphertext,
 };
 use crate::encryption::symmetric_encryption_types::SymmetricEncryptor;
+use crate::math_core::ecc::elliptic_curve::EllipticCurve;
 use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
+use crate::math_core::ecc::named_curves::NamedCurve;
+use crate::math_core::ecc::point_encoding::PointEncoding;
 use crate::math_core::ecc::secure_finite_field_elliptic_curve::SecureFiniteFieldEllipticCurve;
+use crate::math_core::ecc::weierstrass_elliptic_curve::WeierstrassEllipticCurve;
 use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
     Fast, Slow,
 };
+use crate::math_core::number_theory::number_theory_service::{
+    NumberTheoryService, NumberTheoryServiceTrait,
+};
 use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+use crate::math_core::random_source::PrngRandomSource;
 use crate::math_core::traits::logarithm::Logarithm;
+use crate::shared::errors::EllipticCurveError;
+use crate::shared::hex::{from_hex, to_hex};
 
 #[derive(Deserialize, Clone)]
 pub struct MvCreateKeyPairRequestBean {
+    #[serde(default)]
     pub modulus_width: u32,
+    #[serde(default)]
     pub miller_rabin_rounds: u32,
+    #[serde(default)]
     pub coef_a: i32,
     pub random_seed: u32,
+    #[serde(default)]
+    pub prng_backend: PrngBackendBean,
+    #[serde(default)]
+    pub prime_search: PrimeSearchBean,
+    /// Der Name einer Standardkurve, z.B. "P-256". Ist er gesetzt, wird keine eigene Kurve
+    /// erzeugt und `modulus_width`, `miller_rabin_rounds` und `coef_a` werden ignoriert.
+    #[serde(default)]
+    pub curve_name: Option<String>,
+    /// Ist sie gesetzt, wird der öffentliche Punkt als SEC1-Oktett-String ausgegeben.
+    #[serde(default)]
+    pub point_encoding: Option<EcPointEncodingBean>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -76,17 +103,85 @@ impl From<FiniteFieldEllipticCurvePoint> for EcPointBean {
     }
 }
 
+/// Die Kodierung, in der Punkte in Antworten als SEC1-Oktett-String ausgegeben werden.
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum EcPointEncodingBean {
+    Compressed,
+    Uncompressed,
+}
+
+impl From<EcPointEncodingBean> for PointEncoding {
+    fn from(encoding: EcPointEncodingBean) -> Self {
+        match encoding {
+            EcPointEncodingBean::Compressed => PointEncoding::Compressed,
+            EcPointEncodingBean::Uncompressed => PointEncoding::Uncompressed,
+        }
+    }
+}
+
+/// Ein Punkt entweder als SEC1-Oktett-String in Hex-Darstellung oder mit seinen Koordinaten.
+/// Für die Dekodierung der kompakten Form wird die Kurve benötigt.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum EcAnyPointBean {
+    Encoded(String),
+    Plain(EcPointBean),
+}
+
+impl From<FiniteFieldEllipticCurvePoint> for EcAnyPointBean {
+    fn from(point: FiniteFieldEllipticCurvePoint) -> Self {
+        EcAnyPointBean::Plain(EcPointBean::from(point))
+    }
+}
+
+impl EcAnyPointBean {
+    /// Stellt den Punkt in der gewünschten Kodierung dar, ohne Kodierung mit Koordinaten.
+    pub fn new<C: EllipticCurve>(
+        point: FiniteFieldEllipticCurvePoint,
+        curve: &C,
+        encoding: Option<EcPointEncodingBean>,
+    ) -> Self {
+        match encoding {
+            Some(encoding) => {
+                EcAnyPointBean::Encoded(to_hex(&point.to_sec1_bytes(curve, encoding.into())))
+            }
+            None => EcAnyPointBean::from(point),
+        }
+    }
+
+    /// Liest den Punkt auf der gegebenen Kurve ein.
+    pub fn to_point<C: EllipticCurve>(&self, curve: &C) -> Result<FiniteFieldEllipticCurvePoint> {
+        match self {
+            EcAnyPointBean::Encoded(hex) => {
+                let service = NumberTheoryService::new(Fast);
+                // Die Wurzel für die Dekomprimierung existiert nur modulo einer Primzahl.
+                ensure!(
+                    service.is_probably_prime(curve.prime(), 20, &PrngRandomSource::seeded(13)),
+                    EllipticCurveError::InvalidPrimeError(curve.prime().to_string())
+                );
+                FiniteFieldEllipticCurvePoint::from_sec1_bytes(&from_hex(hex)?, curve, &service)
+            }
+            EcAnyPointBean::Plain(point) => Ok(FiniteFieldEllipticCurvePoint {
+                x: point.x.parse()?,
+                y: point.y.parse()?,
+                is_infinite: point.is_infinite,
+            }),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct MvPublicKeyBean {
     pub curve: EllipticCurveBean,
-    pub y: EcPointBean,
+    pub y: EcAnyPointBean,
 }
 
 impl From<MenezesVanstonePublicKey> for MvPublicKeyBean {
     fn from(key: MenezesVanstonePublicKey) -> Self {
         MvPublicKeyBean {
             curve: EllipticCurveBean::from(key.curve),
-            y: EcPointBean::from(key.y),
+            y: EcAnyPointBean::from(key.y),
         }
     }
 }
@@ -121,23 +216,94 @@ impl From<MenezesVanstoneKeyPair> for MvKeyPairBean {
     }
 }
 
+/// Ein öffentlicher Schlüssel auf einer Standardkurve, die nur über ihren Namen angegeben wird.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct MvNamedPublicKeyBean {
+    pub curve_name: String,
+    pub y: EcAnyPointBean,
+}
+
+/// Ein privater Schlüssel auf einer Standardkurve, die nur über ihren Namen angegeben wird.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct MvNamedPrivateKeyBean {
+    pub curve_name: String,
+    pub x: String,
+}
+
+#[derive(Serialize, Clone)]
+pub struct MvNamedKeyPairBean {
+    pub public_key: MvNamedPublicKeyBean,
+    pub private_key: MvNamedPrivateKeyBean,
+}
+
+impl MvNamedKeyPairBean {
+    fn new(
+        curve: NamedCurve,
+        key_pair: MenezesVanstoneKeyPair<WeierstrassEllipticCurve>,
+        encoding: Option<EcPointEncodingBean>,
+    ) -> Self {
+        MvNamedKeyPairBean {
+            public_key: MvNamedPublicKeyBean {
+                curve_name: curve.to_string(),
+                y: EcAnyPointBean::new(key_pair.public_key.y, &key_pair.public_key.curve, encoding),
+            },
+            private_key: MvNamedPrivateKeyBean {
+                curve_name: curve.to_string(),
+                x: key_pair.private_key.x.to_string(),
+            },
+        }
+    }
+}
+
+/// Ein öffentlicher Schlüssel entweder auf einer Standardkurve oder auf einer eigens erzeugten
+/// Kurve mit allen Parametern.
+#[derive(Deserialize, Clone)]
+#[serde(untagged)]
+pub enum MvAnyPublicKeyBean {
+    Named(MvNamedPublicKeyBean),
+    Generated(MvPublicKeyBean),
+}
+
+/// Ein privater Schlüssel entweder auf einer Standardkurve oder auf einer eigens erzeugten
+/// Kurve mit allen Parametern.
+#[derive(Deserialize, Clone)]
+#[serde(untagged)]
+pub enum MvAnyPrivateKeyBean {
+    Named(MvNamedPrivateKeyBean),
+    Generated(MvPrivateKeyBean),
+}
+
 #[derive(Deserialize)]
 pub struct MvEncryptRequestBean {
     pub public_key: MvPublicKeyBean,
     pub message: String,
     pub radix: u32,
     pub random_seed: u32,
+    #[serde(default)]
+    pub prng_backend: PrngBackendBean,
+    /// Ist sie gesetzt, werden die Punkte des Chiffrats als SEC1-Oktett-Strings ausgegeben.
+    #[serde(default)]
+    pub point_encoding: Option<EcPointEncodingBean>,
 }
 
 #[derive(Deserialize, Serialize, Clone)]
 pub struct MvCipherTextBean {
     pub encrypted_message: String,
-    pub points: Vec<EcPointBean>,
+    pub points: Vec<EcAnyPointBean>,
 }
 
-impl From<MvStringCiphertext> for MvCipherTextBean {
-    fn from(ciphertext: MvStringCiphertext) -> Self {
-        let points = ciphertext.points.into_iter().map(Into::into).collect();
+impl MvCipherTextBean {
+    /// Erstellt die Bean, wobei die Punkte in der gewünschten Kodierung dargestellt werden.
+    fn new<C: EllipticCurve>(
+        ciphertext: MvStringCiphertext,
+        curve: &C,
+        encoding: Option<EcPointEncodingBean>,
+    ) -> Self {
+        let points = ciphertext
+            .points
+            .into_iter()
+            .map(|point| EcAnyPointBean::new(point, curve, encoding))
+            .collect();
 
         MvCipherTextBean {
             encrypted_message: ciphertext.ciphertext,
@@ -185,19 +351,27 @@ impl From<MenezesVanstoneSignature> for MvSignatureBean {
 
 #[derive(Deserialize, Clone)]
 pub struct MvSignRequestBean {
-    pub private_key: MvPrivateKeyBean,
+    pub private_key: MvAnyPrivateKeyBean,
     pub message: String,
+    /// Wird bei deterministischen Nonces nicht benötigt.
+    #[serde(default)]
     pub random_seed: u32,
+    #[serde(default)]
+    pub prng_backend: PrngBackendBean,
+    /// Ist es gesetzt, wird die Nonce nach RFC 6979 aus Schlüssel und Nachricht abgeleitet.
+    #[serde(default)]
+    pub deterministic_nonce: bool,
 }
 
 #[derive(Deserialize, Clone)]
 pub struct MvVerifyRequestBean {
-    pub public_key: MvPublicKeyBean,
+    pub public_key: MvAnyPublicKeyBean,
     pub message: String,
     pub signature: MvSignatureBean,
 }
 
-/// Erstellt ein neues Schlüsselpaar für das MenezesVanstone-Schema.
+/// Erstellt ein neues Schlüsselpaar für das MenezesVanstone-Schema. Ist ein Kurvenname angegeben,
+/// wird die entsprechende Standardkurve verwendet, ansonsten wird eine neue Kurve erzeugt.
 ///
 /// # Arguments
 /// * `req_body` - Die Anfrage, die die Parameter für die Erstellung des Schlüsselpaares enthält.
@@ -215,10 +389,31 @@ pub(crate) async fn create_key_pair(
     let req_body: MvCreateKeyPairRequestBean = req_body.into_inner();
     let use_fast = query.use_fast;
 
+    let backend = req_body.prng_backend.with_seed(req_body.random_seed);
     let service = match use_fast {
-        true => NumberTheoryWithPrngService::new(Fast, req_body.random_seed),
-        false => NumberTheoryWithPrngService::new(Slow, req_body.random_seed),
-    };
+        true => NumberTheoryWithPrngService::with_backend(Fast, backend),
+        false => NumberTheoryWithPrngService::with_backend(Slow, backend),
+    }
+    .with_prime_search(req_body.prime_search.into());
+
+    if let Some(curve_name) = &req_body.curve_name {
+        let curve = match curve_name.parse::<NamedCurve>() {
+            Ok(curve) => curve,
+            Err(e) => {
+                return HttpResponse::BadRequest().json(SingleStringResponse {
+                    message: e.to_string(),
+                })
+            }
+        };
+        return match MenezesVanstoneScheme::generate_keypair_for_curve(&curve.curve(), &service) {
+            Ok(key_pair) => HttpResponse::Ok().json(MvNamedKeyPairBean::new(
+                curve,
+                key_pair,
+                req_body.point_encoding,
+            )),
+            Err(e) => HttpResponse::InternalServerError().body(e.to_string()),
+        };
+    }
 
     let key_pair = MenezesVanstoneScheme::generate_keypair(
         req_body.coef_a,
@@ -229,7 +424,13 @@ pub(crate) async fn create_key_pair(
 
     match key_pair {
         Ok(key_pair) => {
-            let response = MvKeyPairBean::from(key_pair);
+            let y = EcAnyPointBean::new(
+                key_pair.public_key.y.clone(),
+                &key_pair.public_key.curve,
+                req_body.point_encoding,
+            );
+            let mut response = MvKeyPairBean::from(key_pair);
+            response.public_key.y = y;
             HttpResponse::Ok().json(response)
         }
         Err(e) => HttpResponse::InternalServerError().body(e.to_string()),
@@ -251,7 +452,10 @@ pub(crate) async fn encrypt(
     let req_body: MvEncryptRequestBean = req_body.into_inner();
 
     call_checked_with_parsed_big_ints(|| {
-        let public_key = req_body.public_key.clone().into();
+        let public_key = match MenezesVanstonePublicKey::try_from(req_body.public_key.clone()) {
+            Ok(public_key) => public_key,
+            Err(e) => return Ok(bad_request(e)),
+        };
 
         let public_key = MenezesVanstoneStringPublicKey {
             mv_key: public_key,
@@ -260,17 +464,21 @@ pub(crate) async fn encrypt(
 
         let message = &req_body.message;
 
-        let random_seed = req_body.random_seed;
+        let backend = req_body.prng_backend.with_seed(req_body.random_seed);
         let service = match query.use_fast {
-            true => NumberTheoryWithPrngService::new(Fast, random_seed),
-            false => NumberTheoryWithPrngService::new(Slow, random_seed),
+            true => NumberTheoryWithPrngService::with_backend(Fast, backend),
+            false => NumberTheoryWithPrngService::with_backend(Slow, backend),
         };
 
         let ciphertext = MenezesVanstoneStringScheme::encrypt(&public_key, &message, &service);
 
         match ciphertext {
             Ok(ciphertext) => {
-                let response = MvCipherTextBean::from(ciphertext);
+                let response = MvCipherTextBean::new(
+                    ciphertext,
+                    &public_key.mv_key.curve,
+                    req_body.point_encoding,
+                );
                 Ok(HttpResponse::Ok().json(response))
             }
             Err(e) => Ok(HttpResponse::InternalServerError().body(e.to_string())),
@@ -300,7 +508,13 @@ pub(crate) async fn decrypt(
             radix: req_body.radix,
         };
 
-        let ciphertext = req_body.cipher_text.clone().into();
+        let ciphertext = match MvStringCiphertext::from_bean(
+            req_body.cipher_text.clone(),
+            &private_key.mv_key.curve,
+        ) {
+            Ok(ciphertext) => ciphertext,
+            Err(e) => return Ok(bad_request(e)),
+        };
 
         let random_seed = 14; // TODO: Random_seed erwarten
         let service = match query.use_fast {
@@ -328,16 +542,37 @@ pub(crate) async fn sign(
 
     let req_body: &MvSignRequestBean = &req_body.into_inner();
     call_checked_with_parsed_big_ints(|| {
-        let private_key = req_body.private_key.clone().into();
         let message = &req_body.message;
 
-        let random_seed = req_body.random_seed;
+        let backend = req_body.prng_backend.with_seed(req_body.random_seed);
         let service = match query.use_fast {
-            true => NumberTheoryWithPrngService::new(Fast, random_seed),
-            false => NumberTheoryWithPrngService::new(Slow, random_seed),
+            true => NumberTheoryWithPrngService::with_backend(Fast, backend),
+            false => NumberTheoryWithPrngService::with_backend(Slow, backend),
         };
 
-        let signature = MenezesVanstoneScheme::sign(&private_key, message, &service);
+        let signature = match req_body.private_key.clone() {
+            MvAnyPrivateKeyBean::Named(private_key) => {
+                let private_key = match MenezesVanstonePrivateKey::try_from(private_key) {
+                    Ok(private_key) => private_key,
+                    Err(e) => return Ok(bad_request(e)),
+                };
+                match req_body.deterministic_nonce {
+                    true => {
+                        MenezesVanstoneScheme::sign_deterministic(&private_key, message, &service)
+                    }
+                    false => MenezesVanstoneScheme::sign(&private_key, message, &service),
+                }
+            }
+            MvAnyPrivateKeyBean::Generated(private_key) => {
+                let private_key = private_key.into();
+                match req_body.deterministic_nonce {
+                    true => {
+                        MenezesVanstoneScheme::sign_deterministic(&private_key, message, &service)
+                    }
+                    false => MenezesVanstoneScheme::sign(&private_key, message, &service),
+                }
+            }
+        };
 
         match signature {
             Ok(signature) => {
@@ -357,7 +592,6 @@ pub(crate) async fn verify(
 
     let req_body: &MvVerifyRequestBean = &req_body.into_inner();
     call_checked_with_parsed_big_ints(|| {
-        let public_key = req_body.public_key.clone().into();
         let message = &req_body.message;
         let signature = &req_body.signature.clone().into();
 
@@ -367,7 +601,22 @@ pub(crate) async fn verify(
             false => NumberTheoryWithPrngService::new(Slow, random_seed),
         };
 
-        let verified = MenezesVanstoneScheme::verify(&public_key, signature, message, &service);
+        let verified = match req_body.public_key.clone() {
+            MvAnyPublicKeyBean::Named(public_key) => {
+                let public_key = match MenezesVanstonePublicKey::try_from(public_key) {
+                    Ok(public_key) => public_key,
+                    Err(e) => return Ok(bad_request(e)),
+                };
+                MenezesVanstoneScheme::verify(&public_key, signature, message, &service)
+            }
+            MvAnyPublicKeyBean::Generated(public_key) => {
+                let public_key = match MenezesVanstonePublicKey::try_from(public_key) {
+                    Ok(public_key) => public_key,
+                    Err(e) => return Ok(bad_request(e)),
+                };
+                MenezesVanstoneScheme::verify(&public_key, signature, message, &service)
+            }
+        };
 
         match verified {
             Ok(verified) => {
@@ -380,3 +629,9 @@ pub(crate) async fn verify(
         }
     })
 }
+
+fn bad_request(error: impl ToString) -> HttpResponse {
+    HttpResponse::BadRequest().json(SingleStringResponse {
+        message: error.to_string(),
+    })
+}
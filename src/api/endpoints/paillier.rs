@@ -0,0 +1,352 @@
+use crate::api::basic::call_checked_with_parsed_big_ints;
+use crate::api::serializable_models::{PrngBackendBean, SingleStringResponse, UseFastQuery};
+use crate::encryption::asymmetric_encryption_types::{
+    AsymmetricDecryptor, AsymmetricEncryptor, KeyGenerator,
+};
+use crate::encryption::core::paillier::keys::{PaillierPrivateKey, PaillierPublicKey};
+use crate::encryption::core::paillier::paillier_scheme::{PaillierKeyGenConfig, PaillierScheme};
+use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
+    Fast, Slow,
+};
+use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+use crate::math_core::pseudo_random_number_generator::{PrngBackend, PseudoRandomNumberGenerator};
+use crate::math_core::random_source::PrngRandomSource;
+use actix_web::http::StatusCode;
+use actix_web::web::{Json, Query};
+use actix_web::{HttpResponse, HttpResponseBuilder, Responder};
+use bigdecimal::num_bigint::{BigInt, ParseBigIntError};
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+#[derive(Deserialize)]
+pub struct PaillierCreateKeyPairRequestBean {
+    pub modulus_width: u32,
+    pub miller_rabin_rounds: u32,
+    pub random_seed: u32,
+    #[serde(default)]
+    pub prng_backend: PrngBackendBean,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PaillierKeyPairBean {
+    pub n: String,
+    pub g: String,
+    pub lambda: String,
+    pub mu: String,
+}
+
+impl PaillierKeyPairBean {
+    /// Wandelt das serialisierte Schlüsselpaar in einen öffentlichen Schlüssel um.
+    ///
+    /// # Fehler
+    /// * `ParseBigIntError` - Falls die BigInts nicht geparst werden können.
+    fn to_public_key(&self) -> Result<PaillierPublicKey, ParseBigIntError> {
+        debug!("Serialisiere KeyPair zu PublicKey");
+        Ok(PaillierPublicKey {
+            n: self.n.parse()?,
+            g: self.g.parse()?,
+        })
+    }
+
+    /// Wandelt das serialisierte Schlüsselpaar in einen privaten Schlüssel um.
+    ///
+    /// # Fehler
+    /// * `ParseBigIntError` - Falls die BigInts nicht geparst werden können.
+    fn to_private_key(&self) -> Result<PaillierPrivateKey, ParseBigIntError> {
+        debug!("Serialisiere KeyPair zu PrivateKey");
+        Ok(PaillierPrivateKey {
+            n: self.n.parse()?,
+            lambda: self.lambda.parse()?,
+            mu: self.mu.parse()?,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+pub struct PaillierEncryptRequestBean {
+    pub plaintext: String,
+    pub key_pair: PaillierKeyPairBean,
+}
+
+#[derive(Deserialize)]
+pub struct PaillierDecryptRequestBean {
+    pub ciphertext: String,
+    pub key_pair: PaillierKeyPairBean,
+}
+
+#[derive(Deserialize)]
+pub struct PaillierAddRequestBean {
+    pub ciphertext_one: String,
+    pub ciphertext_two: String,
+    pub key_pair: PaillierKeyPairBean,
+}
+
+#[derive(Deserialize)]
+pub struct PaillierScalarMultiplyRequestBean {
+    pub ciphertext: String,
+    pub scalar: String,
+    pub key_pair: PaillierKeyPairBean,
+}
+
+#[derive(Deserialize)]
+pub struct PaillierAdditionRequestBean {
+    pub summand_one: String,
+    pub summand_two: String,
+    pub key_pair: PaillierKeyPairBean,
+}
+
+#[derive(Serialize)]
+pub struct PaillierAdditionResponseBean {
+    pub encrypted_summand_one: String,
+    pub encrypted_summand_two: String,
+    pub encrypted_result: String,
+    pub decrypted_result: String,
+}
+
+/// Erstellt ein neues Schlüsselpaar für das Paillier-Kryptosystem.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Parameter für die Erstellung des Schlüsselpaares enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die das Schlüsselpaar enthält.
+pub(crate) async fn create_key_pair(
+    req_body: Json<PaillierCreateKeyPairRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /paillier/createKeyPair wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: PaillierCreateKeyPairRequestBean = req_body.into_inner();
+
+    let number_theory_service = match query.use_fast {
+        true => NumberTheoryService::new(Fast),
+        false => NumberTheoryService::new(Slow),
+    };
+
+    let config = PaillierKeyGenConfig {
+        key_size: req_body.modulus_width,
+        miller_rabin_iterations: req_body.miller_rabin_rounds,
+        random_seed: req_body.random_seed,
+        number_theory_service,
+    };
+
+    let random = PrngRandomSource::new(PseudoRandomNumberGenerator::with_backend(
+        req_body.prng_backend.with_seed(req_body.random_seed),
+    ));
+    let key_pair = match PaillierScheme::generate_keypair_with_source(&config, &random) {
+        Ok(key_pair) => key_pair,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })
+        }
+    };
+
+    let key_pair_response = PaillierKeyPairBean {
+        n: key_pair.public_key.n.to_str_radix(10),
+        g: key_pair.public_key.g.to_str_radix(10),
+        lambda: key_pair.private_key.lambda.to_str_radix(10),
+        mu: key_pair.private_key.mu.to_str_radix(10),
+    };
+
+    HttpResponse::Ok().json(key_pair_response)
+}
+
+/// Verschlüsselt eine Zahl mit dem Paillier-Kryptosystem.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Zahl und das Schlüsselpaar enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die den Chiffretext enthält.
+pub(crate) async fn encrypt(
+    req_body: Json<PaillierEncryptRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /paillier/encrypt wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: PaillierEncryptRequestBean = req_body.into_inner();
+    // ChaCha20, damit die Zufallszahl `r` nicht vorhersagbar ist.
+    let number_theory_service = match query.use_fast {
+        true => NumberTheoryWithPrngService::with_backend(Fast, PrngBackend::ChaCha20),
+        false => NumberTheoryWithPrngService::with_backend(Slow, PrngBackend::ChaCha20),
+    };
+
+    call_checked_with_parsed_big_ints(|| {
+        let plaintext = BigInt::from_str(&req_body.plaintext)?;
+        let public_key = req_body.key_pair.to_public_key()?;
+
+        let ciphertext = PaillierScheme::encrypt(&public_key, &plaintext, &number_theory_service);
+        let response = SingleStringResponse {
+            message: ciphertext.to_str_radix(10),
+        };
+
+        Ok(HttpResponse::Ok().json(response))
+    })
+}
+
+/// Entschlüsselt einen Chiffretext des Paillier-Kryptosystems.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die den Chiffretext und das Schlüsselpaar enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die den Klartext enthält.
+pub(crate) async fn decrypt(
+    req_body: Json<PaillierDecryptRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /paillier/decrypt wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: PaillierDecryptRequestBean = req_body.into_inner();
+    let number_theory_service = match query.use_fast {
+        true => NumberTheoryWithPrngService::new(Fast, 13),
+        false => NumberTheoryWithPrngService::new(Slow, 13),
+    };
+
+    call_checked_with_parsed_big_ints(|| {
+        let ciphertext = BigInt::from_str(&req_body.ciphertext)?;
+        let private_key = req_body.key_pair.to_private_key()?;
+
+        let plaintext = PaillierScheme::decrypt(&private_key, &ciphertext, &number_theory_service);
+        let response = SingleStringResponse {
+            message: plaintext.to_str_radix(10),
+        };
+
+        Ok(HttpResponse::Ok().json(response))
+    })
+}
+
+/// Addiert zwei Chiffretexte homomorph, ohne sie zu entschlüsseln.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die beiden Chiffretexte und das Schlüsselpaar enthält.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die den Chiffretext der Summe enthält.
+pub(crate) async fn add(req_body: Json<PaillierAddRequestBean>) -> impl Responder {
+    info!("Endpunkt /paillier/add wurde aufgerufen");
+    let req_body: PaillierAddRequestBean = req_body.into_inner();
+
+    call_checked_with_parsed_big_ints(|| {
+        let ciphertext_one = BigInt::from_str(&req_body.ciphertext_one)?;
+        let ciphertext_two = BigInt::from_str(&req_body.ciphertext_two)?;
+        let public_key = req_body.key_pair.to_public_key()?;
+
+        let sum = PaillierScheme::add(&public_key, &ciphertext_one, &ciphertext_two);
+        let response = SingleStringResponse {
+            message: sum.to_str_radix(10),
+        };
+
+        Ok(HttpResponse::Ok().json(response))
+    })
+}
+
+/// Multipliziert einen Chiffretext homomorph mit einem Skalar, ohne ihn zu entschlüsseln.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die den Chiffretext, den Skalar und das Schlüsselpaar enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die den Chiffretext des Produkts enthält.
+pub(crate) async fn scalar_multiply(
+    req_body: Json<PaillierScalarMultiplyRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /paillier/scalarMultiply wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: PaillierScalarMultiplyRequestBean = req_body.into_inner();
+    let number_theory_service = match query.use_fast {
+        true => NumberTheoryService::new(Fast),
+        false => NumberTheoryService::new(Slow),
+    };
+
+    call_checked_with_parsed_big_ints(|| {
+        let ciphertext = BigInt::from_str(&req_body.ciphertext)?;
+        let scalar = BigInt::from_str(&req_body.scalar)?;
+        let public_key = req_body.key_pair.to_public_key()?;
+
+        let product = PaillierScheme::scalar_multiply(
+            &public_key,
+            &ciphertext,
+            &scalar,
+            &number_theory_service,
+        );
+        let response = SingleStringResponse {
+            message: product.to_str_radix(10),
+        };
+
+        Ok(HttpResponse::Ok().json(response))
+    })
+}
+
+/// Addiert zwei Zahlen, indem sie verschlüsselt, homomorph addiert und wieder entschlüsselt werden.
+/// Das Gegenstück zu `/rsa/multiplication`.
+pub(crate) async fn addition(
+    req_body: Json<PaillierAdditionRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /paillier/addition wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: PaillierAdditionRequestBean = req_body.into_inner();
+    // ChaCha20, damit die Zufallszahl `r` nicht vorhersagbar ist.
+    let number_theory_service = match query.use_fast {
+        true => NumberTheoryWithPrngService::with_backend(Fast, PrngBackend::ChaCha20),
+        false => NumberTheoryWithPrngService::with_backend(Slow, PrngBackend::ChaCha20),
+    };
+
+    call_checked_with_parsed_big_ints(|| {
+        let summand_one = BigInt::from_str(&req_body.summand_one)?;
+        let summand_two = BigInt::from_str(&req_body.summand_two)?;
+
+        let public_key = req_body.key_pair.to_public_key()?;
+        let private_key = req_body.key_pair.to_private_key()?;
+
+        let encrypted_summand_one =
+            PaillierScheme::encrypt(&public_key, &summand_one, &number_theory_service);
+        let encrypted_summand_two =
+            PaillierScheme::encrypt(&public_key, &summand_two, &number_theory_service);
+
+        let encrypted_result =
+            PaillierScheme::add(&public_key, &encrypted_summand_one, &encrypted_summand_two);
+
+        let result =
+            PaillierScheme::decrypt(&private_key, &encrypted_result, &number_theory_service);
+
+        let response = PaillierAdditionResponseBean {
+            encrypted_summand_one: encrypted_summand_one.to_str_radix(10),
+            encrypted_summand_two: encrypted_summand_two.to_str_radix(10),
+            encrypted_result: encrypted_result.to_str_radix(10),
+            decrypted_result: result.to_str_radix(10),
+        };
+
+        if (summand_one + summand_two) != result {
+            return Ok(
+                HttpResponseBuilder::new(StatusCode::INTERNAL_SERVER_ERROR).json(
+                    SingleStringResponse {
+                        message: "Addition fehlgeschlagen: Summe außerhalb von 0 bis n-1!"
+                            .to_string(),
+                    },
+                ),
+            );
+        }
+
+        Ok(HttpResponse::Ok().json(response))
+    })
+}
@@ -0,0 +1,318 @@
+use crate::api::basic::call_checked_with_parsed_big_ints;
+use crate::api::serializable_models::{PrngBackendBean, SingleStringResponse, UseFastQuery};
+use crate::encryption::asymmetric_encryption_types::{
+    AsymmetricDecryptor, AsymmetricEncryptor, KeyGenerator, Signer, Verifier,
+};
+use crate::encryption::core::rabin::keys::{RabinPrivateKey, RabinPublicKey};
+use crate::encryption::core::rabin::rabin_scheme::{RabinKeyGenConfig, RabinScheme};
+use crate::encryption::core::rabin::rabin_signature_scheme::{
+    RabinSignature, RabinSignatureScheme,
+};
+use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
+    Fast, Slow,
+};
+use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+use crate::math_core::random_source::PrngRandomSource;
+use actix_web::web::{Json, Query};
+use actix_web::{HttpResponse, Responder};
+use bigdecimal::num_bigint::{BigInt, ParseBigIntError};
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+#[derive(Deserialize)]
+pub struct RabinCreateKeyPairRequestBean {
+    pub modulus_width: u32,
+    pub miller_rabin_rounds: u32,
+    pub random_seed: u32,
+    #[serde(default)]
+    pub prng_backend: PrngBackendBean,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RabinKeyPairBean {
+    pub n: String,
+    pub p: String,
+    pub q: String,
+}
+
+impl RabinKeyPairBean {
+    /// Wandelt das serialisierte Schlüsselpaar in einen öffentlichen Schlüssel um.
+    ///
+    /// # Fehler
+    /// * `ParseBigIntError` - Falls die BigInts nicht geparst werden können.
+    fn to_public_key(&self) -> Result<RabinPublicKey, ParseBigIntError> {
+        debug!("Serialisiere KeyPair zu PublicKey");
+        Ok(RabinPublicKey { n: self.n.parse()? })
+    }
+
+    /// Wandelt das serialisierte Schlüsselpaar in einen privaten Schlüssel um.
+    ///
+    /// # Fehler
+    /// * `ParseBigIntError` - Falls die BigInts nicht geparst werden können.
+    fn to_private_key(&self) -> Result<RabinPrivateKey, ParseBigIntError> {
+        debug!("Serialisiere KeyPair zu PrivateKey");
+        Ok(RabinPrivateKey {
+            p: self.p.parse()?,
+            q: self.q.parse()?,
+            n: self.n.parse()?,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+pub struct RabinEncryptRequestBean {
+    pub plaintext: String,
+    pub key_pair: RabinKeyPairBean,
+}
+
+#[derive(Deserialize)]
+pub struct RabinDecryptRequestBean {
+    pub ciphertext: String,
+    pub key_pair: RabinKeyPairBean,
+}
+
+#[derive(Serialize)]
+pub struct RabinDecryptResponseBean {
+    pub plaintext: String,
+    /// Alle vier Quadratwurzeln des Chiffretextes, von denen genau eine gültige Redundanz hat.
+    pub roots: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RabinSignatureBean {
+    pub root: String,
+    pub counter: u32,
+}
+
+#[derive(Deserialize)]
+pub struct RabinSignRequestBean {
+    pub plaintext: String,
+    pub key_pair: RabinKeyPairBean,
+}
+
+#[derive(Deserialize)]
+pub struct RabinVerifyRequestBean {
+    pub plaintext: String,
+    pub signature: RabinSignatureBean,
+    pub key_pair: RabinKeyPairBean,
+}
+
+/// Erstellt ein neues Schlüsselpaar für das Rabin-Kryptosystem.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Parameter für die Erstellung des Schlüsselpaares enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die das Schlüsselpaar enthält.
+pub(crate) async fn create_key_pair(
+    req_body: Json<RabinCreateKeyPairRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /rabin/createKeyPair wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: RabinCreateKeyPairRequestBean = req_body.into_inner();
+
+    let number_theory_service = match query.use_fast {
+        true => NumberTheoryService::new(Fast),
+        false => NumberTheoryService::new(Slow),
+    };
+
+    let config = RabinKeyGenConfig {
+        key_size: req_body.modulus_width,
+        miller_rabin_iterations: req_body.miller_rabin_rounds,
+        random_seed: req_body.random_seed,
+        number_theory_service,
+    };
+
+    let random = PrngRandomSource::new(PseudoRandomNumberGenerator::with_backend(
+        req_body.prng_backend.with_seed(req_body.random_seed),
+    ));
+    let key_pair = match RabinScheme::generate_keypair_with_source(&config, &random) {
+        Ok(key_pair) => key_pair,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })
+        }
+    };
+
+    let key_pair_response = RabinKeyPairBean {
+        n: key_pair.public_key.n.to_str_radix(10),
+        p: key_pair.private_key.p.to_str_radix(10),
+        q: key_pair.private_key.q.to_str_radix(10),
+    };
+
+    HttpResponse::Ok().json(key_pair_response)
+}
+
+/// Verschlüsselt eine Zahl mit dem Rabin-Kryptosystem.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Zahl und das Schlüsselpaar enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die den Chiffretext enthält.
+pub(crate) async fn encrypt(
+    req_body: Json<RabinEncryptRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /rabin/encrypt wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: RabinEncryptRequestBean = req_body.into_inner();
+    let number_theory_service = match query.use_fast {
+        true => NumberTheoryWithPrngService::new(Fast, 13),
+        false => NumberTheoryWithPrngService::new(Slow, 13),
+    };
+
+    call_checked_with_parsed_big_ints(|| {
+        let plaintext = BigInt::from_str(&req_body.plaintext)?;
+        let public_key = req_body.key_pair.to_public_key()?;
+
+        match RabinScheme::encrypt(&public_key, &plaintext, &number_theory_service) {
+            Ok(ciphertext) => Ok(HttpResponse::Ok().json(SingleStringResponse {
+                message: ciphertext.to_str_radix(10),
+            })),
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
+
+/// Entschlüsselt einen Chiffretext des Rabin-Kryptosystems und gibt neben dem Klartext
+/// alle vier Quadratwurzeln zurück.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die den Chiffretext und das Schlüsselpaar enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die den Klartext und die Wurzeln enthält.
+pub(crate) async fn decrypt(
+    req_body: Json<RabinDecryptRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /rabin/decrypt wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: RabinDecryptRequestBean = req_body.into_inner();
+    let number_theory_service = match query.use_fast {
+        true => NumberTheoryWithPrngService::new(Fast, 13),
+        false => NumberTheoryWithPrngService::new(Slow, 13),
+    };
+
+    call_checked_with_parsed_big_ints(|| {
+        let ciphertext = BigInt::from_str(&req_body.ciphertext)?;
+        let private_key = req_body.key_pair.to_private_key()?;
+
+        let result = RabinScheme::square_roots(
+            &private_key,
+            &ciphertext,
+            &number_theory_service.number_theory_service,
+        )
+        .and_then(|roots| {
+            let plaintext =
+                RabinScheme::decrypt(&private_key, &ciphertext, &number_theory_service)?;
+            Ok(RabinDecryptResponseBean {
+                plaintext: plaintext.to_str_radix(10),
+                roots: roots.iter().map(|root| root.to_str_radix(10)).collect(),
+            })
+        });
+
+        match result {
+            Ok(response) => Ok(HttpResponse::Ok().json(response)),
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
+
+/// Signiert eine Nachricht mit dem Rabin-Signaturverfahren.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Nachricht und das Schlüsselpaar enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die die Signatur enthält.
+pub(crate) async fn sign(
+    req_body: Json<RabinSignRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /rabin/sign wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: RabinSignRequestBean = req_body.into_inner();
+    let number_theory_service = match query.use_fast {
+        true => NumberTheoryWithPrngService::new(Fast, 13),
+        false => NumberTheoryWithPrngService::new(Slow, 13),
+    };
+
+    call_checked_with_parsed_big_ints(|| {
+        let private_key = req_body.key_pair.to_private_key()?;
+
+        let signature =
+            RabinSignatureScheme::sign(&private_key, &req_body.plaintext, &number_theory_service);
+        let response = RabinSignatureBean {
+            root: signature.root.to_str_radix(10),
+            counter: signature.counter,
+        };
+
+        Ok(HttpResponse::Ok().json(response))
+    })
+}
+
+/// Überprüft eine Rabin-Signatur.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Nachricht, die Signatur und das Schlüsselpaar enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, ob die Signatur gültig ist.
+pub(crate) async fn verify(
+    req_body: Json<RabinVerifyRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /rabin/verify wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: RabinVerifyRequestBean = req_body.into_inner();
+    let number_theory_service = match query.use_fast {
+        true => NumberTheoryWithPrngService::new(Fast, 13),
+        false => NumberTheoryWithPrngService::new(Slow, 13),
+    };
+
+    call_checked_with_parsed_big_ints(|| {
+        let public_key = req_body.key_pair.to_public_key()?;
+        let signature = RabinSignature {
+            root: BigInt::from_str(&req_body.signature.root)?,
+            counter: req_body.signature.counter,
+        };
+
+        let is_valid = RabinSignatureScheme::verify(
+            &public_key,
+            &signature,
+            &req_body.plaintext,
+            &number_theory_service,
+        );
+        let response = SingleStringResponse {
+            message: is_valid.to_string(),
+        };
+
+        Ok(HttpResponse::Ok().json(response))
+    })
+}
@@ -1,19 +1,33 @@
 use crate::api::basic::call_checked_with_parsed_big_ints;
-use crate::api::serializable_models::{SingleStringResponse, UseFastQuery};
+use crate::api::serializable_models::{PrngBackendBean, SingleStringResponse, UseFastQuery};
 use crate::encryption::asymmetric_encryption_types::{
     AsymmetricDecryptor, AsymmetricEncryptor, AsymmetricKeyPair, KeyGenerator, Signer, Verifier,
 };
-use crate::encryption::core::rsa::keys::{RsaPrivateKey, RsaPublicKey};
+use crate::encryption::core::rsa::key_encoding::{
+    decode_key_pem, encode_private_key_pem, encode_public_key_pem, RsaPemKey, RsaPrivateKeyFormat,
+    RsaPublicKeyFormat,
+};
+use crate::encryption::core::rsa::keys::{
+    RsaCrtParameters, RsaKeyPair, RsaOtherPrimeInfo, RsaPrivateKey, RsaPublicKey,
+};
+use crate::encryption::core::rsa::rsa_pkcs1v15_signature_scheme::RsaPkcs1v15SignatureScheme;
+use crate::encryption::core::rsa::rsa_pss_scheme::RsaPssScheme;
 use crate::encryption::core::rsa::rsa_scheme::{RsaKeyGenConfig, RsaScheme};
+use crate::encryption::string_schemes::decimal_unicode_schemes::from_decimal_block_scheme::FromDecimalBlockScheme;
+use crate::encryption::string_schemes::decimal_unicode_schemes::keys::DecimalUnicodeConversionSchemeKey;
 use crate::encryption::string_schemes::rsa::keys::{
     RsaWithStringPrivateKey, RsaWithStringPublicKey,
 };
+use crate::encryption::string_schemes::rsa::rsa_oaep_with_string_scheme::RsaOaepWithStringScheme;
 use crate::encryption::string_schemes::rsa::rsa_with_string_scheme::RsaWithStringScheme;
+use crate::encryption::symmetric_encryption_types::{SymmetricDecryptor, SymmetricEncryptor};
 use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
 use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
     Fast, Slow,
 };
 use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+use crate::math_core::pseudo_random_number_generator::{PrngBackend, PseudoRandomNumberGenerator};
+use crate::math_core::random_source::PrngRandomSource;
 use crate::math_core::traits::logarithm::Logarithm;
 use actix_web::http::StatusCode;
 use actix_web::web::{Json, Query};
@@ -28,7 +42,11 @@ pub struct RsaCreateKeyPairRequestBean {
     pub modulus_width: u32,
     pub miller_rabin_rounds: u32,
     pub random_seed: u32,
+    #[serde(default)]
+    pub prng_backend: PrngBackendBean,
     pub number_system_base: u32,
+    /// Die Anzahl der Primfaktoren (2 bis 5). Ist sie nicht gesetzt, werden 2 verwendet.
+    pub prime_count: Option<u32>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -38,9 +56,64 @@ pub struct RsaKeyPairBean {
     pub d: String,
     pub block_size_pub: String,
     pub block_size_priv: String,
+    /// Die optionalen CRT-Parameter des privaten Schlüssels.
+    pub p: Option<String>,
+    pub q: Option<String>,
+    pub d_p: Option<String>,
+    pub d_q: Option<String>,
+    pub q_inv: Option<String>,
+    /// Die weiteren Primfaktoren bei Multi-Prime-RSA.
+    pub other_primes: Option<Vec<RsaOtherPrimeInfoBean>>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RsaOtherPrimeInfoBean {
+    pub r: String,
+    pub d: String,
+    pub t: String,
 }
 
 impl RsaKeyPairBean {
+    /// Serialisiert ein Schlüsselpaar inklusive der Blockgrößen zur gegebenen Basis.
+    ///
+    /// # Argumente
+    /// * `key_pair` - Das Schlüsselpaar.
+    /// * `number_system_base` - Die Basis, für die die Blockgrößen berechnet werden.
+    ///
+    /// # Rückgabe
+    /// * `RsaKeyPairBean` - Das serialisierte Schlüsselpaar.
+    fn from_key_pair(key_pair: &RsaKeyPair, number_system_base: u32) -> RsaKeyPairBean {
+        let public_key = key_pair.public();
+        let private_key = key_pair.private();
+
+        let block_size_pub = public_key.n.log(&number_system_base.into());
+        let block_size_priv = private_key.n.log(&number_system_base.into()) + 1;
+
+        let crt = private_key.crt.as_ref();
+        RsaKeyPairBean {
+            modulus: public_key.n.to_str_radix(10),
+            e: public_key.e.to_str_radix(10),
+            d: private_key.d.to_str_radix(10),
+            block_size_pub: block_size_pub.to_string(),
+            block_size_priv: block_size_priv.to_string(),
+            p: crt.map(|crt| crt.p.to_str_radix(10)),
+            q: crt.map(|crt| crt.q.to_str_radix(10)),
+            d_p: crt.map(|crt| crt.d_p.to_str_radix(10)),
+            d_q: crt.map(|crt| crt.d_q.to_str_radix(10)),
+            q_inv: crt.map(|crt| crt.q_inv.to_str_radix(10)),
+            other_primes: crt.map(|crt| {
+                crt.other_primes
+                    .iter()
+                    .map(|other_prime| RsaOtherPrimeInfoBean {
+                        r: other_prime.r.to_str_radix(10),
+                        d: other_prime.d.to_str_radix(10),
+                        t: other_prime.t.to_str_radix(10),
+                    })
+                    .collect()
+            }),
+        }
+    }
+
     /// Wandelt das serialisierte Schlüsselpaar in einen privaten Schlüssel um.
     ///
     /// # Rückgabe
@@ -53,9 +126,42 @@ impl RsaKeyPairBean {
         Ok(RsaPrivateKey {
             d: self.d.parse()?,
             n: self.modulus.parse()?,
+            crt: self.to_crt_parameters()?,
         })
     }
 
+    /// Wandelt die serialisierten CRT-Parameter um, sofern alle vorhanden sind.
+    ///
+    /// # Rückgabe
+    /// * `Option<RsaCrtParameters>` - Die CRT-Parameter oder `None`, falls einer fehlt.
+    ///
+    /// # Fehler
+    /// * `ParseBigIntError` - Falls die BigInts nicht geparst werden können.
+    fn to_crt_parameters(&self) -> Result<Option<RsaCrtParameters>, ParseBigIntError> {
+        match (&self.p, &self.q, &self.d_p, &self.d_q, &self.q_inv) {
+            (Some(p), Some(q), Some(d_p), Some(d_q), Some(q_inv)) => Ok(Some(RsaCrtParameters {
+                p: p.parse()?,
+                q: q.parse()?,
+                d_p: d_p.parse()?,
+                d_q: d_q.parse()?,
+                q_inv: q_inv.parse()?,
+                other_primes: self
+                    .other_primes
+                    .iter()
+                    .flatten()
+                    .map(|other_prime| {
+                        Ok(RsaOtherPrimeInfo {
+                            r: other_prime.r.parse()?,
+                            d: other_prime.d.parse()?,
+                            t: other_prime.t.parse()?,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, ParseBigIntError>>()?,
+            })),
+            _ => Ok(None),
+        }
+    }
+
     /// Wandelt das serialisierte Schlüsselpaar in einen öffentlichen Schlüssel um.
     ///
     /// # Rückgabe
@@ -72,11 +178,37 @@ impl RsaKeyPairBean {
     }
 }
 
+/// Das Padding-Verfahren, mit dem bei der Ver- und Entschlüsselung gearbeitet wird.
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RsaEncryptionPadding {
+    /// Lehrbuch-RSA ohne Padding über die Decimal-Unicode-Abbildung.
+    #[default]
+    Textbook,
+    /// RSAES-OAEP nach RFC 8017 mit SHA256 und MGF1.
+    Oaep,
+}
+
 #[derive(Deserialize)]
 pub struct RsaEncryptDecryptRequestBean {
     pub message: String,
     pub key_pair: RsaKeyPairBean,
     pub number_system_base: u32,
+    /// Das zu verwendende Padding. Ist es nicht gesetzt, wird Lehrbuch-RSA verwendet.
+    pub padding: Option<RsaEncryptionPadding>,
+}
+
+/// Die Kodierung, mit der beim Signieren und Verifizieren gearbeitet wird.
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RsaSignatureEncoding {
+    /// Signatur des Hashes in Dezimaldarstellung über die Decimal-Unicode-Abbildung.
+    #[default]
+    Textbook,
+    /// RSASSA-PKCS1-v1_5 nach RFC 8017 mit SHA256.
+    Pkcs1v15,
+    /// RSASSA-PSS nach RFC 8017 mit SHA256 und MGF1.
+    Pss,
 }
 
 #[derive(Deserialize)]
@@ -84,6 +216,8 @@ pub struct RsaSignRequestBean {
     pub plaintext: String,
     pub key_pair: RsaKeyPairBean,
     pub radix: u32,
+    /// Die zu verwendende Kodierung. Ist sie nicht gesetzt, wird die Lehrbuch-Signatur verwendet.
+    pub encoding: Option<RsaSignatureEncoding>,
 }
 
 #[derive(Deserialize)]
@@ -92,6 +226,82 @@ pub struct RsaVerifyRequestBean {
     pub signature: String,
     pub key_pair: RsaKeyPairBean,
     pub radix: u32,
+    /// Die zu verwendende Kodierung. Ist sie nicht gesetzt, wird die Lehrbuch-Signatur verwendet.
+    pub encoding: Option<RsaSignatureEncoding>,
+}
+
+/// Das Format für den Export eines öffentlichen Schlüssels.
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RsaPublicKeyFormatBean {
+    /// SubjectPublicKeyInfo (`PUBLIC KEY`)
+    #[default]
+    Spki,
+    /// PKCS#1 (`RSA PUBLIC KEY`)
+    Pkcs1,
+}
+
+impl From<RsaPublicKeyFormatBean> for RsaPublicKeyFormat {
+    fn from(format: RsaPublicKeyFormatBean) -> Self {
+        match format {
+            RsaPublicKeyFormatBean::Spki => RsaPublicKeyFormat::Spki,
+            RsaPublicKeyFormatBean::Pkcs1 => RsaPublicKeyFormat::Pkcs1,
+        }
+    }
+}
+
+/// Das Format für den Export eines privaten Schlüssels.
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RsaPrivateKeyFormatBean {
+    /// PKCS#8 (`PRIVATE KEY`)
+    #[default]
+    Pkcs8,
+    /// PKCS#1 (`RSA PRIVATE KEY`)
+    Pkcs1,
+}
+
+impl From<RsaPrivateKeyFormatBean> for RsaPrivateKeyFormat {
+    fn from(format: RsaPrivateKeyFormatBean) -> Self {
+        match format {
+            RsaPrivateKeyFormatBean::Pkcs8 => RsaPrivateKeyFormat::Pkcs8,
+            RsaPrivateKeyFormatBean::Pkcs1 => RsaPrivateKeyFormat::Pkcs1,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct RsaExportKeyRequestBean {
+    pub key_pair: RsaKeyPairBean,
+    /// Das Format des öffentlichen Schlüssels. Standardmäßig SubjectPublicKeyInfo.
+    pub public_key_format: Option<RsaPublicKeyFormatBean>,
+    /// Das Format des privaten Schlüssels. Standardmäßig PKCS#8.
+    pub private_key_format: Option<RsaPrivateKeyFormatBean>,
+}
+
+#[derive(Serialize)]
+pub struct RsaExportKeyResponseBean {
+    /// Der öffentliche Schlüssel als PEM.
+    pub public_key: String,
+    /// Der private Schlüssel als PEM.
+    pub private_key: String,
+}
+
+#[derive(Deserialize)]
+pub struct RsaImportKeyRequestBean {
+    /// Der Schlüssel als PEM. Öffentliche Schlüssel im Format PKCS#1 oder SubjectPublicKeyInfo,
+    /// private Schlüssel im Format PKCS#1 oder PKCS#8.
+    #[serde(alias = "private_key")]
+    pub key: String,
+    pub number_system_base: u32,
+}
+
+/// Ein importierter öffentlicher Schlüssel ohne privaten Anteil.
+#[derive(Serialize)]
+pub struct RsaPublicKeyBean {
+    pub modulus: String,
+    pub e: String,
+    pub block_size_pub: String,
 }
 
 #[derive(Deserialize)]
@@ -137,24 +347,23 @@ pub(crate) async fn create_key_pair(
         miller_rabin_iterations: req_body.miller_rabin_rounds,
         random_seed: req_body.random_seed,
         number_theory_service,
+        prime_count: req_body.prime_count.unwrap_or(2),
     };
 
-    let key_pair = RsaScheme::generate_keypair(&config);
-
-    let public_key = key_pair.public();
-    let private_key = key_pair.private();
-
-    let block_size_pub = public_key.n.log(&req_body.number_system_base.into());
-    let block_size_priv = private_key.n.log(&req_body.number_system_base.into()) + 1;
-
-    let key_pair_response = RsaKeyPairBean {
-        modulus: public_key.n.to_str_radix(10),
-        e: public_key.e.to_str_radix(10),
-        d: private_key.d.to_str_radix(10),
-        block_size_pub: block_size_pub.to_string(),
-        block_size_priv: block_size_priv.to_string(),
+    let random = PrngRandomSource::new(PseudoRandomNumberGenerator::with_backend(
+        req_body.prng_backend.with_seed(req_body.random_seed),
+    ));
+    let key_pair = match RsaScheme::generate_keypair_with_source(&config, &random) {
+        Ok(key_pair) => key_pair,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })
+        }
     };
 
+    let key_pair_response = RsaKeyPairBean::from_key_pair(&key_pair, req_body.number_system_base);
+
     HttpResponse::Ok().json(key_pair_response)
 }
 
@@ -174,14 +383,20 @@ pub(crate) async fn encrypt(
 
     let plaintext = req_body.message;
     let number_system_base = req_body.number_system_base;
+    let padding = req_body.padding.unwrap_or_default();
 
     call_checked_with_parsed_big_ints(|| {
         let public_key = req_body.key_pair.to_public_key()?;
 
-        // Hier wird ein Dummy-Seed verwendet, weil er für RSA nicht notwendig ist.
+        // Für Lehrbuch-RSA ist der Seed nicht notwendig. Für OAEP wird ChaCha20 verwendet,
+        // damit die Verschlüsselung nicht deterministisch und der Seed nicht vorhersagbar ist.
+        let backend = match padding {
+            RsaEncryptionPadding::Textbook => PrngBackend::Weyl(13),
+            RsaEncryptionPadding::Oaep => PrngBackend::ChaCha20,
+        };
         let number_theory_service = match use_fast {
-            true => NumberTheoryWithPrngService::new(Fast, 13),
-            false => NumberTheoryWithPrngService::new(Slow, 13),
+            true => NumberTheoryWithPrngService::with_backend(Fast, backend),
+            false => NumberTheoryWithPrngService::with_backend(Slow, backend),
         };
 
         let rsa_with_string_key = RsaWithStringPublicKey {
@@ -189,13 +404,30 @@ pub(crate) async fn encrypt(
             radix: number_system_base,
         };
 
-        let ciphertext =
-            RsaWithStringScheme::encrypt(&rsa_with_string_key, &plaintext, &number_theory_service);
-        let response = SingleStringResponse {
-            message: ciphertext,
+        let ciphertext = match padding {
+            RsaEncryptionPadding::Textbook => Ok(RsaWithStringScheme::encrypt(
+                &rsa_with_string_key,
+                &plaintext,
+                &number_theory_service,
+            )),
+            RsaEncryptionPadding::Oaep => RsaOaepWithStringScheme::encrypt(
+                &rsa_with_string_key,
+                &plaintext,
+                &number_theory_service,
+            ),
         };
 
-        Ok(HttpResponse::Ok().json(response))
+        match ciphertext {
+            Ok(ciphertext) => {
+                let response = SingleStringResponse {
+                    message: ciphertext,
+                };
+                Ok(HttpResponse::Ok().json(response))
+            }
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
     })
 }
 
@@ -218,6 +450,7 @@ pub(crate) async fn decrypt(
     let use_fast = query.use_fast;
     let ciphertext = req_body.message;
     let number_system_base = req_body.number_system_base;
+    let padding = req_body.padding.unwrap_or_default();
 
     call_checked_with_parsed_big_ints(|| {
         let private_key = req_body.key_pair.to_private_key()?;
@@ -233,11 +466,28 @@ pub(crate) async fn decrypt(
             radix: number_system_base,
         };
 
-        let plaintext =
-            RsaWithStringScheme::decrypt(&rsa_with_string_key, &ciphertext, &number_theory_service);
-        let response = SingleStringResponse { message: plaintext };
+        let plaintext = match padding {
+            RsaEncryptionPadding::Textbook => Ok(RsaWithStringScheme::decrypt(
+                &rsa_with_string_key,
+                &ciphertext,
+                &number_theory_service,
+            )),
+            RsaEncryptionPadding::Oaep => RsaOaepWithStringScheme::decrypt(
+                &rsa_with_string_key,
+                &ciphertext,
+                &number_theory_service,
+            ),
+        };
 
-        Ok(HttpResponse::Ok().json(response))
+        match plaintext {
+            Ok(plaintext) => {
+                let response = SingleStringResponse { message: plaintext };
+                Ok(HttpResponse::Ok().json(response))
+            }
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
     })
 }
 
@@ -262,26 +512,55 @@ pub(crate) async fn sign(
 
     let plaintext = req_body.plaintext;
     let radix = req_body.radix;
+    let encoding = req_body.encoding.unwrap_or_default();
 
     call_checked_with_parsed_big_ints(|| {
         let private_key = req_body.key_pair.to_private_key()?;
 
-        // Hier wird ein Dummy-Seed verwendet, weil er für RSA nicht notwendig ist.
+        // Für die Lehrbuch-Signatur und PKCS#1 v1.5 ist der Seed nicht notwendig. Für PSS wird
+        // ChaCha20 verwendet, damit der Salt nicht vorhersagbar ist.
+        let backend = match encoding {
+            RsaSignatureEncoding::Pss => PrngBackend::ChaCha20,
+            _ => PrngBackend::Weyl(13),
+        };
         let number_theory_service = match use_fast {
-            true => NumberTheoryWithPrngService::new(Fast, 13),
-            false => NumberTheoryWithPrngService::new(Slow, 13),
+            true => NumberTheoryWithPrngService::with_backend(Fast, backend),
+            false => NumberTheoryWithPrngService::with_backend(Slow, backend),
         };
 
-        let rsa_with_string_key = RsaWithStringPrivateKey {
-            rsa_private_key: private_key,
-            radix,
+        let signature = match encoding {
+            RsaSignatureEncoding::Textbook => {
+                let rsa_with_string_key = RsaWithStringPrivateKey {
+                    rsa_private_key: private_key,
+                    radix,
+                };
+                Ok(RsaWithStringScheme::sign(
+                    &rsa_with_string_key,
+                    &plaintext,
+                    &number_theory_service,
+                ))
+            }
+            RsaSignatureEncoding::Pkcs1v15 => RsaPkcs1v15SignatureScheme::sign(
+                &private_key,
+                plaintext.as_bytes(),
+                &number_theory_service,
+            )
+            .map(|signature| signature_to_string(&signature, &private_key.n, radix)),
+            RsaSignatureEncoding::Pss => {
+                RsaPssScheme::sign(&private_key, plaintext.as_bytes(), &number_theory_service)
+                    .map(|signature| signature_to_string(&signature, &private_key.n, radix))
+            }
         };
 
-        let signature =
-            RsaWithStringScheme::sign(&rsa_with_string_key, &plaintext, &number_theory_service);
-        let response = SingleStringResponse { message: signature };
-
-        Ok(HttpResponse::Ok().json(response))
+        match signature {
+            Ok(signature) => {
+                let response = SingleStringResponse { message: signature };
+                Ok(HttpResponse::Ok().json(response))
+            }
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
     })
 }
 
@@ -307,6 +586,7 @@ pub(crate) async fn verify(
     let plaintext = req_body.plaintext;
     let signature = req_body.signature;
     let radix = req_body.radix;
+    let encoding = req_body.encoding.unwrap_or_default();
 
     call_checked_with_parsed_big_ints(|| {
         let public_key = req_body.key_pair.to_public_key()?;
@@ -317,25 +597,145 @@ pub(crate) async fn verify(
             false => NumberTheoryWithPrngService::new(Slow, 13),
         };
 
-        let rsa_with_string_key = RsaWithStringPublicKey {
-            rsa_public_key: public_key,
-            radix,
+        let is_valid = match encoding {
+            RsaSignatureEncoding::Textbook => {
+                let rsa_with_string_key = RsaWithStringPublicKey {
+                    rsa_public_key: public_key,
+                    radix,
+                };
+                RsaWithStringScheme::verify(
+                    &rsa_with_string_key,
+                    &signature,
+                    &plaintext,
+                    &number_theory_service,
+                )
+            }
+            RsaSignatureEncoding::Pkcs1v15 => {
+                match signature_from_string(&signature, &public_key.n, radix) {
+                    Some(signature) => RsaPkcs1v15SignatureScheme::verify(
+                        &public_key,
+                        &signature,
+                        plaintext.as_bytes(),
+                        &number_theory_service,
+                    ),
+                    None => false,
+                }
+            }
+            RsaSignatureEncoding::Pss => {
+                match signature_from_string(&signature, &public_key.n, radix) {
+                    Some(signature) => RsaPssScheme::verify(
+                        &public_key,
+                        &signature,
+                        plaintext.as_bytes(),
+                        &number_theory_service,
+                    ),
+                    None => false,
+                }
+            }
         };
-
-        let plaintext = RsaWithStringScheme::verify(
-            &rsa_with_string_key,
-            &signature,
-            &plaintext,
-            &number_theory_service,
-        );
         let response = SingleStringResponse {
-            message: plaintext.to_string(),
+            message: is_valid.to_string(),
         };
 
         Ok(HttpResponse::Ok().json(response))
     })
 }
 
+/// Wandelt eine Signatur in einen String um. Die Signatur wird dabei wie ein einzelner
+/// Chiffretext-Block unter der Decimal-Unicode-Abbildung zur gegebenen Basis dargestellt.
+///
+/// # Argumente
+/// * `signature` - Die Signatur.
+/// * `modulus` - Der Modulus des Schlüssels, aus dem die Blockgröße bestimmt wird.
+/// * `radix` - Die Basis der Abbildung.
+fn signature_to_string(signature: &BigInt, modulus: &BigInt, radix: u32) -> String {
+    let key = DecimalUnicodeConversionSchemeKey {
+        radix,
+        block_size: modulus.log(&radix.into()) + 1,
+    };
+    FromDecimalBlockScheme::encrypt(&vec![signature.clone()], &key)
+}
+
+/// Wandelt einen mit `signature_to_string` erzeugten String zurück in die Signatur um.
+///
+/// # Rückgabe
+/// * `Option<BigInt>` - Die Signatur oder `None`, falls der String nicht genau einen Block enthält.
+fn signature_from_string(signature: &str, modulus: &BigInt, radix: u32) -> Option<BigInt> {
+    let key = DecimalUnicodeConversionSchemeKey {
+        radix,
+        block_size: modulus.log(&radix.into()) + 1,
+    };
+    let mut blocks = FromDecimalBlockScheme::decrypt(signature, &key);
+    match blocks.len() {
+        1 => blocks.pop(),
+        _ => None,
+    }
+}
+
+/// Endpunkt zum Exportieren eines Schlüsselpaares als PEM.
+/// Für den privaten Schlüssel werden die CRT-Parameter benötigt.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die das Schlüsselpaar und die gewünschten Formate enthält.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die die beiden PEM-Blöcke enthält.
+pub(crate) async fn export_key(req_body: Json<RsaExportKeyRequestBean>) -> impl Responder {
+    info!("Endpunkt /rsa/exportKey wurde aufgerufen");
+    let req_body: RsaExportKeyRequestBean = req_body.into_inner();
+    let public_key_format = req_body.public_key_format.unwrap_or_default().into();
+    let private_key_format = req_body.private_key_format.unwrap_or_default().into();
+
+    call_checked_with_parsed_big_ints(|| {
+        let key_pair = RsaKeyPair {
+            public_key: req_body.key_pair.to_public_key()?,
+            private_key: req_body.key_pair.to_private_key()?,
+        };
+
+        let public_key = encode_public_key_pem(&key_pair.public_key, public_key_format);
+        match encode_private_key_pem(&key_pair, private_key_format) {
+            Ok(private_key) => Ok(HttpResponse::Ok().json(RsaExportKeyResponseBean {
+                public_key,
+                private_key,
+            })),
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
+
+/// Endpunkt zum Importieren eines Schlüssels im PEM-Format, z.B. aus OpenSSL. Aus einem privaten
+/// Schlüssel wird das Schlüsselpaar gelesen, aus einem öffentlichen nur Modulus und Exponent.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die den Schlüssel und die Basis enthält.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die das Schlüsselpaar bzw. den öffentlichen Schlüssel enthält.
+pub(crate) async fn import_key(req_body: Json<RsaImportKeyRequestBean>) -> impl Responder {
+    info!("Endpunkt /rsa/importKey wurde aufgerufen");
+    let req_body: RsaImportKeyRequestBean = req_body.into_inner();
+
+    match decode_key_pem(&req_body.key) {
+        Ok(RsaPemKey::KeyPair(key_pair)) => HttpResponse::Ok().json(RsaKeyPairBean::from_key_pair(
+            &key_pair,
+            req_body.number_system_base,
+        )),
+        Ok(RsaPemKey::Public(public_key)) => HttpResponse::Ok().json(RsaPublicKeyBean {
+            modulus: public_key.n.to_str_radix(10),
+            e: public_key.e.to_str_radix(10),
+            block_size_pub: public_key
+                .n
+                .log(&req_body.number_system_base.into())
+                .to_string(),
+        }),
+        Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: e.to_string(),
+        }),
+    }
+}
+
 /// Multipliziert zwei Zahlen miteinander.
 pub(crate) async fn multiplication(
     req_body: Json<RsaMultiplicationRequestBean>,
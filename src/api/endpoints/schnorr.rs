@@ -0,0 +1,636 @@
+use crate::api::basic::call_checked_with_parsed_big_ints;
+use crate::api::endpoints::dsa::DsaDomainParametersBean;
+use crate::api::endpoints::mv::{EcPointBean, EllipticCurveBean};
+use crate::api::serializable_models::{PrngBackendBean, SingleStringResponse, UseFastQuery};
+use crate::encryption::asymmetric_encryption_types::{Signer, Verifier};
+use crate::encryption::core::dsa::domain_parameters::DsaDomainParameters;
+use crate::encryption::core::schnorr::keys::{SchnorrKeyPair, SchnorrPrivateKey, SchnorrPublicKey};
+use crate::encryption::core::schnorr::schnorr_group::SchnorrGroup;
+use crate::encryption::core::schnorr::schnorr_scheme::{SchnorrScheme, SchnorrSignature};
+use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
+use crate::math_core::ecc::secure_finite_field_elliptic_curve::SecureFiniteFieldEllipticCurve;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
+    Fast, Slow,
+};
+use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+use crate::shared::errors::SchnorrError;
+use actix_web::web::{Json, Query};
+use actix_web::{HttpResponse, Responder};
+use anyhow::Result;
+use bigdecimal::num_bigint::{BigInt, ParseBigIntError};
+use log::info;
+use serde::{Deserialize, Serialize};
+
+/// Die Parameter für die Erzeugung einer neuen Gruppe.
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SchnorrGroupParametersBean {
+    /// Untergruppe von `Z_p^*` mit DSA-Domain-Parametern.
+    PrimeField {
+        modulus_width: u32,
+        subgroup_width: u32,
+    },
+    /// Untergruppe einer sicheren elliptischen Kurve `y^2 = x^3 - n^2 x`.
+    EllipticCurve { modulus_width: u32, coef_a: i32 },
+}
+
+#[derive(Deserialize)]
+pub struct SchnorrCreateKeyPairRequestBean {
+    pub group_parameters: SchnorrGroupParametersBean,
+    pub miller_rabin_rounds: u32,
+    pub random_seed: u32,
+    #[serde(default)]
+    pub prng_backend: PrngBackendBean,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SchnorrGroupBean {
+    PrimeField {
+        domain_parameters: DsaDomainParametersBean,
+    },
+    EllipticCurve {
+        curve: EllipticCurveBean,
+    },
+}
+
+/// Ein Gruppenelement: eine Zahl in `Z_p^*` oder ein Punkt auf der Kurve.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum SchnorrElementBean {
+    Number(String),
+    Point(EcPointBean),
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SchnorrPublicKeyBean {
+    pub group: SchnorrGroupBean,
+    pub y: SchnorrElementBean,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SchnorrPrivateKeyBean {
+    pub group: SchnorrGroupBean,
+    pub x: String,
+}
+
+#[derive(Serialize)]
+pub struct SchnorrKeyPairBean {
+    pub public_key: SchnorrPublicKeyBean,
+    pub private_key: SchnorrPrivateKeyBean,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SchnorrSignatureBean {
+    pub e: String,
+    pub s: String,
+}
+
+impl From<SchnorrSignature> for SchnorrSignatureBean {
+    fn from(signature: SchnorrSignature) -> Self {
+        SchnorrSignatureBean {
+            e: signature.e.to_string(),
+            s: signature.s.to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct SchnorrSignRequestBean {
+    pub private_key: SchnorrPrivateKeyBean,
+    pub message: String,
+    pub random_seed: u32,
+    #[serde(default)]
+    pub prng_backend: PrngBackendBean,
+}
+
+#[derive(Deserialize)]
+pub struct SchnorrVerifyRequestBean {
+    pub public_key: SchnorrPublicKeyBean,
+    pub message: String,
+    pub signature: SchnorrSignatureBean,
+}
+
+#[derive(Deserialize)]
+pub struct SchnorrCommitRequestBean {
+    pub group: SchnorrGroupBean,
+    pub random_seed: u32,
+    #[serde(default)]
+    pub prng_backend: PrngBackendBean,
+}
+
+#[derive(Serialize)]
+pub struct SchnorrCommitmentBean {
+    /// Die geheime Zufallszahl des Beweisers, die für `respond` benötigt wird.
+    pub k: String,
+    pub r: SchnorrElementBean,
+}
+
+#[derive(Deserialize)]
+pub struct SchnorrChallengeRequestBean {
+    pub group: SchnorrGroupBean,
+    pub random_seed: u32,
+    #[serde(default)]
+    pub prng_backend: PrngBackendBean,
+}
+
+#[derive(Serialize)]
+pub struct SchnorrChallengeBean {
+    pub e: String,
+}
+
+#[derive(Deserialize)]
+pub struct SchnorrRespondRequestBean {
+    pub private_key: SchnorrPrivateKeyBean,
+    pub k: String,
+    pub e: String,
+}
+
+#[derive(Serialize)]
+pub struct SchnorrResponseBean {
+    pub s: String,
+}
+
+#[derive(Deserialize)]
+pub struct SchnorrVerifyResponseRequestBean {
+    pub public_key: SchnorrPublicKeyBean,
+    pub r: SchnorrElementBean,
+    pub e: String,
+    pub s: String,
+}
+
+/// Bildet eine Gruppe und ihre Elemente auf die Beans ab und zurück.
+trait SchnorrGroupMapping: SchnorrGroup {
+    /// Der Typ der Gruppe, wie er in Fehlermeldungen erscheint.
+    const GROUP_TYPE: &'static str;
+
+    fn to_bean(&self) -> SchnorrGroupBean;
+
+    fn element_to_bean(element: Self::Element) -> SchnorrElementBean;
+
+    /// Gibt `None` zurück, falls das Element nicht zum Typ der Gruppe passt.
+    fn element_from_bean(
+        bean: SchnorrElementBean,
+    ) -> Result<Option<Self::Element>, ParseBigIntError>;
+}
+
+impl SchnorrGroupMapping for DsaDomainParameters {
+    const GROUP_TYPE: &'static str = "prime_field";
+
+    fn to_bean(&self) -> SchnorrGroupBean {
+        SchnorrGroupBean::PrimeField {
+            domain_parameters: self.clone().into(),
+        }
+    }
+
+    fn element_to_bean(element: BigInt) -> SchnorrElementBean {
+        SchnorrElementBean::Number(element.to_string())
+    }
+
+    fn element_from_bean(bean: SchnorrElementBean) -> Result<Option<BigInt>, ParseBigIntError> {
+        match bean {
+            SchnorrElementBean::Number(number) => Ok(Some(number.parse()?)),
+            SchnorrElementBean::Point(_) => Ok(None),
+        }
+    }
+}
+
+impl SchnorrGroupMapping for SecureFiniteFieldEllipticCurve {
+    const GROUP_TYPE: &'static str = "elliptic_curve";
+
+    fn to_bean(&self) -> SchnorrGroupBean {
+        SchnorrGroupBean::EllipticCurve {
+            curve: self.clone().into(),
+        }
+    }
+
+    fn element_to_bean(element: FiniteFieldEllipticCurvePoint) -> SchnorrElementBean {
+        SchnorrElementBean::Point(element.into())
+    }
+
+    fn element_from_bean(
+        bean: SchnorrElementBean,
+    ) -> Result<Option<FiniteFieldEllipticCurvePoint>, ParseBigIntError> {
+        match bean {
+            SchnorrElementBean::Point(point) => Ok(Some(FiniteFieldEllipticCurvePoint {
+                x: point.x.parse()?,
+                y: point.y.parse()?,
+                is_infinite: point.is_infinite,
+            })),
+            SchnorrElementBean::Number(_) => Ok(None),
+        }
+    }
+}
+
+/// Erstellt ein neues Schlüsselpaar für das Schnorr-Verfahren. Dabei wird zunächst eine
+/// passende Gruppe (Untergruppe von `Z_p^*` oder einer elliptischen Kurve) erzeugt.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Parameter für die Gruppe enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die das Schlüsselpaar enthält.
+pub(crate) async fn create_key_pair(
+    req_body: Json<SchnorrCreateKeyPairRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /schnorr/createKeyPair wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: SchnorrCreateKeyPairRequestBean = req_body.into_inner();
+    let backend = req_body.prng_backend.with_seed(req_body.random_seed);
+    let service = match query.use_fast {
+        true => NumberTheoryWithPrngService::with_backend(Fast, backend),
+        false => NumberTheoryWithPrngService::with_backend(Slow, backend),
+    };
+    let miller_rabin_rounds = req_body.miller_rabin_rounds;
+
+    let result = match req_body.group_parameters {
+        SchnorrGroupParametersBean::PrimeField {
+            modulus_width,
+            subgroup_width,
+        } => DsaDomainParameters::generate(
+            modulus_width,
+            subgroup_width,
+            miller_rabin_rounds,
+            &service,
+        )
+        .and_then(|group| create_key_pair_in_group(&group, &service)),
+        SchnorrGroupParametersBean::EllipticCurve {
+            modulus_width,
+            coef_a,
+        } => SecureFiniteFieldEllipticCurve::new(
+            coef_a.into(),
+            modulus_width,
+            miller_rabin_rounds,
+            &service,
+        )
+        .and_then(|group| create_key_pair_in_group(&group, &service)),
+    };
+    match result {
+        Ok(key_pair) => HttpResponse::Ok().json(key_pair),
+        Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: e.to_string(),
+        }),
+    }
+}
+
+/// Signiert eine Nachricht mit dem Schnorr-Verfahren.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Nachricht und den privaten Schlüssel enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die die Signatur enthält.
+pub(crate) async fn sign(
+    req_body: Json<SchnorrSignRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /schnorr/sign wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: SchnorrSignRequestBean = req_body.into_inner();
+    let backend = req_body.prng_backend.with_seed(req_body.random_seed);
+    let service = match query.use_fast {
+        true => NumberTheoryWithPrngService::with_backend(Fast, backend),
+        false => NumberTheoryWithPrngService::with_backend(Slow, backend),
+    };
+
+    let key = req_body.private_key;
+    call_checked_with_parsed_big_ints(|| match key.group.clone() {
+        SchnorrGroupBean::PrimeField { domain_parameters } => sign_in_group(
+            DsaDomainParameters::try_from(domain_parameters)?,
+            &key.x,
+            &req_body.message,
+            &service,
+        ),
+        SchnorrGroupBean::EllipticCurve { curve } => sign_in_group(
+            SecureFiniteFieldEllipticCurve::from(curve),
+            &key.x,
+            &req_body.message,
+            &service,
+        ),
+    })
+}
+
+/// Überprüft eine Schnorr-Signatur.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Nachricht, die Signatur und den öffentlichen Schlüssel enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, ob die Signatur gültig ist.
+pub(crate) async fn verify(
+    req_body: Json<SchnorrVerifyRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /schnorr/verify wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: SchnorrVerifyRequestBean = req_body.into_inner();
+    // Hier wird ein Dummy-Seed verwendet, weil die Verifikation keine Zufallszahlen benötigt.
+    let service = match query.use_fast {
+        true => NumberTheoryWithPrngService::new(Fast, 13),
+        false => NumberTheoryWithPrngService::new(Slow, 13),
+    };
+
+    let key = req_body.public_key;
+    call_checked_with_parsed_big_ints(|| match key.group.clone() {
+        SchnorrGroupBean::PrimeField { domain_parameters } => verify_in_group(
+            DsaDomainParameters::try_from(domain_parameters)?,
+            key.y.clone(),
+            &req_body.signature,
+            &req_body.message,
+            &service,
+        ),
+        SchnorrGroupBean::EllipticCurve { curve } => verify_in_group(
+            SecureFiniteFieldEllipticCurve::from(curve),
+            key.y.clone(),
+            &req_body.signature,
+            &req_body.message,
+            &service,
+        ),
+    })
+}
+
+/// Erster Schritt der interaktiven Identifikation: Der Beweiser erzeugt das Commitment `R = g^k`.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Gruppe enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die `k` und `R` enthält.
+pub(crate) async fn commit(
+    req_body: Json<SchnorrCommitRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /schnorr/commit wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: SchnorrCommitRequestBean = req_body.into_inner();
+    let backend = req_body.prng_backend.with_seed(req_body.random_seed);
+    let service = match query.use_fast {
+        true => NumberTheoryWithPrngService::with_backend(Fast, backend),
+        false => NumberTheoryWithPrngService::with_backend(Slow, backend),
+    };
+
+    call_checked_with_parsed_big_ints(|| match req_body.group.clone() {
+        SchnorrGroupBean::PrimeField { domain_parameters } => Ok(commit_in_group(
+            &DsaDomainParameters::try_from(domain_parameters)?,
+            &service,
+        )),
+        SchnorrGroupBean::EllipticCurve { curve } => Ok(commit_in_group(
+            &SecureFiniteFieldEllipticCurve::from(curve),
+            &service,
+        )),
+    })
+}
+
+/// Zweiter Schritt der interaktiven Identifikation: Der Verifizierer wählt eine Challenge `e`.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Gruppe enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die die Challenge enthält.
+pub(crate) async fn challenge(
+    req_body: Json<SchnorrChallengeRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /schnorr/challenge wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: SchnorrChallengeRequestBean = req_body.into_inner();
+    let backend = req_body.prng_backend.with_seed(req_body.random_seed);
+    let service = match query.use_fast {
+        true => NumberTheoryWithPrngService::with_backend(Fast, backend),
+        false => NumberTheoryWithPrngService::with_backend(Slow, backend),
+    };
+
+    call_checked_with_parsed_big_ints(|| {
+        let e = match req_body.group.clone() {
+            SchnorrGroupBean::PrimeField { domain_parameters } => SchnorrScheme::challenge(
+                &DsaDomainParameters::try_from(domain_parameters)?,
+                &service,
+            ),
+            SchnorrGroupBean::EllipticCurve { curve } => {
+                SchnorrScheme::challenge(&SecureFiniteFieldEllipticCurve::from(curve), &service)
+            }
+        };
+        Ok(HttpResponse::Ok().json(SchnorrChallengeBean { e: e.to_string() }))
+    })
+}
+
+/// Dritter Schritt der interaktiven Identifikation: Der Beweiser beantwortet die Challenge
+/// mit `s = k + x * e mod q`.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die den privaten Schlüssel, `k` und `e` enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die `s` enthält.
+pub(crate) async fn respond(
+    req_body: Json<SchnorrRespondRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /schnorr/respond wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: SchnorrRespondRequestBean = req_body.into_inner();
+
+    let key = req_body.private_key;
+    call_checked_with_parsed_big_ints(|| match key.group.clone() {
+        SchnorrGroupBean::PrimeField { domain_parameters } => respond_in_group(
+            DsaDomainParameters::try_from(domain_parameters)?,
+            &key.x,
+            &req_body.k,
+            &req_body.e,
+        ),
+        SchnorrGroupBean::EllipticCurve { curve } => respond_in_group(
+            SecureFiniteFieldEllipticCurve::from(curve),
+            &key.x,
+            &req_body.k,
+            &req_body.e,
+        ),
+    })
+}
+
+/// Letzter Schritt der interaktiven Identifikation: Der Verifizierer prüft `g^s = R * y^e`.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die den öffentlichen Schlüssel, `R`, `e` und `s` enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, ob die Identifikation erfolgreich war.
+pub(crate) async fn verify_response(
+    req_body: Json<SchnorrVerifyResponseRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /schnorr/verifyResponse wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: SchnorrVerifyResponseRequestBean = req_body.into_inner();
+    // Hier wird ein Dummy-Seed verwendet, weil die Verifikation keine Zufallszahlen benötigt.
+    let service = match query.use_fast {
+        true => NumberTheoryWithPrngService::new(Fast, 13),
+        false => NumberTheoryWithPrngService::new(Slow, 13),
+    };
+
+    let key = req_body.public_key;
+    let (r, e, s) = (req_body.r, req_body.e, req_body.s);
+    call_checked_with_parsed_big_ints(|| match key.group.clone() {
+        SchnorrGroupBean::PrimeField { domain_parameters } => verify_response_in_group(
+            DsaDomainParameters::try_from(domain_parameters)?,
+            key.y.clone(),
+            r.clone(),
+            &e,
+            &s,
+            &service,
+        ),
+        SchnorrGroupBean::EllipticCurve { curve } => verify_response_in_group(
+            SecureFiniteFieldEllipticCurve::from(curve),
+            key.y.clone(),
+            r.clone(),
+            &e,
+            &s,
+            &service,
+        ),
+    })
+}
+
+fn create_key_pair_in_group<G: SchnorrGroupMapping>(
+    group: &G,
+    service: &NumberTheoryWithPrngService,
+) -> Result<SchnorrKeyPairBean> {
+    let SchnorrKeyPair {
+        public_key,
+        private_key,
+    } = SchnorrScheme::generate_keypair(group, service)?;
+    Ok(SchnorrKeyPairBean {
+        public_key: SchnorrPublicKeyBean {
+            group: public_key.group.to_bean(),
+            y: G::element_to_bean(public_key.y),
+        },
+        private_key: SchnorrPrivateKeyBean {
+            group: private_key.group.to_bean(),
+            x: private_key.x.to_string(),
+        },
+    })
+}
+
+fn sign_in_group<G: SchnorrGroupMapping>(
+    group: G,
+    x: &str,
+    message: &str,
+    service: &NumberTheoryWithPrngService,
+) -> Result<HttpResponse, ParseBigIntError> {
+    let key = SchnorrPrivateKey {
+        group,
+        x: x.parse()?,
+    };
+    Ok(match SchnorrScheme::sign(&key, message, service) {
+        Ok(signature) => HttpResponse::Ok().json(SchnorrSignatureBean::from(signature)),
+        Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: e.to_string(),
+        }),
+    })
+}
+
+fn verify_in_group<G: SchnorrGroupMapping>(
+    group: G,
+    y: SchnorrElementBean,
+    signature: &SchnorrSignatureBean,
+    message: &str,
+    service: &NumberTheoryWithPrngService,
+) -> Result<HttpResponse, ParseBigIntError> {
+    let Some(y) = G::element_from_bean(y)? else {
+        return Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+            message: SchnorrError::ElementTypeMismatchError(G::GROUP_TYPE.into()).to_string(),
+        }));
+    };
+    let key = SchnorrPublicKey { group, y };
+    let signature = SchnorrSignature {
+        e: signature.e.parse()?,
+        s: signature.s.parse()?,
+    };
+    Ok(
+        match SchnorrScheme::verify(&key, &signature, message, service) {
+            Ok(is_valid) => HttpResponse::Ok().json(SingleStringResponse {
+                message: is_valid.to_string(),
+            }),
+            Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            }),
+        },
+    )
+}
+
+fn commit_in_group<G: SchnorrGroupMapping>(
+    group: &G,
+    service: &NumberTheoryWithPrngService,
+) -> HttpResponse {
+    match SchnorrScheme::commit(group, service) {
+        Ok(commitment) => HttpResponse::Ok().json(SchnorrCommitmentBean {
+            k: commitment.k.to_string(),
+            r: G::element_to_bean(commitment.r),
+        }),
+        Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: e.to_string(),
+        }),
+    }
+}
+
+fn respond_in_group<G: SchnorrGroupMapping>(
+    group: G,
+    x: &str,
+    k: &str,
+    e: &str,
+) -> Result<HttpResponse, ParseBigIntError> {
+    let key = SchnorrPrivateKey {
+        group,
+        x: x.parse()?,
+    };
+    let s = SchnorrScheme::respond(&key, &k.parse()?, &e.parse()?);
+    Ok(HttpResponse::Ok().json(SchnorrResponseBean { s: s.to_string() }))
+}
+
+fn verify_response_in_group<G: SchnorrGroupMapping>(
+    group: G,
+    y: SchnorrElementBean,
+    r: SchnorrElementBean,
+    e: &str,
+    s: &str,
+    service: &NumberTheoryWithPrngService,
+) -> Result<HttpResponse, ParseBigIntError> {
+    let (Some(y), Some(r)) = (G::element_from_bean(y)?, G::element_from_bean(r)?) else {
+        return Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+            message: SchnorrError::ElementTypeMismatchError(G::GROUP_TYPE.into()).to_string(),
+        }));
+    };
+    let key = SchnorrPublicKey { group, y };
+    let (e, s) = (e.parse()?, s.parse()?);
+    Ok(
+        match SchnorrScheme::verify_response(&key, &r, &e, &s, service) {
+            Ok(is_valid) => HttpResponse::Ok().json(SingleStringResponse {
+                message: is_valid.to_string(),
+            }),
+            Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            }),
+        },
+    )
+}
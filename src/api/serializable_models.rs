@@ -1,5 +1,9 @@
 use serde::{Deserialize, Serialize};
 
+use crate::math_core::number_theory::prime_sieve::IncrementalPrimeSearch;
+use crate::math_core::prime_generator::PrimeSearch;
+use crate::math_core::pseudo_random_number_generator::PrngBackend;
+
 #[derive(Serialize)]
 pub struct SingleStringResponse {
     pub message: String,
@@ -9,3 +13,54 @@ pub struct SingleStringResponse {
 pub struct UseFastQuery {
     pub use_fast: bool,
 }
+
+/// Die Quelle der Zufallsfolge einer Anfrage, siehe `PrngBackend`. Ohne Angabe wird die
+/// reproduzierbare Weyl-Folge zum Seed der Anfrage verwendet.
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PrngBackendBean {
+    /// Die Weyl-Folge des `PseudoRandomNumberGenerator` zum Seed der Anfrage.
+    #[default]
+    Weyl,
+    /// HMAC_DRBG, geseedet mit Entropie des Betriebssystems.
+    HmacDrbg,
+    /// ChaCha20, geseedet mit Entropie des Betriebssystems.
+    #[serde(rename = "chacha20")]
+    ChaCha20,
+    /// Blum Blum Shub, geseedet mit Entropie des Betriebssystems.
+    BlumBlumShub,
+}
+
+impl PrngBackendBean {
+    /// Gibt die Quelle der Zufallsfolge zurück, bei der Weyl-Folge mit dem Seed der Anfrage.
+    pub fn with_seed(self, random_seed: u32) -> PrngBackend {
+        match self {
+            PrngBackendBean::Weyl => PrngBackend::Weyl(random_seed),
+            PrngBackendBean::HmacDrbg => PrngBackend::HmacDrbg,
+            PrngBackendBean::ChaCha20 => PrngBackend::ChaCha20,
+            PrngBackendBean::BlumBlumShub => PrngBackend::BlumBlumShub,
+        }
+    }
+}
+
+/// Das Verfahren, mit dem eine Anfrage Primzahlen sucht, siehe `PrimeSearch`.
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PrimeSearchBean {
+    /// Nach jedem gescheiterten Test wird ein neuer Zufallskandidat gezogen.
+    #[default]
+    Random,
+    /// Ab einem zufälligen Startwert wird ein Intervall mit kleinen Primzahlen gesiebt.
+    Incremental,
+}
+
+impl From<PrimeSearchBean> for PrimeSearch {
+    fn from(bean: PrimeSearchBean) -> Self {
+        match bean {
+            PrimeSearchBean::Random => PrimeSearch::Random,
+            PrimeSearchBean::Incremental => {
+                PrimeSearch::Incremental(IncrementalPrimeSearch::default())
+            }
+        }
+    }
+}
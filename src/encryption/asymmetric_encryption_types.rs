@@ -2,6 +2,8 @@ use crate::encryption::encryption_types::{Decryptor, EncryptionScheme, Encryptor
 use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
 
 use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+use crate::math_core::random_source::{PrngRandomSource, RandomSource};
+use anyhow::Result;
 use std::fmt::Debug;
 
 /// Ein asymmetrisches Verschlüsselungsschema.
@@ -57,6 +59,8 @@ where
 ///
 /// # Methoden
 /// * `generate_keypair` - Generiert ein Schlüsselpaar für das asymmetrische Verschlüsselungsschema.
+/// * `generate_keypair_with_source` - Wie `generate_keypair`, aber mit einer beliebigen Quelle
+///   für die Zufallszahlen.
 pub trait KeyGenerator<Public, Private, Scheme>
 where
     Public: AsymmetricEncryptionKey<Scheme>,
@@ -64,14 +68,38 @@ where
     Scheme: AsymmetricEncryptionScheme,
 {
     type KeyPair: AsymmetricKeyPair<Public, Private, Scheme>;
-    /// Generiert ein Schlüsselpaar für das asymmetrische Verschlüsselungsschema.
+    /// Generiert ein Schlüsselpaar für das asymmetrische Verschlüsselungsschema. Die
+    /// Zufallszahlen stammen aus der Weyl-Folge zum Seed der Konfiguration.
     ///
     /// # Argumente
     /// * `config` - Die Konfiguration für den Schlüsselgenerierungsvorgang.
     ///
     /// # Rückgabe
     /// Ein Tupel aus dem öffentlichen und privaten Schlüssel.
-    fn generate_keypair(config: &impl KeyGenWithPrimeConfig) -> Self::KeyPair;
+    ///
+    /// # Fehler
+    /// Siehe `generate_keypair_with_source`.
+    fn generate_keypair(config: &impl KeyGenWithPrimeConfig) -> Result<Self::KeyPair> {
+        let random = PrngRandomSource::seeded(config.random_seed());
+        Self::generate_keypair_with_source(config, &random)
+    }
+
+    /// Generiert ein Schlüsselpaar für das asymmetrische Verschlüsselungsschema.
+    ///
+    /// # Argumente
+    /// * `config` - Die Konfiguration für den Schlüsselgenerierungsvorgang. Der Seed wird hier
+    ///   nicht verwendet.
+    /// * `random` - Die Quelle aller Zufallszahlen, auch der Zeugen des Miller-Rabin-Tests.
+    ///
+    /// # Rückgabe
+    /// Ein Tupel aus dem öffentlichen und privaten Schlüssel.
+    ///
+    /// # Fehler
+    /// Wenn die Konfiguration für das Verfahren ungültig ist.
+    fn generate_keypair_with_source(
+        config: &impl KeyGenWithPrimeConfig,
+        random: &dyn RandomSource,
+    ) -> Result<Self::KeyPair>;
 }
 
 /// Die Konfiguration für die Schlüsselgenerierung für ein Verschlüsselungsschema, welches Primzahlen verwendet.
@@ -84,6 +112,11 @@ pub trait KeyGenWithPrimeConfig: Debug {
     fn random_seed(&self) -> u32;
     /// Der Service für die Zahlentheorie.
     fn number_theory_service(&self) -> NumberTheoryService;
+    /// Die Anzahl der zu generierenden Primfaktoren, z.B. für Multi-Prime-RSA.
+    /// Verfahren mit einer festen Anzahl an Primzahlen ignorieren diesen Wert.
+    fn prime_count(&self) -> u32 {
+        2
+    }
 }
 
 /// Ein Verschlüsseler für das asymmetrische Verschlüsselungsschema.
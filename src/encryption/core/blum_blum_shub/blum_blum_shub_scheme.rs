@@ -0,0 +1,153 @@
+use anyhow::Result;
+use bigdecimal::num_bigint::BigInt;
+
+use crate::encryption::core::blum_blum_shub::keys::BlumBlumShubKey;
+use crate::encryption::encryption_types::{Decryptor, EncryptionScheme, Encryptor};
+use crate::encryption::symmetric_encryption_types::{
+    SymmetricDecryptor, SymmetricEncryptionScheme, SymmetricEncryptor,
+};
+use crate::math_core::csprng::blum_blum_shub::BlumBlumShub;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+
+/// Eine Stromchiffre, die die Nachricht byteweise per XOR mit dem Schlüsselstrom des
+/// Blum-Blum-Shub-Generators verknüpft. Ver- und Entschlüsselung sind dieselbe Operation.
+///
+/// Wie bei jeder Stromchiffre darf ein Schlüssel nur für eine Nachricht verwendet werden, da
+/// sonst das XOR zweier Geheimtexte das XOR der Klartexte ergibt.
+pub struct BlumBlumShubScheme {}
+
+impl EncryptionScheme for BlumBlumShubScheme {}
+
+impl SymmetricEncryptionScheme for BlumBlumShubScheme {}
+
+impl BlumBlumShubScheme {
+    /// Generiert einen Schlüssel mit zufälligem Modulus aus zwei Blum-Primzahlen und Seed.
+    ///
+    /// # Argumente
+    /// * `modulus_width` - Die Bit-Breite des Modulus.
+    /// * `miller_rabin_iterations` - Die Anzahl der Iterationen für den Miller-Rabin-Test.
+    /// * `service` - Der Service mit der Zufallsfolge.
+    ///
+    /// # Rückgabe
+    /// * `BlumBlumShubKey` - Der Schlüssel.
+    ///
+    /// # Fehler
+    /// * `BlumBlumShubError::InvalidModulusWidthError` - Wenn der Modulus zu schmal ist.
+    pub fn generate_key(
+        modulus_width: u32,
+        miller_rabin_iterations: u32,
+        service: &NumberTheoryWithPrngService,
+    ) -> Result<BlumBlumShubKey> {
+        let modulus = BlumBlumShub::generate(modulus_width, miller_rabin_iterations, service)?
+            .modulus()
+            .clone();
+        let upper = &modulus - BigInt::from(2);
+        loop {
+            let seed = service.take_random_number_in_range(&BigInt::from(2), &upper);
+            if BlumBlumShub::new(modulus.clone(), &seed, service.number_theory_service).is_ok() {
+                return Ok(BlumBlumShubKey { modulus, seed });
+            }
+        }
+    }
+
+    /// Verknüpft die Daten mit dem Schlüsselstrom zum Schlüssel.
+    fn apply_keystream(data: &[u8], key: &BlumBlumShubKey) -> Result<Vec<u8>> {
+        let mut generator = BlumBlumShub::new(
+            key.modulus.clone(),
+            &key.seed,
+            NumberTheoryService::new(Fast),
+        )?;
+        let mut output = data.to_vec();
+        generator.apply_keystream(&mut output);
+        Ok(output)
+    }
+}
+
+impl Encryptor<BlumBlumShubScheme> for BlumBlumShubScheme {
+    type Input = [u8];
+    type Output = Result<Vec<u8>>;
+    type Key = BlumBlumShubKey;
+}
+
+impl SymmetricEncryptor<BlumBlumShubScheme> for BlumBlumShubScheme {
+    /// Verschlüsselt die Nachricht per XOR mit dem Schlüsselstrom.
+    ///
+    /// # Argumente
+    /// * `plaintext` - Die Bytes des Klartextes.
+    /// * `key` - Der Schlüssel.
+    ///
+    /// # Rückgabe
+    /// * `Result<Vec<u8>>` - Die Bytes des Geheimtextes, gleich lang wie der Klartext.
+    ///
+    /// # Fehler
+    /// * `BlumBlumShubError::InvalidSeedError` - Wenn der Seed nicht zum Modulus passt.
+    fn encrypt(plaintext: &Self::Input, key: &Self::Key) -> Self::Output {
+        Self::apply_keystream(plaintext, key)
+    }
+}
+
+impl Decryptor<BlumBlumShubScheme> for BlumBlumShubScheme {
+    type Input = [u8];
+    type Output = Result<Vec<u8>>;
+    type Key = BlumBlumShubKey;
+}
+
+impl SymmetricDecryptor<BlumBlumShubScheme> for BlumBlumShubScheme {
+    /// Entschlüsselt den Geheimtext per XOR mit demselben Schlüsselstrom.
+    ///
+    /// # Argumente
+    /// * `ciphertext` - Die Bytes des Geheimtextes.
+    /// * `key` - Der Schlüssel.
+    ///
+    /// # Rückgabe
+    /// * `Result<Vec<u8>>` - Die Bytes des Klartextes.
+    ///
+    /// # Fehler
+    /// * `BlumBlumShubError::InvalidSeedError` - Wenn der Seed nicht zum Modulus passt.
+    fn decrypt(ciphertext: &Self::Input, key: &Self::Key) -> Self::Output {
+        Self::apply_keystream(ciphertext, key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let service = NumberTheoryWithPrngService::new(Fast, 17);
+        let key = BlumBlumShubScheme::generate_key(256, 20, &service).unwrap();
+        let plaintext = "Geheime Nachricht über Blum Blum Shub".as_bytes();
+
+        let ciphertext = BlumBlumShubScheme::encrypt(plaintext, &key).unwrap();
+        assert_eq!(ciphertext.len(), plaintext.len());
+        assert_ne!(ciphertext, plaintext);
+
+        let decrypted = BlumBlumShubScheme::decrypt(&ciphertext, &key).unwrap();
+        assert_eq!(decrypted, plaintext);
+    }
+
+    #[test]
+    fn test_known_keystream() {
+        // n = 11 · 23 = 253, Seed 3: Die ersten 16 Bits der Folge sind 1001 0100 0001 1011.
+        let key = BlumBlumShubKey {
+            modulus: BigInt::from(253),
+            seed: BigInt::from(3),
+        };
+        assert_eq!(
+            BlumBlumShubScheme::encrypt(&[0x00, 0xff], &key).unwrap(),
+            vec![0x94, 0xe4]
+        );
+    }
+
+    #[test]
+    fn test_invalid_seed_is_rejected() {
+        let key = BlumBlumShubKey {
+            modulus: BigInt::from(253),
+            seed: BigInt::from(11),
+        };
+        assert!(BlumBlumShubScheme::encrypt(b"abc", &key).is_err());
+    }
+}
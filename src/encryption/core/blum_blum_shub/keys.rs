@@ -0,0 +1,25 @@
+use bigdecimal::num_bigint::BigInt;
+
+use crate::encryption::core::blum_blum_shub::blum_blum_shub_scheme::BlumBlumShubScheme;
+use crate::encryption::encryption_types::Key;
+use crate::encryption::symmetric_encryption_types::{
+    SymmetricDecryptionKey, SymmetricEncryptionKey, SymmetricKey,
+};
+
+/// Der gemeinsame Schlüssel der Blum-Blum-Shub-Stromchiffre. Modulus und Seed legen den
+/// Schlüsselstrom fest und werden daher zum Ver- und Entschlüsseln verwendet.
+#[derive(Clone, Debug)]
+pub struct BlumBlumShubKey {
+    /// Der Modulus `n = p · q` aus zwei Blum-Primzahlen.
+    pub modulus: BigInt,
+    /// Der Seed, teilerfremd zu `n`.
+    pub seed: BigInt,
+}
+
+impl Key<BlumBlumShubScheme> for BlumBlumShubKey {}
+
+impl SymmetricKey<BlumBlumShubScheme> for BlumBlumShubKey {}
+
+impl SymmetricEncryptionKey<BlumBlumShubScheme> for BlumBlumShubKey {}
+
+impl SymmetricDecryptionKey<BlumBlumShubScheme> for BlumBlumShubKey {}
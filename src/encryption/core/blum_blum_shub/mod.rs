@@ -0,0 +1,2 @@
+pub mod blum_blum_shub_scheme;
+pub mod keys;
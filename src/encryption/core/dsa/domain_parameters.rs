@@ -0,0 +1,341 @@
+use crate::encryption::core::rsa::data_conversion::integer_to_octets;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceTrait;
+use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+use crate::math_core::traits::increment::Increment;
+use crate::shared::errors::DsaError;
+use crate::shared::hashing::{sha256_bytes, SHA256_LENGTH};
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::{BigInt, Sign};
+use bigdecimal::One;
+use log::{debug, trace};
+
+/// Die Ausgabelänge der verwendeten Hashfunktion SHA256 in Bits (`outlen` aus FIPS 186-4).
+const OUTLEN: u32 = (SHA256_LENGTH * 8) as u32;
+
+/// Der Index für die verifizierbare Generierung von `g` (FIPS 186-4, A.2.3).
+const GENERATOR_INDEX: u8 = 1;
+
+/// Die Domain-Parameter für DSA nach FIPS 186-4.
+///
+/// # Felder
+/// * `p` - Der Modulus mit `L` Bits.
+/// * `q` - Der Primteiler von `p - 1` mit `N` Bits, die Ordnung der Untergruppe.
+/// * `g` - Ein Erzeuger der Untergruppe der Ordnung `q`.
+/// * `domain_parameter_seed` - Der Seed, aus dem `p`, `q` und `g` abgeleitet wurden.
+/// * `counter` - Der Zähler, bei dem `p` gefunden wurde.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DsaDomainParameters {
+    pub p: BigInt,
+    pub q: BigInt,
+    pub g: BigInt,
+    pub domain_parameter_seed: BigInt,
+    pub counter: u32,
+}
+
+impl DsaDomainParameters {
+    /// Generiert Domain-Parameter nach FIPS 186-4, A.1.1.2 (`p` und `q`) und A.2.3 (`g`)
+    /// mit SHA256 als Hashfunktion. Der Seed ist `N` Bits (auf Bytes aufgerundet) lang.
+    ///
+    /// # Argumente
+    /// * `modulus_width` - Die Bit-Breite `L` von `p`.
+    /// * `subgroup_width` - Die Bit-Breite `N` von `q`.
+    /// * `miller_rabin_iterations` - Die Anzahl der Iterationen für den Miller-Rabin-Test.
+    /// * `service` - Der Service für die Zahlentheorie und die Zufallszahlen.
+    ///
+    /// # Rückgabe
+    /// * `DsaDomainParameters` - Die Domain-Parameter inklusive Seed und Zähler.
+    ///
+    /// # Fehler
+    /// * `DsaError::InvalidParameterSizesError` - Falls `L` und `N` nicht zueinander passen.
+    pub fn generate(
+        modulus_width: u32,
+        subgroup_width: u32,
+        miller_rabin_iterations: u32,
+        service: &NumberTheoryWithPrngService,
+    ) -> Result<Self> {
+        ensure!(
+            (8..=OUTLEN).contains(&subgroup_width) && subgroup_width < modulus_width,
+            DsaError::InvalidParameterSizesError(modulus_width, subgroup_width)
+        );
+        debug!(
+            "Generiere DSA-Domain-Parameter mit L = {} und N = {}",
+            modulus_width, subgroup_width
+        );
+
+        let seed_upper_bound = (BigInt::one() << Self::seed_length(subgroup_width)).decrement();
+        loop {
+            let seed = service.take_random_number_in_range(&0.into(), &seed_upper_bound);
+            let q = Self::derive_q(&seed, subgroup_width);
+            if !service.is_probably_prime(&q, miller_rabin_iterations) {
+                trace!("q {} ist keine Primzahl, neuer Seed", q);
+                continue;
+            }
+
+            for counter in 0..4 * modulus_width {
+                let p = match Self::derive_p(&seed, &q, modulus_width, counter) {
+                    Some(p) => p,
+                    None => continue,
+                };
+                if service.is_probably_prime(&p, miller_rabin_iterations) {
+                    debug!("p {} mit counter {} gefunden", p, counter);
+                    let g = Self::derive_g(&seed, &p, &q, service);
+                    return Ok(DsaDomainParameters {
+                        p,
+                        q,
+                        g,
+                        domain_parameter_seed: seed,
+                        counter,
+                    });
+                }
+            }
+            trace!("Kein p für q {} gefunden, neuer Seed", q);
+        }
+    }
+
+    /// Prüft, ob die Domain-Parameter aus dem Seed und dem Zähler hervorgehen
+    /// (FIPS 186-4, A.1.1.3 und A.2.4).
+    ///
+    /// # Argumente
+    /// * `miller_rabin_iterations` - Die Anzahl der Iterationen für den Miller-Rabin-Test.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// `true`, wenn `p`, `q` und `g` korrekt aus dem Seed abgeleitet wurden, ansonsten `false`.
+    pub fn verify(
+        &self,
+        miller_rabin_iterations: u32,
+        service: &NumberTheoryWithPrngService,
+    ) -> bool {
+        let modulus_width = self.p.bits() as u32;
+        let subgroup_width = self.q.bits() as u32;
+        if !(8..=OUTLEN).contains(&subgroup_width)
+            || subgroup_width >= modulus_width
+            || self.counter >= 4 * modulus_width
+            || self.domain_parameter_seed.bits() > Self::seed_length(subgroup_width) as u64
+            || self.domain_parameter_seed.sign() == Sign::Minus
+        {
+            return false;
+        }
+
+        let q = Self::derive_q(&self.domain_parameter_seed, subgroup_width);
+        if q != self.q || !service.is_probably_prime(&q, miller_rabin_iterations) {
+            return false;
+        }
+
+        // Das erste p, das zu einer Primzahl führt, muss genau beim angegebenen Zähler liegen.
+        for counter in 0..=self.counter {
+            let p = match Self::derive_p(&self.domain_parameter_seed, &q, modulus_width, counter) {
+                Some(p) => p,
+                None => continue,
+            };
+            if service.is_probably_prime(&p, miller_rabin_iterations) {
+                return counter == self.counter
+                    && p == self.p
+                    && self.g == Self::derive_g(&self.domain_parameter_seed, &p, &q, service);
+            }
+        }
+        false
+    }
+
+    /// Prüft die Bedingungen, die das Signieren voraussetzt: `q` ist eine ungerade Primzahl,
+    /// die `p - 1` teilt, und `g` liegt in `(1, p)` mit `g^q ≡ 1 mod p`. Anders als `verify`
+    /// wird nicht geprüft, ob die Parameter aus dem Seed hervorgehen.
+    ///
+    /// # Argumente
+    /// * `miller_rabin_iterations` - Die Anzahl der Iterationen für den Miller-Rabin-Test.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Fehler
+    /// * `DsaError::InvalidDomainParametersError` - Falls eine der Bedingungen verletzt ist.
+    pub fn check_subgroup(
+        &self,
+        miller_rabin_iterations: u32,
+        service: &NumberTheoryWithPrngService,
+    ) -> Result<()> {
+        let DsaDomainParameters { p, q, g, .. } = self;
+        ensure!(
+            q > &BigInt::from(2) && service.is_probably_prime(q, miller_rabin_iterations),
+            DsaError::InvalidDomainParametersError(format!("q = {} is not an odd prime", q))
+        );
+        ensure!(
+            p > &BigInt::one() && (p - BigInt::one()) % q == BigInt::from(0),
+            DsaError::InvalidDomainParametersError(format!("q = {} does not divide p - 1", q))
+        );
+        ensure!(
+            g > &BigInt::one()
+                && g < p
+                && service.number_theory_service.fast_exponentiation(g, q, p) == BigInt::one(),
+            DsaError::InvalidDomainParametersError(format!(
+                "g = {} does not generate a subgroup of order q",
+                g
+            ))
+        );
+        Ok(())
+    }
+
+    /// Die Länge des Seeds in Bits: `N`, aufgerundet auf ganze Bytes.
+    fn seed_length(subgroup_width: u32) -> u32 {
+        subgroup_width.div_ceil(8) * 8
+    }
+
+    /// Berechnet `SHA256(seed)`, wobei der Seed als Bytefolge der Länge `seedlen` kodiert wird.
+    fn hash_seed(seed: &BigInt, seed_length: u32) -> BigInt {
+        let modulus = BigInt::one() << seed_length;
+        // Das unwrap ist sicher, weil der Wert nach der Reduktion in seed_length Bits passt.
+        let bytes = integer_to_octets(&(seed % modulus), (seed_length / 8) as usize).unwrap();
+        BigInt::from_bytes_be(Sign::Plus, &sha256_bytes(&bytes))
+    }
+
+    /// Leitet den Primzahlkandidaten `q` aus dem Seed ab (A.1.1.2, Schritte 6 und 7):
+    /// `U = Hash(seed) mod 2^(N-1)` und `q = 2^(N-1) + U + 1 - (U mod 2)`.
+    fn derive_q(seed: &BigInt, subgroup_width: u32) -> BigInt {
+        let two_to_n_minus_one = BigInt::one() << (subgroup_width - 1);
+        let u = Self::hash_seed(seed, Self::seed_length(subgroup_width)) % &two_to_n_minus_one;
+        let u_mod_two = &u % 2;
+        two_to_n_minus_one + u + 1 - u_mod_two
+    }
+
+    /// Leitet den Primzahlkandidaten `p` für einen Zähler ab (A.1.1.2, Schritt 11).
+    /// Es wird `None` zurückgegeben, falls der Kandidat kleiner als `2^(L-1)` ist.
+    fn derive_p(seed: &BigInt, q: &BigInt, modulus_width: u32, counter: u32) -> Option<BigInt> {
+        let seed_length = Self::seed_length(q.bits() as u32);
+        let n = modulus_width.div_ceil(OUTLEN) - 1;
+        let b = modulus_width - 1 - n * OUTLEN;
+        let offset = 1 + counter * (n + 1);
+
+        let mut w = BigInt::from(0);
+        for j in 0..=n {
+            let mut v = Self::hash_seed(&(seed + offset + j), seed_length);
+            if j == n {
+                v %= BigInt::one() << b;
+            }
+            w += v << (j * OUTLEN);
+        }
+
+        let x = w + (BigInt::one() << (modulus_width - 1));
+        let c: BigInt = &x % (q * 2);
+        let p = x - c.decrement();
+        if p < BigInt::one() << (modulus_width - 1) {
+            return None;
+        }
+        Some(p)
+    }
+
+    /// Leitet den Erzeuger `g` verifizierbar ab (A.2.3):
+    /// `W = Hash(seed || "ggen" || index || count)` und `g = W^((p-1)/q) mod p`.
+    fn derive_g(
+        seed: &BigInt,
+        p: &BigInt,
+        q: &BigInt,
+        service: &NumberTheoryWithPrngService,
+    ) -> BigInt {
+        let seed_length = Self::seed_length(q.bits() as u32);
+        // Das unwrap ist sicher, weil der Seed in seed_length Bits passt.
+        let seed_bytes = integer_to_octets(seed, (seed_length / 8) as usize).unwrap();
+        let e = p.decrement() / q;
+
+        let mut count: u16 = 0;
+        loop {
+            count += 1;
+            let mut data = seed_bytes.clone();
+            data.extend_from_slice(b"ggen");
+            data.push(GENERATOR_INDEX);
+            data.extend_from_slice(&count.to_be_bytes());
+
+            let w = BigInt::from_bytes_be(Sign::Plus, &sha256_bytes(&data));
+            let g = service.number_theory_service.fast_exponentiation(&w, &e, p);
+            if g >= 2.into() {
+                return g;
+            }
+            trace!("g ist kleiner als 2 für count {}", count);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
+        Fast, Slow,
+    };
+
+    #[test]
+    fn test_generate_domain_parameters() {
+        for service in [
+            NumberTheoryWithPrngService::new(Slow, 13),
+            NumberTheoryWithPrngService::new(Fast, 13),
+        ] {
+            let params = DsaDomainParameters::generate(512, 160, 20, &service).unwrap();
+
+            assert_eq!(params.p.bits(), 512);
+            assert_eq!(params.q.bits(), 160);
+            assert!(service.is_probably_prime(&params.p, 20));
+            assert!(service.is_probably_prime(&params.q, 20));
+            assert_eq!(params.p.decrement() % &params.q, BigInt::from(0));
+            assert_eq!(
+                service
+                    .number_theory_service
+                    .fast_exponentiation(&params.g, &params.q, &params.p),
+                BigInt::one()
+            );
+            assert!(params.verify(20, &service));
+        }
+    }
+
+    #[test]
+    fn test_generate_with_modulus_larger_than_hash() {
+        let service = NumberTheoryWithPrngService::new(Fast, 17);
+        let params = DsaDomainParameters::generate(1024, 256, 20, &service).unwrap();
+        assert_eq!(params.p.bits(), 1024);
+        assert_eq!(params.q.bits(), 256);
+        assert!(params.verify(20, &service));
+    }
+
+    #[test]
+    fn test_verify_detects_manipulation() {
+        let service = NumberTheoryWithPrngService::new(Fast, 19);
+        let params = DsaDomainParameters::generate(256, 64, 20, &service).unwrap();
+
+        let mut other_seed = params.clone();
+        other_seed.domain_parameter_seed += 1;
+        assert!(!other_seed.verify(20, &service));
+
+        let mut other_counter = params.clone();
+        other_counter.counter += 1;
+        assert!(!other_counter.verify(20, &service));
+
+        let mut other_g = params.clone();
+        other_g.g =
+            service
+                .number_theory_service
+                .fast_exponentiation(&params.g, &2.into(), &params.p);
+        assert!(!other_g.verify(20, &service));
+    }
+
+    #[test]
+    fn test_check_subgroup() {
+        let service = NumberTheoryWithPrngService::new(Fast, 23);
+        let params = DsaDomainParameters::generate(256, 64, 20, &service).unwrap();
+        assert!(params.check_subgroup(20, &service).is_ok());
+
+        let mut no_prime = params.clone();
+        no_prime.q = BigInt::from(0);
+        assert!(no_prime.check_subgroup(20, &service).is_err());
+
+        let mut no_divisor = params.clone();
+        no_divisor.p += 2;
+        assert!(no_divisor.check_subgroup(20, &service).is_err());
+
+        let mut no_generator = params.clone();
+        no_generator.g = BigInt::one();
+        assert!(no_generator.check_subgroup(20, &service).is_err());
+    }
+
+    #[test]
+    fn test_generate_rejects_invalid_sizes() {
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        assert!(DsaDomainParameters::generate(512, 512, 20, &service).is_err());
+        assert!(DsaDomainParameters::generate(512, 4, 20, &service).is_err());
+        assert!(DsaDomainParameters::generate(1024, 384, 20, &service).is_err());
+    }
+}
@@ -0,0 +1,298 @@
+use crate::encryption::asymmetric_encryption_types::{
+    AsymmetricEncryptionScheme, Signer, Verifier,
+};
+use crate::encryption::core::dsa::domain_parameters::DsaDomainParameters;
+use crate::encryption::core::dsa::keys::{DsaKeyPair, DsaPrivateKey, DsaPublicKey};
+use crate::encryption::encryption_types::EncryptionScheme;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceTrait;
+use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+use crate::math_core::traits::increment::Increment;
+use crate::shared::hashing::{sha256, SHA256_LENGTH};
+use anyhow::Result;
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::{One, Zero};
+use log::{debug, trace};
+
+pub struct DsaScheme;
+
+/// Eine DSA-Signatur, bestehend aus `r = (g^k mod p) mod q` und `s = k^-1 * (z + x * r) mod q`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DsaSignature {
+    pub r: BigInt,
+    pub s: BigInt,
+}
+
+impl EncryptionScheme for DsaScheme {}
+
+impl AsymmetricEncryptionScheme for DsaScheme {}
+
+impl DsaScheme {
+    /// Generiert neue Domain-Parameter und ein Schlüsselpaar für DSA.
+    ///
+    /// # Argumente
+    /// * `modulus_width` - Die Bit-Breite `L` des Modulus `p`.
+    /// * `subgroup_width` - Die Bit-Breite `N` der Untergruppenordnung `q`.
+    /// * `miller_rabin_iterations` - Die Anzahl der Iterationen für den Miller-Rabin-Test.
+    /// * `service` - Der Service für die Zahlentheorie und die Zufallszahlen.
+    ///
+    /// # Rückgabe
+    /// * `DsaKeyPair` - Das Schlüsselpaar.
+    ///
+    /// # Fehler
+    /// * `DsaError::InvalidParameterSizesError` - Falls `L` und `N` nicht zueinander passen.
+    pub fn generate_keypair(
+        modulus_width: u32,
+        subgroup_width: u32,
+        miller_rabin_iterations: u32,
+        service: &NumberTheoryWithPrngService,
+    ) -> Result<DsaKeyPair> {
+        let domain_parameters = DsaDomainParameters::generate(
+            modulus_width,
+            subgroup_width,
+            miller_rabin_iterations,
+            service,
+        )?;
+        Ok(Self::generate_keypair_for_domain_parameters(
+            &domain_parameters,
+            service,
+        ))
+    }
+
+    /// Generiert ein Schlüsselpaar für bereits vorhandene Domain-Parameter.
+    /// Der Geheimwert `x` wird zufällig aus `[1, q - 1]` gewählt.
+    ///
+    /// # Argumente
+    /// * `domain_parameters` - Die zu verwendenden Domain-Parameter.
+    /// * `service` - Der Service für die Zahlentheorie und die Zufallszahlen.
+    ///
+    /// # Rückgabe
+    /// * `DsaKeyPair` - Das Schlüsselpaar.
+    pub fn generate_keypair_for_domain_parameters(
+        domain_parameters: &DsaDomainParameters,
+        service: &NumberTheoryWithPrngService,
+    ) -> DsaKeyPair {
+        let DsaDomainParameters { p, q, g, .. } = domain_parameters;
+        let x = service.take_random_number_in_range(&BigInt::one(), &q.decrement());
+        let y = service.number_theory_service.fast_exponentiation(g, &x, p);
+        debug!("DSA-Schlüsselpaar mit y {} generiert", y);
+
+        DsaKeyPair {
+            public_key: DsaPublicKey {
+                domain_parameters: domain_parameters.clone(),
+                y,
+            },
+            private_key: DsaPrivateKey {
+                domain_parameters: domain_parameters.clone(),
+                x,
+            },
+        }
+    }
+
+    /// Berechnet den Hashwert `z` einer Nachricht: die linken `min(N, 256)` Bits von SHA256.
+    fn hash_message(message: &str, q: &BigInt) -> BigInt {
+        let hash_bits = (SHA256_LENGTH * 8) as u64;
+        let subgroup_width = q.bits();
+        let hash = sha256(message);
+        if subgroup_width < hash_bits {
+            hash >> (hash_bits - subgroup_width)
+        } else {
+            hash
+        }
+    }
+}
+
+impl Signer<DsaScheme> for DsaScheme {
+    type Input = str;
+    type Output = DsaSignature;
+    type Key = DsaPrivateKey;
+
+    /// Signiert eine Nachricht mit dem privaten DSA-Schlüssel.
+    /// Für jede Signatur wird eine neue Zufallszahl `k` aus `[1, q - 1]` gewählt.
+    ///
+    /// # Argumente
+    /// * `key` - Der private Schlüssel.
+    /// * `message` - Die zu signierende Nachricht. Sie wird mit SHA256 gehasht.
+    /// * `service` - Der Service für die Zahlentheorie und die Zufallszahlen.
+    ///
+    /// # Rückgabe
+    /// Die Signatur `(r, s)`.
+    fn sign(
+        key: &Self::Key,
+        message: &Self::Input,
+        service: &NumberTheoryWithPrngService,
+    ) -> Self::Output {
+        let DsaDomainParameters { p, q, g, .. } = &key.domain_parameters;
+        let z = DsaScheme::hash_message(message, q);
+
+        loop {
+            let k = service.take_random_number_in_range(&BigInt::one(), &q.decrement());
+            let r = service.number_theory_service.fast_exponentiation(g, &k, p) % q;
+            if r.is_zero() {
+                trace!("r ist 0 für k {}", k);
+                continue;
+            }
+
+            // Das unwrap ist sicher, weil q prim ist und 0 < k < q gilt.
+            let k_inverse = service.number_theory_service.modulo_inverse(&k, q).unwrap();
+            let s = (k_inverse * (&z + &key.x * &r)) % q;
+            if !s.is_zero() {
+                return DsaSignature { r, s };
+            }
+            trace!("s ist 0 für k {}", k);
+        }
+    }
+}
+
+impl Verifier<DsaScheme> for DsaScheme {
+    type Signature = DsaSignature;
+    type Message = str;
+    type Output = bool;
+    type Key = DsaPublicKey;
+
+    /// Überprüft eine DSA-Signatur: Es muss `0 < r, s < q` und
+    /// `((g^(z * w) * y^(r * w)) mod p) mod q = r` mit `w = s^-1 mod q` gelten.
+    ///
+    /// # Argumente
+    /// * `key` - Der öffentliche Schlüssel.
+    /// * `signature` - Die Signatur.
+    /// * `message` - Die Nachricht.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// `true`, wenn die Signatur gültig ist, ansonsten `false`.
+    fn verify(
+        key: &Self::Key,
+        signature: &Self::Signature,
+        message: &Self::Message,
+        service: &NumberTheoryWithPrngService,
+    ) -> Self::Output {
+        let DsaDomainParameters { p, q, g, .. } = &key.domain_parameters;
+        let DsaSignature { r, s } = signature;
+
+        if r < &BigInt::one() || r >= q || s < &BigInt::one() || s >= q {
+            return false;
+        }
+
+        let service = &service.number_theory_service;
+        let w = match service.modulo_inverse(s, q) {
+            Ok(w) => w,
+            Err(_) => return false,
+        };
+        let z = DsaScheme::hash_message(message, q);
+        let u1 = (z * &w) % q;
+        let u2 = (r * &w) % q;
+
+        let v = (service.fast_exponentiation(g, &u1, p)
+            * service.fast_exponentiation(&key.y, &u2, p)
+            % p)
+            % q;
+        &v == r
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
+        Fast, Slow,
+    };
+
+    fn run_test_for_all_services(test: impl Fn(NumberTheoryWithPrngService)) {
+        test(NumberTheoryWithPrngService::new(Slow, 13)); // Langsame, eigene Implementierung
+        test(NumberTheoryWithPrngService::new(Fast, 13)); // Schnelle, externe Implementierung
+    }
+
+    #[test]
+    fn test_sign_verify_happy_flow() {
+        run_test_for_all_services(|service| {
+            let key_pair = DsaScheme::generate_keypair(512, 160, 20, &service).unwrap();
+            let message = "Das ist eine Testnachricht für DSA mit Ümläuten!";
+
+            let signature = DsaScheme::sign(&key_pair.private_key, message, &service);
+            assert!(DsaScheme::verify(
+                &key_pair.public_key,
+                &signature,
+                message,
+                &service
+            ));
+        });
+    }
+
+    #[test]
+    fn test_sign_verify_with_subgroup_width_of_hash() {
+        let service = NumberTheoryWithPrngService::new(Fast, 17);
+        let key_pair = DsaScheme::generate_keypair(1024, 256, 20, &service).unwrap();
+        let message = "Nachricht";
+
+        let signature = DsaScheme::sign(&key_pair.private_key, message, &service);
+        assert!(DsaScheme::verify(
+            &key_pair.public_key,
+            &signature,
+            message,
+            &service
+        ));
+    }
+
+    #[test]
+    fn test_verify_rejects_manipulation() {
+        run_test_for_all_services(|service| {
+            let key_pair = DsaScheme::generate_keypair(256, 64, 20, &service).unwrap();
+            let message = "Originalnachricht";
+            let signature = DsaScheme::sign(&key_pair.private_key, message, &service);
+
+            assert!(!DsaScheme::verify(
+                &key_pair.public_key,
+                &signature,
+                "Manipulierte Nachricht",
+                &service
+            ));
+
+            let manipulated = DsaSignature {
+                r: signature.r.clone(),
+                s: (&signature.s + 1) % &key_pair.public_key.domain_parameters.q,
+            };
+            assert!(!DsaScheme::verify(
+                &key_pair.public_key,
+                &manipulated,
+                message,
+                &service
+            ));
+
+            let out_of_range = DsaSignature {
+                r: key_pair.public_key.domain_parameters.q.clone(),
+                s: signature.s.clone(),
+            };
+            assert!(!DsaScheme::verify(
+                &key_pair.public_key,
+                &out_of_range,
+                message,
+                &service
+            ));
+        });
+    }
+
+    #[test]
+    fn test_key_pairs_share_domain_parameters() {
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        let alice = DsaScheme::generate_keypair(256, 64, 20, &service).unwrap();
+        let bob = DsaScheme::generate_keypair_for_domain_parameters(
+            &alice.public_key.domain_parameters,
+            &service,
+        );
+        let message = "Gemeinsame Parameter";
+
+        let signature = DsaScheme::sign(&bob.private_key, message, &service);
+        assert!(DsaScheme::verify(
+            &bob.public_key,
+            &signature,
+            message,
+            &service
+        ));
+        assert!(!DsaScheme::verify(
+            &alice.public_key,
+            &signature,
+            message,
+            &service
+        ));
+    }
+}
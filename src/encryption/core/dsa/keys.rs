@@ -0,0 +1,47 @@
+use crate::encryption::asymmetric_encryption_types::{
+    AsymmetricKey, PrivateKey, PublicKey, SignatureKey, VerificationKey,
+};
+use crate::encryption::core::dsa::domain_parameters::DsaDomainParameters;
+use crate::encryption::core::dsa::dsa_scheme::DsaScheme;
+use crate::encryption::encryption_types::Key;
+use bigdecimal::num_bigint::BigInt;
+
+/// Ein öffentlicher DSA-Schlüssel.
+/// Besteht aus den Domain-Parametern und dem öffentlichen Wert `y = g^x mod p`.
+#[derive(Clone, Debug)]
+pub struct DsaPublicKey {
+    pub domain_parameters: DsaDomainParameters,
+    pub y: BigInt,
+}
+
+impl Key<DsaScheme> for DsaPublicKey {}
+
+impl AsymmetricKey<DsaScheme> for DsaPublicKey {}
+
+impl PublicKey<DsaScheme> for DsaPublicKey {}
+
+impl VerificationKey<DsaScheme> for DsaPublicKey {}
+
+/// Ein privater DSA-Schlüssel.
+/// Besteht aus den Domain-Parametern und dem Geheimwert `x` mit `0 < x < q`.
+#[derive(Clone, Debug)]
+pub struct DsaPrivateKey {
+    pub domain_parameters: DsaDomainParameters,
+    pub x: BigInt,
+}
+
+impl Key<DsaScheme> for DsaPrivateKey {}
+
+impl AsymmetricKey<DsaScheme> for DsaPrivateKey {}
+
+impl PrivateKey<DsaScheme> for DsaPrivateKey {}
+
+impl SignatureKey<DsaScheme> for DsaPrivateKey {}
+
+/// Ein DSA-Schlüsselpaar. DSA ist ein reines Signaturverfahren, daher wird
+/// `AsymmetricKeyPair` (das Ver- und Entschlüsselungsschlüssel voraussetzt) nicht implementiert.
+#[derive(Clone, Debug)]
+pub struct DsaKeyPair {
+    pub public_key: DsaPublicKey,
+    pub private_key: DsaPrivateKey,
+}
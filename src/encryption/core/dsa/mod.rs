@@ -0,0 +1,3 @@
+pub mod domain_parameters;
+pub mod dsa_scheme;
+pub mod keys;
@@ -1,6 +1,6 @@
 use crate::encryption::asymmetric_encryption_types::{
     AsymmetricDecryptor, AsymmetricEncryptionScheme, AsymmetricEncryptor, KeyGenWithPrimeConfig,
-    KeyGenerator,
+    KeyGenerator, Signer, Verifier,
 };
 use crate::encryption::core::el_gamal::keys::{
     ElGamalKeyPair, ElGamalPrivateKey, ElGamalPublicKey,
@@ -10,14 +10,25 @@ use crate::math_core::number_theory::number_theory_service::{
     NumberTheoryService, NumberTheoryServiceTrait,
 };
 use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
-use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+use crate::math_core::prime_generator::PrimeGenerator;
+use crate::math_core::random_source::RandomSource;
 use crate::math_core::traits::increment::Increment;
-use atomic_counter::RelaxedCounter;
+use crate::shared::hashing::sha256;
+use anyhow::Result;
 use bigdecimal::num_bigint::BigInt;
-use log::debug;
+use bigdecimal::num_traits::Euclid;
+use bigdecimal::{One, Zero};
+use log::{debug, trace};
 
 pub struct ElGamalScheme;
 
+/// Eine ElGamal-Signatur, bestehend aus `r = g^k mod p` und `s = (H(m) - x * r) * k^-1 mod (p - 1)`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ElGamalSignature {
+    pub r: BigInt,
+    pub s: BigInt,
+}
+
 impl EncryptionScheme for ElGamalScheme {}
 
 impl AsymmetricEncryptionScheme for ElGamalScheme {}
@@ -63,38 +74,44 @@ impl KeyGenerator<ElGamalPublicKey, ElGamalPrivateKey, ElGamalScheme> for ElGama
     ///
     /// # Argumente
     /// * `config` - Die Konfiguration für den Schlüsselgenerierungsvorgang.
+    /// * `random` - Die Quelle der Zufallszahlen.
     ///
     /// # Rückgabe
     /// Ein Tupel aus dem öffentlichen und privaten Schlüssel.
-    fn generate_keypair(config: &impl KeyGenWithPrimeConfig) -> Self::KeyPair {
+    fn generate_keypair_with_source(
+        config: &impl KeyGenWithPrimeConfig,
+        random: &dyn RandomSource,
+    ) -> Result<Self::KeyPair> {
         debug!(
             "Generieren eines neuen ElGamal-Schlüsselpaares mit Konfiguration: {:?}",
             config
         );
-        let random_generator =
-            PseudoRandomNumberGenerator::new(config.random_seed(), config.number_theory_service());
-        let counter = RelaxedCounter::new(1);
+        let prime_generator = PrimeGenerator::new(config.number_theory_service());
 
         // Generieren der sicheren Primzahl p und der Primitivwurzel g
-        let (p, g) = random_generator.generate_secure_prime_with_primitive_root(
+        let (p, g) = prime_generator.generate_secure_prime_with_primitive_root(
             config.characteristic(),
             config.miller_rabin_iterations(),
-            &counter,
+            random,
         );
 
         // Generieren des privaten Schlüssels x (Zufallszahl zwischen 1 und p-2)
         let p_minus_two = p.decrement().decrement();
-        let x = random_generator.take(&1.into(), &p_minus_two, &counter);
+        let x = random.take(&1.into(), &p_minus_two);
 
         // Berechnen des öffentlichen Schlüsselwertes y
         let y = config
             .number_theory_service()
             .fast_exponentiation(&g, &x, &p);
 
-        ElGamalKeyPair {
-            public_key: ElGamalPublicKey { p: p.clone(), g, y },
-            private_key: ElGamalPrivateKey { p, x },
-        }
+        Ok(ElGamalKeyPair {
+            public_key: ElGamalPublicKey {
+                p: p.clone(),
+                g: g.clone(),
+                y,
+            },
+            private_key: ElGamalPrivateKey { p, g, x },
+        })
     }
 }
 
@@ -174,10 +191,105 @@ impl AsymmetricDecryptor<ElGamalScheme> for ElGamalScheme {
     }
 }
 
+impl Signer<ElGamalScheme> for ElGamalScheme {
+    type Input = str;
+    type Output = ElGamalSignature;
+    type Key = ElGamalPrivateKey;
+
+    /// Signiert eine Nachricht mit dem privaten Schlüssel des ElGamal-Kryptosystems.
+    /// Dafür wird eine Zufallszahl `k` mit `ggT(k, p - 1) = 1` gewählt.
+    ///
+    /// # Argumente
+    /// * `key` - Der private Schlüssel.
+    /// * `message` - Die zu signierende Nachricht. Sie wird mit SHA256 gehasht.
+    /// * `service` - Der Service für die Zahlentheorie und die Zufallszahlen.
+    ///
+    /// # Rückgabe
+    /// Die Signatur `(r, s)`.
+    fn sign(
+        key: &Self::Key,
+        message: &Self::Input,
+        service: &NumberTheoryWithPrngService,
+    ) -> Self::Output {
+        let p = &key.p;
+        let p_minus_one = p.decrement();
+        let hash = sha256(message).rem_euclid(&p_minus_one);
+
+        loop {
+            // Generieren der Zufallszahl k (Zufallszahl zwischen 1 und p-2, teilerfremd zu p-1)
+            let k = service.take_random_number_in_range(&1.into(), &p_minus_one.decrement());
+            let k_inverse = match service
+                .number_theory_service
+                .modulo_inverse(&k, &p_minus_one)
+            {
+                Ok(k_inverse) => k_inverse,
+                Err(_) => {
+                    trace!("k {} ist nicht teilerfremd zu p-1", k);
+                    continue;
+                }
+            };
+
+            let r = service
+                .number_theory_service
+                .fast_exponentiation(&key.g, &k, p);
+            let s = ((&hash - &key.x * &r) * k_inverse).rem_euclid(&p_minus_one);
+
+            // Für s = 0 ließe sich x aus der Signatur berechnen.
+            if !s.is_zero() {
+                return ElGamalSignature { r, s };
+            }
+            trace!("s ist 0 für k {}", k);
+        }
+    }
+}
+
+impl Verifier<ElGamalScheme> for ElGamalScheme {
+    type Signature = ElGamalSignature;
+    type Message = str;
+    type Output = bool;
+    type Key = ElGamalPublicKey;
+
+    /// Überprüft eine ElGamal-Signatur: Es muss `0 < r < p`, `0 < s < p - 1` und
+    /// `g^H(m) = y^r * r^s mod p` gelten.
+    ///
+    /// # Argumente
+    /// * `key` - Der öffentliche Schlüssel.
+    /// * `signature` - Die Signatur.
+    /// * `message` - Die Nachricht.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// `true`, wenn die Signatur gültig ist, ansonsten `false`.
+    fn verify(
+        key: &Self::Key,
+        signature: &Self::Signature,
+        message: &Self::Message,
+        service: &NumberTheoryWithPrngService,
+    ) -> Self::Output {
+        let p = &key.p;
+        let p_minus_one = p.decrement();
+        let ElGamalSignature { r, s } = signature;
+
+        if r < &BigInt::one() || r >= p || s < &BigInt::one() || s >= &p_minus_one {
+            return false;
+        }
+
+        let hash = sha256(message).rem_euclid(&p_minus_one);
+        let service = &service.number_theory_service;
+
+        let left = service.fast_exponentiation(&key.g, &hash, p);
+        let right =
+            (service.fast_exponentiation(&key.y, r, p) * service.fast_exponentiation(r, s, p)) % p;
+        left == right
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
+        Fast, Slow,
+    };
     use bigdecimal::FromPrimitive;
     use std::time::SystemTime;
 
@@ -195,7 +307,7 @@ mod tests {
             number_theory_service: service,
         };
 
-        let keypair = ElGamalScheme::generate_keypair(&config);
+        let keypair = ElGamalScheme::generate_keypair(&config).unwrap();
         let calculated_y = service.fast_exponentiation(
             &keypair.public_key.g,
             &keypair.private_key.x,
@@ -220,7 +332,7 @@ mod tests {
 
         let service = NumberTheoryWithPrngService::new(Fast, 13);
 
-        let keypair = ElGamalScheme::generate_keypair(&config);
+        let keypair = ElGamalScheme::generate_keypair(&config).unwrap();
         let public_key = keypair.public_key;
         let private_key = keypair.private_key;
 
@@ -243,7 +355,7 @@ mod tests {
 
         let service = NumberTheoryWithPrngService::new(Fast, 13);
 
-        let keypair = ElGamalScheme::generate_keypair(&config);
+        let keypair = ElGamalScheme::generate_keypair(&config).unwrap();
         let public_key = keypair.public_key;
         let private_key = keypair.private_key;
 
@@ -265,7 +377,7 @@ mod tests {
             number_theory_service: NumberTheoryService::new(Fast),
         };
 
-        let keypair = ElGamalScheme::generate_keypair(&config);
+        let keypair = ElGamalScheme::generate_keypair(&config).unwrap();
         let public_key = keypair.public_key;
         let private_key = keypair.private_key;
 
@@ -278,4 +390,86 @@ mod tests {
 
         assert_eq!(plaintext, decrypted_plaintext);
     }
+
+    fn generate_key_pair(modulus_width: u32, random_seed: u32) -> ElGamalKeyPair {
+        let config = ElGamalKeyGenConfig {
+            modulus_width,
+            miller_rabin_iterations: 30,
+            random_seed,
+            number_theory_service: NumberTheoryService::new(Fast),
+        };
+        ElGamalScheme::generate_keypair(&config).unwrap()
+    }
+
+    #[test]
+    fn test_sign_verify_happy_flow() {
+        for service in [
+            NumberTheoryWithPrngService::new(Slow, 13),
+            NumberTheoryWithPrngService::new(Fast, 13),
+        ] {
+            let keypair = generate_key_pair(128, 31);
+            let message = "Das ist eine Nachricht, die signiert werden soll.";
+
+            let signature = ElGamalScheme::sign(&keypair.private_key, message, &service);
+            assert!(ElGamalScheme::verify(
+                &keypair.public_key,
+                &signature,
+                message,
+                &service
+            ));
+        }
+    }
+
+    #[test]
+    fn test_sign_is_randomized() {
+        let keypair = generate_key_pair(64, 32);
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+
+        let first = ElGamalScheme::sign(&keypair.private_key, "Nachricht", &service);
+        let second = ElGamalScheme::sign(&keypair.private_key, "Nachricht", &service);
+        assert_ne!(first, second);
+        assert!(ElGamalScheme::verify(
+            &keypair.public_key,
+            &second,
+            "Nachricht",
+            &service
+        ));
+    }
+
+    #[test]
+    fn test_verify_rejects_manipulation() {
+        let keypair = generate_key_pair(64, 33);
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        let signature = ElGamalScheme::sign(&keypair.private_key, "Nachricht", &service);
+
+        assert!(!ElGamalScheme::verify(
+            &keypair.public_key,
+            &signature,
+            "Andere Nachricht",
+            &service
+        ));
+
+        let manipulated = ElGamalSignature {
+            r: signature.r.clone(),
+            s: signature.s.increment(),
+        };
+        assert!(!ElGamalScheme::verify(
+            &keypair.public_key,
+            &manipulated,
+            "Nachricht",
+            &service
+        ));
+
+        // r außerhalb von Z_p* muss abgelehnt werden.
+        let out_of_range = ElGamalSignature {
+            r: &signature.r + &keypair.public_key.p,
+            s: signature.s.clone(),
+        };
+        assert!(!ElGamalScheme::verify(
+            &keypair.public_key,
+            &out_of_range,
+            "Nachricht",
+            &service
+        ));
+    }
 }
@@ -1,10 +1,11 @@
+use crate::api::endpoints::el_gamal::{ElGamalPrivateKeyBean, ElGamalPublicKeyBean};
 use crate::encryption::asymmetric_encryption_types::{
     AsymmetricDecryptionKey, AsymmetricEncryptionKey, AsymmetricKey, AsymmetricKeyPair, PrivateKey,
-    PublicKey,
+    PublicKey, SignatureKey, VerificationKey,
 };
 use crate::encryption::core::el_gamal::el_gamal_scheme::ElGamalScheme;
 use crate::encryption::encryption_types::Key;
-use bigdecimal::num_bigint::BigInt;
+use bigdecimal::num_bigint::{BigInt, ParseBigIntError};
 
 /// Ein öffentlicher ElGamal-Schlüssel für das ElGamal-Kryptosystem in primen Restklassengruppen.
 /// Besteht aus dem Modulus p, dem Generator g und dem öffentlichen Wert y.
@@ -15,6 +16,19 @@ pub struct ElGamalPublicKey {
     pub y: BigInt,
 }
 
+impl TryFrom<ElGamalPublicKeyBean> for ElGamalPublicKey {
+    type Error = ParseBigIntError;
+
+    /// Mapped die Bean in das Domain-Modell
+    fn try_from(bean: ElGamalPublicKeyBean) -> Result<Self, Self::Error> {
+        Ok(ElGamalPublicKey {
+            p: bean.p.parse()?,
+            g: bean.g.parse()?,
+            y: bean.y.parse()?,
+        })
+    }
+}
+
 impl Key<ElGamalScheme> for ElGamalPublicKey {}
 
 impl AsymmetricKey<ElGamalScheme> for ElGamalPublicKey {}
@@ -23,16 +37,31 @@ impl PublicKey<ElGamalScheme> for ElGamalPublicKey {}
 
 impl AsymmetricEncryptionKey<ElGamalScheme> for ElGamalPublicKey {}
 
-// TODO: Ist dieser Schlüssel auf für die Verifikation von Signaturen notwendig?
+impl VerificationKey<ElGamalScheme> for ElGamalPublicKey {}
 
 /// Ein privater ElGamal-Schlüssel für das ElGamal-Kryptosystem in primen Restklassengruppen.
-/// Besteht aus dem Modulus p und dem Geheimwert x.
+/// Besteht aus dem Modulus p, dem Generator g und dem Geheimwert x.
+/// Der Generator wird nur für das Signieren benötigt.
 #[derive(Clone, Debug)]
 pub struct ElGamalPrivateKey {
     pub p: BigInt,
+    pub g: BigInt,
     pub x: BigInt,
 }
 
+impl TryFrom<ElGamalPrivateKeyBean> for ElGamalPrivateKey {
+    type Error = ParseBigIntError;
+
+    /// Mapped die Bean in das Domain-Modell
+    fn try_from(bean: ElGamalPrivateKeyBean) -> Result<Self, Self::Error> {
+        Ok(ElGamalPrivateKey {
+            p: bean.p.parse()?,
+            g: bean.g.parse()?,
+            x: bean.x.parse()?,
+        })
+    }
+}
+
 impl Key<ElGamalScheme> for ElGamalPrivateKey {}
 
 impl AsymmetricKey<ElGamalScheme> for ElGamalPrivateKey {}
@@ -41,7 +70,7 @@ impl PrivateKey<ElGamalScheme> for ElGamalPrivateKey {}
 
 impl AsymmetricDecryptionKey<ElGamalScheme> for ElGamalPrivateKey {}
 
-// TODO: Siehe oben. Ist dieser Schlüssel auch für die Signierung von Nachrichten notwendig?
+impl SignatureKey<ElGamalScheme> for ElGamalPrivateKey {}
 
 #[derive(Clone, Debug)]
 pub struct ElGamalKeyPair {
@@ -1,2 +1,2 @@
-mod el_gamal_scheme;
-mod keys;
+pub mod el_gamal_scheme;
+pub mod keys;
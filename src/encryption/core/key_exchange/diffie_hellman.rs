@@ -0,0 +1,225 @@
+use crate::encryption::core::rsa::data_conversion::{integer_to_octets, octet_length};
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceTrait;
+use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+use crate::math_core::traits::increment::Increment;
+use crate::shared::errors::KeyExchangeError;
+use crate::shared::hashing::concat_kdf_sha256;
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::One;
+use log::debug;
+
+/// Die öffentlichen Parameter für den Diffie-Hellman-Schlüsselaustausch in `Z_p^*`.
+///
+/// # Felder
+/// * `p` - Eine sichere Primzahl.
+/// * `g` - Eine Primitivwurzel modulo `p`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiffieHellmanParameters {
+    pub p: BigInt,
+    pub g: BigInt,
+}
+
+/// Die Werte einer Partei im Schlüsselaustausch.
+///
+/// # Felder
+/// * `private_value` - Der geheime Exponent `a`.
+/// * `public_value` - Der öffentliche Wert `A = g^a mod p`.
+#[derive(Clone, Debug)]
+pub struct DiffieHellmanKeyPair {
+    pub private_value: BigInt,
+    pub public_value: BigInt,
+}
+
+/// Der klassische Diffie-Hellman-Schlüsselaustausch in primen Restklassengruppen.
+pub struct DiffieHellmanScheme {}
+
+impl DiffieHellmanScheme {
+    /// Generiert die öffentlichen Parameter, bestehend aus einer sicheren Primzahl und einer
+    /// Primitivwurzel.
+    ///
+    /// # Argumente
+    /// * `modulus_width` - Die Bit-Breite von `p`.
+    /// * `miller_rabin_iterations` - Die Anzahl der Iterationen für den Miller-Rabin-Test.
+    /// * `service` - Der Service für die Zahlentheorie und die Zufallszahlen.
+    ///
+    /// # Rückgabe
+    /// * `DiffieHellmanParameters` - Die Parameter `(p, g)`.
+    pub fn generate_parameters(
+        modulus_width: u32,
+        miller_rabin_iterations: u32,
+        service: &NumberTheoryWithPrngService,
+    ) -> DiffieHellmanParameters {
+        let (p, g) = service
+            .prime_generator()
+            .generate_secure_prime_with_primitive_root(
+                modulus_width,
+                miller_rabin_iterations,
+                service,
+            );
+        debug!("Diffie-Hellman-Parameter p = {} und g = {} generiert", p, g);
+        DiffieHellmanParameters { p, g }
+    }
+
+    /// Erzeugt den geheimen Exponenten `a` aus `[2, p - 2]` und den öffentlichen Wert
+    /// `A = g^a mod p` einer Partei.
+    ///
+    /// # Argumente
+    /// * `parameters` - Die öffentlichen Parameter.
+    /// * `service` - Der Service für die Zahlentheorie und die Zufallszahlen.
+    ///
+    /// # Rückgabe
+    /// * `DiffieHellmanKeyPair` - Der geheime und der öffentliche Wert.
+    pub fn generate_keypair(
+        parameters: &DiffieHellmanParameters,
+        service: &NumberTheoryWithPrngService,
+    ) -> DiffieHellmanKeyPair {
+        let DiffieHellmanParameters { p, g } = parameters;
+        let private_value =
+            service.take_random_number_in_range(&2.into(), &p.decrement().decrement());
+        let public_value = service
+            .number_theory_service
+            .fast_exponentiation(g, &private_value, p);
+        DiffieHellmanKeyPair {
+            private_value,
+            public_value,
+        }
+    }
+
+    /// Berechnet das gemeinsame Geheimnis `Z = B^a mod p` aus dem eigenen geheimen Exponenten
+    /// und dem öffentlichen Wert der Gegenseite.
+    ///
+    /// # Argumente
+    /// * `parameters` - Die öffentlichen Parameter.
+    /// * `private_value` - Der eigene geheime Exponent `a`.
+    /// * `other_public_value` - Der öffentliche Wert `B` der Gegenseite.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// * `BigInt` - Das gemeinsame Geheimnis.
+    ///
+    /// # Fehler
+    /// * `KeyExchangeError::InvalidPublicValueError` - Falls `B` nicht in `(1, p - 1)` liegt.
+    ///   Die Werte `1` und `p - 1` erzeugen nur triviale Untergruppen.
+    pub fn compute_shared_secret(
+        parameters: &DiffieHellmanParameters,
+        private_value: &BigInt,
+        other_public_value: &BigInt,
+        service: &NumberTheoryWithPrngService,
+    ) -> Result<BigInt> {
+        let p = &parameters.p;
+        ensure!(
+            other_public_value > &BigInt::one() && other_public_value < &p.decrement(),
+            KeyExchangeError::InvalidPublicValueError(other_public_value.to_string())
+        );
+        Ok(service
+            .number_theory_service
+            .fast_exponentiation(other_public_value, private_value, p))
+    }
+
+    /// Leitet aus dem gemeinsamen Geheimnis einen Schlüssel ab. Das Geheimnis wird dazu als
+    /// Bytefolge mit der Länge von `p` kodiert und in die KDF aus NIST SP 800-56A gegeben.
+    ///
+    /// # Argumente
+    /// * `parameters` - Die öffentlichen Parameter.
+    /// * `shared_secret` - Das gemeinsame Geheimnis.
+    /// * `other_info` - Kontextinformationen, die in die Ableitung eingehen.
+    /// * `key_length` - Die gewünschte Schlüssellänge in Bytes.
+    ///
+    /// # Rückgabe
+    /// * `Vec<u8>` - Der abgeleitete Schlüssel.
+    ///
+    /// # Fehler
+    /// * `KeyExchangeError::InvalidKeyLengthError` - Siehe `concat_kdf_sha256`.
+    pub fn derive_key(
+        parameters: &DiffieHellmanParameters,
+        shared_secret: &BigInt,
+        other_info: &[u8],
+        key_length: usize,
+    ) -> Result<Vec<u8>> {
+        let z = integer_to_octets(shared_secret, octet_length(&parameters.p))?;
+        concat_kdf_sha256(&z, other_info, key_length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
+        Fast, Slow,
+    };
+    use crate::math_core::number_theory::prime_sieve::IncrementalPrimeSearch;
+    use crate::math_core::prime_generator::PrimeSearch;
+
+    fn run_test_for_all_services(test: impl Fn(NumberTheoryWithPrngService)) {
+        test(NumberTheoryWithPrngService::new(Slow, 13)); // Langsame, eigene Implementierung
+        test(NumberTheoryWithPrngService::new(Fast, 13)); // Schnelle, externe Implementierung
+    }
+
+    #[test]
+    fn test_both_parties_derive_same_key() {
+        run_test_for_all_services(|service| {
+            let parameters = DiffieHellmanScheme::generate_parameters(128, 20, &service);
+            let alice = DiffieHellmanScheme::generate_keypair(&parameters, &service);
+            let bob = DiffieHellmanScheme::generate_keypair(&parameters, &service);
+
+            let alice_secret = DiffieHellmanScheme::compute_shared_secret(
+                &parameters,
+                &alice.private_value,
+                &bob.public_value,
+                &service,
+            )
+            .unwrap();
+            let bob_secret = DiffieHellmanScheme::compute_shared_secret(
+                &parameters,
+                &bob.private_value,
+                &alice.public_value,
+                &service,
+            )
+            .unwrap();
+            assert_eq!(alice_secret, bob_secret);
+
+            let alice_key =
+                DiffieHellmanScheme::derive_key(&parameters, &alice_secret, b"", 32).unwrap();
+            let bob_key =
+                DiffieHellmanScheme::derive_key(&parameters, &bob_secret, b"", 32).unwrap();
+            assert_eq!(alice_key, bob_key);
+            assert_eq!(alice_key.len(), 32);
+        });
+    }
+
+    #[test]
+    fn test_parameters_with_incremental_prime_search() {
+        let service = NumberTheoryWithPrngService::new(Fast, 17)
+            .with_prime_search(PrimeSearch::Incremental(IncrementalPrimeSearch::default()));
+        let parameters = DiffieHellmanScheme::generate_parameters(128, 20, &service);
+        assert_eq!(parameters.p.bits(), 128);
+
+        let source_prime: BigInt = (&parameters.p - 1) / 2;
+        let number_theory_service = service.number_theory_service;
+        assert!(number_theory_service.is_probably_prime(&parameters.p, 20, &service));
+        assert!(number_theory_service.is_probably_prime(&source_prime, 20, &service));
+    }
+
+    #[test]
+    fn test_rejects_trivial_public_values() {
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        let parameters = DiffieHellmanScheme::generate_parameters(64, 20, &service);
+        let alice = DiffieHellmanScheme::generate_keypair(&parameters, &service);
+
+        for invalid in [
+            BigInt::one(),
+            parameters.p.decrement(),
+            parameters.p.clone(),
+            BigInt::from(0),
+        ] {
+            assert!(DiffieHellmanScheme::compute_shared_secret(
+                &parameters,
+                &alice.private_value,
+                &invalid,
+                &service
+            )
+            .is_err());
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use crate::encryption::core::rsa::data_conversion::{integer_to_octets, octet_length};
+use crate::math_core::ecc::elliptic_curve::EllipticCurve;
+use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
+use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+use crate::math_core::traits::increment::Increment;
+use crate::shared::errors::KeyExchangeError;
+use crate::shared::hashing::concat_kdf_sha256;
+use anyhow::{ensure, Context, Result};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::One;
+
+/// Die Werte einer Partei im ECDH-Schlüsselaustausch.
+///
+/// # Felder
+/// * `private_value` - Der geheime Skalar `a`.
+/// * `public_value` - Der öffentliche Punkt `A = a * G`.
+#[derive(Clone, Debug)]
+pub struct EcdhKeyPair {
+    pub private_value: BigInt,
+    pub public_value: FiniteFieldEllipticCurvePoint,
+}
+
+/// Der Diffie-Hellman-Schlüsselaustausch auf einer elliptischen Kurve (ECDH).
+pub struct EcdhScheme {}
+
+impl EcdhScheme {
+    /// Erzeugt den geheimen Skalar `a` aus `[1, q - 1]` und den öffentlichen Punkt `A = a * G`
+    /// einer Partei.
+    ///
+    /// # Argumente
+    /// * `curve` - Die Kurve inklusive Generator der Untergruppe.
+    /// * `service` - Der Service für die Zahlentheorie und die Zufallszahlen.
+    ///
+    /// # Rückgabe
+    /// * `EcdhKeyPair` - Der geheime Skalar und der öffentliche Punkt.
+    pub fn generate_keypair<C: EllipticCurve>(
+        curve: &C,
+        service: &NumberTheoryWithPrngService,
+    ) -> Result<EcdhKeyPair> {
+        let private_value = service
+            .take_random_number_in_range(&BigInt::one(), &curve.order_of_subgroup().decrement());
+        let public_value = curve
+            .generator()
+            .multiply_secret(&private_value, curve, &service.number_theory_service)
+            .context("Failed to calculate public point")?;
+        Ok(EcdhKeyPair {
+            private_value,
+            public_value,
+        })
+    }
+
+    /// Berechnet das gemeinsame Geheimnis `Z = a * B` aus dem eigenen geheimen Skalar und dem
+    /// öffentlichen Punkt der Gegenseite.
+    ///
+    /// # Argumente
+    /// * `curve` - Die Kurve.
+    /// * `private_value` - Der eigene geheime Skalar `a`.
+    /// * `other_public_value` - Der öffentliche Punkt `B` der Gegenseite.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// * `FiniteFieldEllipticCurvePoint` - Der gemeinsame Punkt.
+    ///
+    /// # Fehler
+    /// * `KeyExchangeError::InvalidPublicValueError` - Falls `B` nicht auf der Kurve oder nicht
+    ///   in der Untergruppe der Ordnung `q` liegt. Punkte kleiner Ordnung würden Teile von `a`
+    ///   preisgeben.
+    /// * `KeyExchangeError::InfiniteSharedSecretError` - Falls `Z` der Punkt im Unendlichen ist.
+    pub fn compute_shared_secret<C: EllipticCurve>(
+        curve: &C,
+        private_value: &BigInt,
+        other_public_value: &FiniteFieldEllipticCurvePoint,
+        service: &NumberTheoryWithPrngService,
+    ) -> Result<FiniteFieldEllipticCurvePoint> {
+        let service = &service.number_theory_service;
+        let invalid_public_value =
+            || KeyExchangeError::InvalidPublicValueError(other_public_value.to_string());
+
+        ensure!(
+            !other_public_value.is_infinite && curve.has_point(other_public_value),
+            invalid_public_value()
+        );
+        ensure!(
+            other_public_value
+                .multiply(curve.order_of_subgroup(), curve, service)?
+                .is_infinite,
+            invalid_public_value()
+        );
+
+        let shared_secret = other_public_value
+            .multiply_secret(private_value, curve, service)
+            .context("Failed to calculate shared point")?;
+        ensure!(
+            !shared_secret.is_infinite,
+            KeyExchangeError::InfiniteSharedSecretError
+        );
+        Ok(shared_secret)
+    }
+
+    /// Leitet aus dem gemeinsamen Punkt einen Schlüssel ab. Wie in SEC 1 geht nur die
+    /// x-Koordinate, kodiert mit der Länge von `p`, in die KDF aus NIST SP 800-56A ein.
+    ///
+    /// # Argumente
+    /// * `curve` - Die Kurve.
+    /// * `shared_secret` - Der gemeinsame Punkt.
+    /// * `other_info` - Kontextinformationen, die in die Ableitung eingehen.
+    /// * `key_length` - Die gewünschte Schlüssellänge in Bytes.
+    ///
+    /// # Rückgabe
+    /// * `Vec<u8>` - Der abgeleitete Schlüssel.
+    ///
+    /// # Fehler
+    /// * `KeyExchangeError::InvalidKeyLengthError` - Siehe `concat_kdf_sha256`.
+    pub fn derive_key<C: EllipticCurve>(
+        curve: &C,
+        shared_secret: &FiniteFieldEllipticCurvePoint,
+        other_info: &[u8],
+        key_length: usize,
+    ) -> Result<Vec<u8>> {
+        let z = integer_to_octets(&shared_secret.x, octet_length(curve.prime()))?;
+        concat_kdf_sha256(&z, other_info, key_length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math_core::ecc::secure_finite_field_elliptic_curve::SecureFiniteFieldEllipticCurve;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
+        Fast, Slow,
+    };
+
+    fn run_test_for_all_services(test: impl Fn(NumberTheoryWithPrngService)) {
+        test(NumberTheoryWithPrngService::new(Slow, 13)); // Langsame, eigene Implementierung
+        test(NumberTheoryWithPrngService::new(Fast, 13)); // Schnelle, externe Implementierung
+    }
+
+    #[test]
+    fn test_both_parties_derive_same_key() {
+        run_test_for_all_services(|service| {
+            let curve = SecureFiniteFieldEllipticCurve::new(5, 32, 40, &service).unwrap();
+            let alice = EcdhScheme::generate_keypair(&curve, &service).unwrap();
+            let bob = EcdhScheme::generate_keypair(&curve, &service).unwrap();
+
+            let alice_secret = EcdhScheme::compute_shared_secret(
+                &curve,
+                &alice.private_value,
+                &bob.public_value,
+                &service,
+            )
+            .unwrap();
+            let bob_secret = EcdhScheme::compute_shared_secret(
+                &curve,
+                &bob.private_value,
+                &alice.public_value,
+                &service,
+            )
+            .unwrap();
+            assert_eq!(alice_secret, bob_secret);
+
+            let alice_key = EcdhScheme::derive_key(&curve, &alice_secret, b"", 16).unwrap();
+            let bob_key = EcdhScheme::derive_key(&curve, &bob_secret, b"", 16).unwrap();
+            assert_eq!(alice_key, bob_key);
+        });
+    }
+
+    #[test]
+    fn test_rejects_invalid_public_points() {
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        let curve = SecureFiniteFieldEllipticCurve::new(5, 32, 40, &service).unwrap();
+        let alice = EcdhScheme::generate_keypair(&curve, &service).unwrap();
+
+        let not_on_curve = FiniteFieldEllipticCurvePoint::new(
+            curve.generator.x.clone(),
+            (&curve.generator.y + 1) % &curve.prime,
+        );
+        // (0, 0) liegt auf y^2 = x^3 + ax, hat aber die Ordnung 2.
+        let small_order = FiniteFieldEllipticCurvePoint::new(0.into(), 0.into());
+
+        for invalid in [
+            not_on_curve,
+            small_order,
+            FiniteFieldEllipticCurvePoint::infinite(),
+        ] {
+            assert!(EcdhScheme::compute_shared_secret(
+                &curve,
+                &alice.private_value,
+                &invalid,
+                &service
+            )
+            .is_err());
+        }
+    }
+}
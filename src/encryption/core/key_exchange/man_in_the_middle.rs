@@ -0,0 +1,575 @@
+use crate::encryption::asymmetric_encryption_types::{Signer, Verifier};
+use crate::encryption::core::key_exchange::diffie_hellman::{
+    DiffieHellmanParameters, DiffieHellmanScheme,
+};
+use crate::encryption::core::key_exchange::elliptic_curve_diffie_hellman::EcdhScheme;
+use crate::encryption::core::menezes_vanstone::keys::{
+    MenezesVanstonePrivateKey, MenezesVanstonePublicKey,
+};
+use crate::encryption::core::menezes_vanstone::menezes_vanstone_scheme::{
+    MenezesVanstoneScheme, MenezesVanstoneSignature,
+};
+use crate::encryption::core::rsa::keys::{RsaPrivateKey, RsaPublicKey};
+use crate::encryption::core::rsa::rsa_scheme::RsaScheme;
+use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
+use crate::math_core::ecc::secure_finite_field_elliptic_curve::SecureFiniteFieldEllipticCurve;
+use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+use crate::shared::hashing::sha256;
+use crate::shared::hex::to_hex;
+use anyhow::Result;
+use bigdecimal::num_bigint::BigInt;
+use std::fmt::{Debug, Display};
+
+/// Die Länge der in der Simulation abgeleiteten Schlüssel in Bytes.
+const SIMULATION_KEY_LENGTH: usize = 16;
+
+/// Die Teilnehmer der Simulation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Party {
+    Alice,
+    Bob,
+    Eve,
+}
+
+impl Display for Party {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Party::Alice => write!(f, "Alice"),
+            Party::Bob => write!(f, "Bob"),
+            Party::Eve => write!(f, "Eve"),
+        }
+    }
+}
+
+/// Ein Eintrag im Protokoll der Simulation: entweder eine Nachricht zwischen zwei Parteien
+/// oder eine lokale Berechnung (ohne Empfänger).
+///
+/// # Felder
+/// * `sender` - Die Partei, die den Wert sendet oder berechnet.
+/// * `receiver` - Der Empfänger der Nachricht, `None` bei lokalen Berechnungen.
+/// * `description` - Eine Beschreibung des Schrittes.
+/// * `value` - Der gesendete oder berechnete Wert.
+#[derive(Clone, Debug)]
+pub struct TranscriptEntry {
+    pub sender: Party,
+    pub receiver: Option<Party>,
+    pub description: String,
+    pub value: String,
+}
+
+/// Das Ergebnis eines simulierten Protokolldurchlaufs.
+///
+/// # Felder
+/// * `transcript` - Alle ausgetauschten und berechneten Werte in der Reihenfolge des Ablaufs.
+/// * `alice_key` - Der Schlüssel von Alice, `None` bei Abbruch.
+/// * `bob_key` - Der Schlüssel von Bob, `None` bei Abbruch.
+/// * `eve_key_with_alice` - Der Schlüssel, den Eve mit Alice teilt, falls vorhanden.
+/// * `eve_key_with_bob` - Der Schlüssel, den Eve mit Bob teilt, falls vorhanden.
+/// * `attack_detected` - Ob eine Partei den Angriff erkannt und abgebrochen hat.
+#[derive(Clone, Debug)]
+pub struct SimulationResult {
+    pub transcript: Vec<TranscriptEntry>,
+    pub alice_key: Option<Vec<u8>>,
+    pub bob_key: Option<Vec<u8>>,
+    pub eve_key_with_alice: Option<Vec<u8>>,
+    pub eve_key_with_bob: Option<Vec<u8>>,
+    pub attack_detected: bool,
+}
+
+impl SimulationResult {
+    /// Der Angriff war erfolgreich, wenn Eve mit beiden Parteien jeweils deren Schlüssel teilt.
+    pub fn attack_successful(&self) -> bool {
+        self.eve_key_with_alice.is_some()
+            && self.eve_key_with_alice == self.alice_key
+            && self.eve_key_with_bob.is_some()
+            && self.eve_key_with_bob == self.bob_key
+    }
+
+    fn new() -> Self {
+        SimulationResult {
+            transcript: Vec::new(),
+            alice_key: None,
+            bob_key: None,
+            eve_key_with_alice: None,
+            eve_key_with_bob: None,
+            attack_detected: false,
+        }
+    }
+
+    fn send(&mut self, sender: Party, receiver: Party, description: &str, value: impl Display) {
+        self.transcript.push(TranscriptEntry {
+            sender,
+            receiver: Some(receiver),
+            description: description.to_string(),
+            value: value.to_string(),
+        });
+    }
+
+    fn compute(&mut self, party: Party, description: &str, value: impl Display) {
+        self.transcript.push(TranscriptEntry {
+            sender: party,
+            receiver: None,
+            description: description.to_string(),
+            value: value.to_string(),
+        });
+    }
+}
+
+/// Eine Gruppe, über der ein Diffie-Hellman-Schlüsselaustausch durchgeführt werden kann.
+pub trait KeyAgreement {
+    /// Der Typ der öffentlichen Werte.
+    type PublicValue: Clone + Debug + Display;
+
+    /// Erzeugt einen geheimen und den zugehörigen öffentlichen Wert.
+    fn generate(
+        &self,
+        service: &NumberTheoryWithPrngService,
+    ) -> Result<(BigInt, Self::PublicValue)>;
+
+    /// Berechnet das gemeinsame Geheimnis und leitet daraus einen Schlüssel ab.
+    fn shared_key(
+        &self,
+        private_value: &BigInt,
+        other_public_value: &Self::PublicValue,
+        service: &NumberTheoryWithPrngService,
+    ) -> Result<Vec<u8>>;
+}
+
+impl KeyAgreement for DiffieHellmanParameters {
+    type PublicValue = BigInt;
+
+    fn generate(&self, service: &NumberTheoryWithPrngService) -> Result<(BigInt, BigInt)> {
+        let key_pair = DiffieHellmanScheme::generate_keypair(self, service);
+        Ok((key_pair.private_value, key_pair.public_value))
+    }
+
+    fn shared_key(
+        &self,
+        private_value: &BigInt,
+        other_public_value: &BigInt,
+        service: &NumberTheoryWithPrngService,
+    ) -> Result<Vec<u8>> {
+        let secret = DiffieHellmanScheme::compute_shared_secret(
+            self,
+            private_value,
+            other_public_value,
+            service,
+        )?;
+        DiffieHellmanScheme::derive_key(self, &secret, b"", SIMULATION_KEY_LENGTH)
+    }
+}
+
+impl KeyAgreement for SecureFiniteFieldEllipticCurve {
+    type PublicValue = FiniteFieldEllipticCurvePoint;
+
+    fn generate(
+        &self,
+        service: &NumberTheoryWithPrngService,
+    ) -> Result<(BigInt, FiniteFieldEllipticCurvePoint)> {
+        let key_pair = EcdhScheme::generate_keypair(self, service)?;
+        Ok((key_pair.private_value, key_pair.public_value))
+    }
+
+    fn shared_key(
+        &self,
+        private_value: &BigInt,
+        other_public_value: &FiniteFieldEllipticCurvePoint,
+        service: &NumberTheoryWithPrngService,
+    ) -> Result<Vec<u8>> {
+        let secret =
+            EcdhScheme::compute_shared_secret(self, private_value, other_public_value, service)?;
+        EcdhScheme::derive_key(self, &secret, b"", SIMULATION_KEY_LENGTH)
+    }
+}
+
+/// Das langfristige Signaturschlüsselpaar einer Partei für Station-to-Station.
+#[derive(Clone, Debug)]
+pub enum SignatureKeys {
+    Rsa {
+        private_key: RsaPrivateKey,
+        public_key: RsaPublicKey,
+    },
+    MenezesVanstone {
+        private_key: MenezesVanstonePrivateKey,
+        public_key: MenezesVanstonePublicKey,
+    },
+}
+
+/// Eine Signatur, die mit einem der beiden Verfahren erstellt wurde.
+#[derive(Clone, Debug)]
+pub enum StsSignature {
+    Rsa(BigInt),
+    MenezesVanstone(MenezesVanstoneSignature),
+}
+
+impl Display for StsSignature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StsSignature::Rsa(signature) => write!(f, "{}", signature),
+            StsSignature::MenezesVanstone(signature) => {
+                write!(f, "(r = {}, s = {})", signature.r, signature.s)
+            }
+        }
+    }
+}
+
+impl SignatureKeys {
+    /// Signiert eine Nachricht. Bei RSA wird der SHA256-Hash modulo `n` signiert.
+    fn sign(&self, message: &str, service: &NumberTheoryWithPrngService) -> Result<StsSignature> {
+        match self {
+            SignatureKeys::Rsa { private_key, .. } => {
+                let hash = sha256(message) % &private_key.n;
+                Ok(StsSignature::Rsa(RsaScheme::sign(
+                    private_key,
+                    &hash,
+                    service,
+                )))
+            }
+            SignatureKeys::MenezesVanstone { private_key, .. } => {
+                Ok(StsSignature::MenezesVanstone(MenezesVanstoneScheme::sign(
+                    private_key,
+                    message,
+                    service,
+                )?))
+            }
+        }
+    }
+
+    /// Überprüft eine Signatur mit dem öffentlichen Schlüssel.
+    fn verify(
+        &self,
+        signature: &StsSignature,
+        message: &str,
+        service: &NumberTheoryWithPrngService,
+    ) -> Result<bool> {
+        match (self, signature) {
+            (SignatureKeys::Rsa { public_key, .. }, StsSignature::Rsa(signature)) => {
+                let hash = sha256(message) % &public_key.n;
+                Ok(RsaScheme::verify(public_key, signature, &hash, service))
+            }
+            (
+                SignatureKeys::MenezesVanstone { public_key, .. },
+                StsSignature::MenezesVanstone(signature),
+            ) => MenezesVanstoneScheme::verify(public_key, signature, message, service),
+            _ => Ok(false),
+        }
+    }
+}
+
+/// Die signierte Nachricht im Station-to-Station-Protokoll: die Konkatenation des eigenen
+/// und des empfangenen öffentlichen Wertes.
+fn sts_message(own: &impl Display, other: &impl Display) -> String {
+    format!("{}|{}", own, other)
+}
+
+/// Simuliert den unauthentisierten Diffie-Hellman-Austausch mit der aktiven Angreiferin Eve.
+/// Eve fängt beide öffentlichen Werte ab und ersetzt sie durch eigene, sodass sie am Ende
+/// mit Alice und Bob jeweils einen eigenen Schlüssel teilt.
+///
+/// # Argumente
+/// * `group` - Die Gruppe für den Schlüsselaustausch.
+/// * `service` - Der Service für die Zahlentheorie und die Zufallszahlen.
+///
+/// # Rückgabe
+/// * `SimulationResult` - Das Protokoll und die Schlüssel aller Parteien.
+pub fn simulate_unauthenticated<K: KeyAgreement>(
+    group: &K,
+    service: &NumberTheoryWithPrngService,
+) -> Result<SimulationResult> {
+    let mut result = SimulationResult::new();
+
+    let (a, big_a) = group.generate(service)?;
+    result.send(
+        Party::Alice,
+        Party::Bob,
+        "Öffentlicher Wert A (von Eve abgefangen)",
+        &big_a,
+    );
+
+    let (e_alice, big_e_alice) = group.generate(service)?;
+    let (e_bob, big_e_bob) = group.generate(service)?;
+    result.send(
+        Party::Eve,
+        Party::Bob,
+        "Gefälschter Wert E_B anstelle von A",
+        &big_e_bob,
+    );
+
+    let (b, big_b) = group.generate(service)?;
+    result.send(
+        Party::Bob,
+        Party::Alice,
+        "Öffentlicher Wert B (von Eve abgefangen)",
+        &big_b,
+    );
+    result.send(
+        Party::Eve,
+        Party::Alice,
+        "Gefälschter Wert E_A anstelle von B",
+        &big_e_alice,
+    );
+
+    let alice_key = group.shared_key(&a, &big_e_alice, service)?;
+    let bob_key = group.shared_key(&b, &big_e_bob, service)?;
+    let eve_key_with_alice = group.shared_key(&e_alice, &big_a, service)?;
+    let eve_key_with_bob = group.shared_key(&e_bob, &big_b, service)?;
+
+    result.compute(Party::Alice, "Schlüssel aus E_A", to_hex(&alice_key));
+    result.compute(Party::Bob, "Schlüssel aus E_B", to_hex(&bob_key));
+    result.compute(
+        Party::Eve,
+        "Schlüssel mit Alice aus A",
+        to_hex(&eve_key_with_alice),
+    );
+    result.compute(
+        Party::Eve,
+        "Schlüssel mit Bob aus B",
+        to_hex(&eve_key_with_bob),
+    );
+
+    result.alice_key = Some(alice_key);
+    result.bob_key = Some(bob_key);
+    result.eve_key_with_alice = Some(eve_key_with_alice);
+    result.eve_key_with_bob = Some(eve_key_with_bob);
+    Ok(result)
+}
+
+/// Simuliert das Station-to-Station-Protokoll (vereinfacht, ohne Verschlüsselung der
+/// Signaturen). Beide Parteien signieren ihren eigenen und den empfangenen öffentlichen Wert
+/// mit ihrem langfristigen Schlüssel. Ist Eve aktiv, ersetzt sie die öffentlichen Werte wie im
+/// unauthentisierten Fall. Da sie Bobs Signatur über die gefälschten Werte nicht erzeugen
+/// kann, erkennt Alice den Angriff und bricht ab.
+///
+/// # Argumente
+/// * `group` - Die Gruppe für den Schlüsselaustausch.
+/// * `alice_keys` - Die Signaturschlüssel von Alice.
+/// * `bob_keys` - Die Signaturschlüssel von Bob.
+/// * `with_attacker` - Ob Eve den Austausch angreift.
+/// * `service` - Der Service für die Zahlentheorie und die Zufallszahlen.
+///
+/// # Rückgabe
+/// * `SimulationResult` - Das Protokoll und die Schlüssel aller Parteien.
+pub fn simulate_station_to_station<K: KeyAgreement>(
+    group: &K,
+    alice_keys: &SignatureKeys,
+    bob_keys: &SignatureKeys,
+    with_attacker: bool,
+    service: &NumberTheoryWithPrngService,
+) -> Result<SimulationResult> {
+    let mut result = SimulationResult::new();
+
+    // Schritt 1: Alice -> Bob: A
+    let (a, big_a) = group.generate(service)?;
+    let mut eve_values = None;
+    let a_at_bob = if with_attacker {
+        result.send(
+            Party::Alice,
+            Party::Bob,
+            "Öffentlicher Wert A (von Eve abgefangen)",
+            &big_a,
+        );
+        let (e_alice, big_e_alice) = group.generate(service)?;
+        let (e_bob, big_e_bob) = group.generate(service)?;
+        result.send(
+            Party::Eve,
+            Party::Bob,
+            "Gefälschter Wert E_B anstelle von A",
+            &big_e_bob,
+        );
+        eve_values = Some((e_alice, big_e_alice, e_bob));
+        big_e_bob
+    } else {
+        result.send(Party::Alice, Party::Bob, "Öffentlicher Wert A", &big_a);
+        big_a.clone()
+    };
+
+    // Schritt 2: Bob -> Alice: B, Sig_B(B || A)
+    let (b, big_b) = group.generate(service)?;
+    let bob_key = group.shared_key(&b, &a_at_bob, service)?;
+    let bob_signature = bob_keys.sign(&sts_message(&big_b, &a_at_bob), service)?;
+    result.compute(Party::Bob, "Schlüssel", to_hex(&bob_key));
+    result.bob_key = Some(bob_key);
+
+    let b_at_alice = match &eve_values {
+        Some((e_alice, big_e_alice, e_bob)) => {
+            result.send(
+                Party::Bob,
+                Party::Alice,
+                "Öffentlicher Wert B (von Eve abgefangen)",
+                &big_b,
+            );
+            result.send(
+                Party::Bob,
+                Party::Alice,
+                "Signatur Sig_B(B || E_B)",
+                &bob_signature,
+            );
+            result.send(
+                Party::Eve,
+                Party::Alice,
+                "Gefälschter Wert E_A anstelle von B",
+                big_e_alice,
+            );
+            result.send(
+                Party::Eve,
+                Party::Alice,
+                "Weitergeleitete Signatur von Bob (Eve kann Sig_B(E_A || A) nicht erzeugen)",
+                &bob_signature,
+            );
+            let eve_key_with_alice = group.shared_key(e_alice, &big_a, service)?;
+            let eve_key_with_bob = group.shared_key(e_bob, &big_b, service)?;
+            result.compute(
+                Party::Eve,
+                "Schlüssel mit Alice aus A",
+                to_hex(&eve_key_with_alice),
+            );
+            result.compute(
+                Party::Eve,
+                "Schlüssel mit Bob aus B",
+                to_hex(&eve_key_with_bob),
+            );
+            result.eve_key_with_alice = Some(eve_key_with_alice);
+            result.eve_key_with_bob = Some(eve_key_with_bob);
+            big_e_alice.clone()
+        }
+        None => {
+            result.send(Party::Bob, Party::Alice, "Öffentlicher Wert B", &big_b);
+            result.send(
+                Party::Bob,
+                Party::Alice,
+                "Signatur Sig_B(B || A)",
+                &bob_signature,
+            );
+            big_b.clone()
+        }
+    };
+
+    // Schritt 3: Alice prüft Bobs Signatur über (empfangenes B || eigenes A).
+    let bob_signature_valid =
+        bob_keys.verify(&bob_signature, &sts_message(&b_at_alice, &big_a), service)?;
+    result.compute(
+        Party::Alice,
+        "Prüfung der Signatur von Bob",
+        bob_signature_valid,
+    );
+    if !bob_signature_valid {
+        result.compute(Party::Alice, "Abbruch des Protokolls", "Angriff erkannt");
+        result.attack_detected = true;
+        return Ok(result);
+    }
+    let alice_key = group.shared_key(&a, &b_at_alice, service)?;
+    result.compute(Party::Alice, "Schlüssel", to_hex(&alice_key));
+    result.alice_key = Some(alice_key);
+
+    // Schritt 4: Alice -> Bob: Sig_A(A || B), Bob prüft über (empfangenes A || eigenes B).
+    let alice_signature = alice_keys.sign(&sts_message(&big_a, &b_at_alice), service)?;
+    result.send(
+        Party::Alice,
+        Party::Bob,
+        "Signatur Sig_A(A || B)",
+        &alice_signature,
+    );
+    let alice_signature_valid =
+        alice_keys.verify(&alice_signature, &sts_message(&a_at_bob, &big_b), service)?;
+    result.compute(
+        Party::Bob,
+        "Prüfung der Signatur von Alice",
+        alice_signature_valid,
+    );
+    if !alice_signature_valid {
+        result.compute(Party::Bob, "Abbruch des Protokolls", "Angriff erkannt");
+        result.attack_detected = true;
+        result.bob_key = None;
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::asymmetric_encryption_types::KeyGenerator;
+    use crate::encryption::core::rsa::rsa_scheme::RsaKeyGenConfig;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+
+    fn rsa_keys(random_seed: u32) -> SignatureKeys {
+        let config = RsaKeyGenConfig {
+            key_size: 256,
+            miller_rabin_iterations: 20,
+            random_seed,
+            number_theory_service: NumberTheoryService::new(Fast),
+            prime_count: 2,
+        };
+        let key_pair = RsaScheme::generate_keypair(&config).unwrap();
+        SignatureKeys::Rsa {
+            private_key: key_pair.private_key,
+            public_key: key_pair.public_key,
+        }
+    }
+
+    fn mv_keys(service: &NumberTheoryWithPrngService) -> SignatureKeys {
+        let key_pair = MenezesVanstoneScheme::generate_keypair(5, 32, 40, service).unwrap();
+        SignatureKeys::MenezesVanstone {
+            private_key: key_pair.private_key,
+            public_key: key_pair.public_key,
+        }
+    }
+
+    #[test]
+    fn test_unauthenticated_exchange_is_broken() {
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        let parameters = DiffieHellmanScheme::generate_parameters(64, 20, &service);
+        let result = simulate_unauthenticated(&parameters, &service).unwrap();
+
+        assert!(result.attack_successful());
+        assert_ne!(result.alice_key, result.bob_key);
+        assert_ne!(result.eve_key_with_alice, result.eve_key_with_bob);
+        assert!(!result.attack_detected);
+        assert_eq!(result.transcript.len(), 8);
+
+        let curve = SecureFiniteFieldEllipticCurve::new(5, 32, 40, &service).unwrap();
+        let result = simulate_unauthenticated(&curve, &service).unwrap();
+        assert!(result.attack_successful());
+    }
+
+    #[test]
+    fn test_station_to_station_detects_attack() {
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        let parameters = DiffieHellmanScheme::generate_parameters(64, 20, &service);
+        let curve = SecureFiniteFieldEllipticCurve::new(5, 32, 40, &service).unwrap();
+
+        for (alice_keys, bob_keys) in [
+            (rsa_keys(17), rsa_keys(19)),
+            (mv_keys(&service), mv_keys(&service)),
+        ] {
+            let result =
+                simulate_station_to_station(&parameters, &alice_keys, &bob_keys, true, &service)
+                    .unwrap();
+            assert!(result.attack_detected);
+            assert!(!result.attack_successful());
+            assert!(result.alice_key.is_none());
+
+            let result =
+                simulate_station_to_station(&curve, &alice_keys, &bob_keys, true, &service)
+                    .unwrap();
+            assert!(result.attack_detected);
+            assert!(!result.attack_successful());
+        }
+    }
+
+    #[test]
+    fn test_station_to_station_without_attacker() {
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        let parameters = DiffieHellmanScheme::generate_parameters(64, 20, &service);
+        let alice_keys = rsa_keys(17);
+        let bob_keys = rsa_keys(19);
+
+        let result =
+            simulate_station_to_station(&parameters, &alice_keys, &bob_keys, false, &service)
+                .unwrap();
+        assert!(!result.attack_detected);
+        assert!(result.alice_key.is_some());
+        assert_eq!(result.alice_key, result.bob_key);
+        assert!(result.eve_key_with_alice.is_none());
+    }
+}
@@ -0,0 +1,3 @@
+pub mod diffie_hellman;
+pub mod elliptic_curve_diffie_hellman;
+pub mod man_in_the_middle;
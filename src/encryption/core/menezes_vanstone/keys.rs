@@ -1,4 +1,7 @@
-use crate::api::endpoints::mv::{MvKeyPairBean, MvPrivateKeyBean, MvPublicKeyBean};
+use crate::api::endpoints::mv::{
+    MvKeyPairBean, MvNamedPrivateKeyBean, MvNamedPublicKeyBean, MvPrivateKeyBean, MvPublicKeyBean,
+};
+use anyhow::Error;
 use num::BigInt;
 
 use crate::encryption::asymmetric_encryption_types::{
@@ -7,33 +10,54 @@ use crate::encryption::asymmetric_encryption_types::{
 };
 use crate::encryption::core::menezes_vanstone::menezes_vanstone_scheme::MenezesVanstoneScheme;
 use crate::encryption::encryption_types::Key;
+use crate::math_core::ecc::elliptic_curve::EllipticCurve;
 use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
+use crate::math_core::ecc::named_curves::NamedCurve;
 use crate::math_core::ecc::secure_finite_field_elliptic_curve::SecureFiniteFieldEllipticCurve;
+use crate::math_core::ecc::weierstrass_elliptic_curve::WeierstrassEllipticCurve;
 
+/// Der öffentliche Schlüssel für Menezes-Vanstone. Standardmäßig wird eine sichere Kurve
+/// verwendet, es kann aber jede Kurve eingesetzt werden, die `EllipticCurve` implementiert.
 #[derive(Clone, Debug)]
-pub struct MenezesVanstonePublicKey {
-    pub curve: SecureFiniteFieldEllipticCurve,
+pub struct MenezesVanstonePublicKey<C: EllipticCurve = SecureFiniteFieldEllipticCurve> {
+    pub curve: C,
     pub y: FiniteFieldEllipticCurvePoint,
 }
 
-impl From<MvPublicKeyBean> for MenezesVanstonePublicKey {
+impl TryFrom<MvPublicKeyBean> for MenezesVanstonePublicKey {
+    type Error = Error;
+
     /// Mapped die Bean in das Domain-Modell
-    fn from(mv_public_key: MvPublicKeyBean) -> Self {
+    fn try_from(mv_public_key: MvPublicKeyBean) -> Result<Self, Self::Error> {
         let curve = SecureFiniteFieldEllipticCurve::from(mv_public_key.curve);
-        let y = FiniteFieldEllipticCurvePoint::from(mv_public_key.y);
-        MenezesVanstonePublicKey { curve, y }
+        let y = mv_public_key.y.to_point(&curve)?;
+        Ok(MenezesVanstonePublicKey { curve, y })
     }
 }
 
-impl Key<MenezesVanstoneScheme> for MenezesVanstonePublicKey {}
-impl AsymmetricKey<MenezesVanstoneScheme> for MenezesVanstonePublicKey {}
-impl PublicKey<MenezesVanstoneScheme> for MenezesVanstonePublicKey {}
-impl AsymmetricEncryptionKey<MenezesVanstoneScheme> for MenezesVanstonePublicKey {}
-impl VerificationKey<MenezesVanstoneScheme> for MenezesVanstonePublicKey {}
+impl TryFrom<MvNamedPublicKeyBean> for MenezesVanstonePublicKey<WeierstrassEllipticCurve> {
+    type Error = Error;
+
+    /// Mapped die Bean in das Domain-Modell
+    fn try_from(bean: MvNamedPublicKeyBean) -> Result<Self, Self::Error> {
+        let curve = bean.curve_name.parse::<NamedCurve>()?.curve();
+        let y = bean.y.to_point(&curve)?;
+        Ok(MenezesVanstonePublicKey { curve, y })
+    }
+}
+
+impl<C: EllipticCurve> Key<MenezesVanstoneScheme<C>> for MenezesVanstonePublicKey<C> {}
+impl<C: EllipticCurve> AsymmetricKey<MenezesVanstoneScheme<C>> for MenezesVanstonePublicKey<C> {}
+impl<C: EllipticCurve> PublicKey<MenezesVanstoneScheme<C>> for MenezesVanstonePublicKey<C> {}
+impl<C: EllipticCurve> AsymmetricEncryptionKey<MenezesVanstoneScheme<C>>
+    for MenezesVanstonePublicKey<C>
+{
+}
+impl<C: EllipticCurve> VerificationKey<MenezesVanstoneScheme<C>> for MenezesVanstonePublicKey<C> {}
 
 #[derive(Clone, Debug)]
-pub struct MenezesVanstonePrivateKey {
-    pub curve: SecureFiniteFieldEllipticCurve,
+pub struct MenezesVanstonePrivateKey<C: EllipticCurve = SecureFiniteFieldEllipticCurve> {
+    pub curve: C,
     pub x: BigInt,
 }
 
@@ -46,42 +70,62 @@ impl From<MvPrivateKeyBean> for MenezesVanstonePrivateKey {
     }
 }
 
-impl Key<MenezesVanstoneScheme> for MenezesVanstonePrivateKey {}
+impl TryFrom<MvNamedPrivateKeyBean> for MenezesVanstonePrivateKey<WeierstrassEllipticCurve> {
+    type Error = Error;
 
-impl AsymmetricKey<MenezesVanstoneScheme> for MenezesVanstonePrivateKey {}
+    /// Mapped die Bean in das Domain-Modell
+    fn try_from(bean: MvNamedPrivateKeyBean) -> Result<Self, Self::Error> {
+        let curve = bean.curve_name.parse::<NamedCurve>()?.curve();
+        let x = bean.x.parse()?;
+        Ok(MenezesVanstonePrivateKey { curve, x })
+    }
+}
+
+impl<C: EllipticCurve> Key<MenezesVanstoneScheme<C>> for MenezesVanstonePrivateKey<C> {}
 
-impl PrivateKey<MenezesVanstoneScheme> for MenezesVanstonePrivateKey {}
+impl<C: EllipticCurve> AsymmetricKey<MenezesVanstoneScheme<C>> for MenezesVanstonePrivateKey<C> {}
 
-impl AsymmetricDecryptionKey<MenezesVanstoneScheme> for MenezesVanstonePrivateKey {}
+impl<C: EllipticCurve> PrivateKey<MenezesVanstoneScheme<C>> for MenezesVanstonePrivateKey<C> {}
 
-impl SignatureKey<MenezesVanstoneScheme> for MenezesVanstonePrivateKey {}
+impl<C: EllipticCurve> AsymmetricDecryptionKey<MenezesVanstoneScheme<C>>
+    for MenezesVanstonePrivateKey<C>
+{
+}
+
+impl<C: EllipticCurve> SignatureKey<MenezesVanstoneScheme<C>> for MenezesVanstonePrivateKey<C> {}
 
 #[derive(Clone, Debug)]
-pub struct MenezesVanstoneKeyPair {
-    pub public_key: MenezesVanstonePublicKey,
-    pub private_key: MenezesVanstonePrivateKey,
+pub struct MenezesVanstoneKeyPair<C: EllipticCurve = SecureFiniteFieldEllipticCurve> {
+    pub public_key: MenezesVanstonePublicKey<C>,
+    pub private_key: MenezesVanstonePrivateKey<C>,
 }
 
-impl From<MvKeyPairBean> for MenezesVanstoneKeyPair {
+impl TryFrom<MvKeyPairBean> for MenezesVanstoneKeyPair {
+    type Error = Error;
+
     /// Mapped die Bean in das Domain-Modell
-    fn from(mv_key_pair: MvKeyPairBean) -> Self {
-        let public_key = MenezesVanstonePublicKey::from(mv_key_pair.public_key);
+    fn try_from(mv_key_pair: MvKeyPairBean) -> Result<Self, Self::Error> {
+        let public_key = MenezesVanstonePublicKey::try_from(mv_key_pair.public_key)?;
         let private_key = MenezesVanstonePrivateKey::from(mv_key_pair.private_key);
-        MenezesVanstoneKeyPair {
+        Ok(MenezesVanstoneKeyPair {
             public_key,
             private_key,
-        }
+        })
     }
 }
 
-impl AsymmetricKeyPair<MenezesVanstonePublicKey, MenezesVanstonePrivateKey, MenezesVanstoneScheme>
-    for MenezesVanstoneKeyPair
+impl<C: EllipticCurve>
+    AsymmetricKeyPair<
+        MenezesVanstonePublicKey<C>,
+        MenezesVanstonePrivateKey<C>,
+        MenezesVanstoneScheme<C>,
+    > for MenezesVanstoneKeyPair<C>
 {
-    fn public(&self) -> MenezesVanstonePublicKey {
+    fn public(&self) -> MenezesVanstonePublicKey<C> {
         self.public_key.clone()
     }
 
-    fn private(&self) -> MenezesVanstonePrivateKey {
+    fn private(&self) -> MenezesVanstonePrivateKey<C> {
         self.private_key.clone()
     }
 }
@@ -1,6 +1,7 @@
 use anyhow::Context;
 use anyhow::{ensure, Result};
 use std::cmp::max;
+use std::marker::PhantomData;
 
 use crate::api::endpoints::mv::MvSignatureBean;
 use bigdecimal::num_bigint::BigInt;
@@ -18,6 +19,7 @@ use crate::encryption::encryption_types::{Decryptor, EncryptionScheme, Encryptor
 use crate::encryption::string_schemes::decimal_unicode_schemes::from_decimal_block_scheme::FromDecimalBlockScheme;
 use crate::encryption::string_schemes::decimal_unicode_schemes::keys::DecimalUnicodeConversionSchemeKey;
 use crate::encryption::symmetric_encryption_types::SymmetricDecryptor;
+use crate::math_core::ecc::elliptic_curve::EllipticCurve;
 use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
 use crate::math_core::ecc::secure_finite_field_elliptic_curve::SecureFiniteFieldEllipticCurve;
 use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceTrait;
@@ -25,7 +27,8 @@ use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngServi
 use crate::math_core::traits::increment::Increment;
 use crate::math_core::traits::logarithm::Logarithm;
 use crate::shared::errors::MenezesVanstoneError;
-use crate::shared::hashing::sha256;
+use crate::shared::hashing::{sha256, sha256_bytes};
+use crate::shared::rfc6979::DeterministicNonceGenerator;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct MenezesVanstonePlaintext {
@@ -99,11 +102,125 @@ impl From<MvSignatureBean> for MenezesVanstoneSignature {
     }
 }
 
-pub struct MenezesVanstoneScheme {}
+/// Das Menezes-Vanstone-Verfahren über einer elliptischen Kurve vom Typ `C`. Standardmäßig
+/// wird eine sichere Kurve verwendet, die bei der Schlüsselerzeugung generiert wird.
+pub struct MenezesVanstoneScheme<C: EllipticCurve = SecureFiniteFieldEllipticCurve> {
+    _curve: PhantomData<C>,
+}
+
+impl<C: EllipticCurve> EncryptionScheme for MenezesVanstoneScheme<C> {}
+
+impl<C: EllipticCurve> AsymmetricEncryptionScheme for MenezesVanstoneScheme<C> {}
+
+impl<C: EllipticCurve> MenezesVanstoneScheme<C> {
+    /// Erzeugt ein Schlüsselpaar auf einer vorgegebenen Kurve.
+    ///
+    /// # Argumente
+    /// * `curve` - Die Kurve inklusive Generator der Untergruppe.
+    /// * `service_wrapper` - Der Service für die Zahlentheorie und die Zufallszahlen.
+    ///
+    /// # Rückgabe
+    /// * `MenezesVanstoneKeyPair` - Das Schlüsselpaar.
+    pub fn generate_keypair_for_curve(
+        curve: &C,
+        service_wrapper: &NumberTheoryWithPrngService,
+    ) -> Result<MenezesVanstoneKeyPair<C>> {
+        let order_of_subgroup = curve.order_of_subgroup();
+        let (mut x, mut y);
+        loop {
+            x = service_wrapper
+                .take_random_number_in_range(&1.into(), &order_of_subgroup.decrement());
+            y = curve
+                .generator()
+                .multiply_secret(&x, curve, &service_wrapper.number_theory_service)
+                .context("Failed to calculate key-component y")?;
+            if !y.x.is_zero() && !y.y.is_zero() {
+                break;
+            }
+        }
+
+        let public_key = MenezesVanstonePublicKey {
+            curve: curve.clone(),
+            y,
+        };
+
+        let private_key = MenezesVanstonePrivateKey {
+            curve: curve.clone(),
+            x,
+        };
+
+        Ok(MenezesVanstoneKeyPair {
+            public_key,
+            private_key,
+        })
+    }
+
+    /// Signiert eine Nachricht mit einer deterministischen Nonce nach RFC 6979. Anders als bei
+    /// `sign` hängt k nicht vom Zufallsgenerator ab, sondern nur vom privaten Schlüssel und vom
+    /// SHA256-Hash der Nachricht. Dieselbe Nachricht ergibt mit demselben Schlüssel also immer
+    /// dieselbe Signatur und ein wiederverwendeter Seed kann den Schlüssel nicht preisgeben.
+    ///
+    /// # Argumente
+    /// * `key` - Der private Schlüssel.
+    /// * `message` - Die Nachricht.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// * `MenezesVanstoneSignature` - Die Signatur.
+    pub fn sign_deterministic(
+        key: &MenezesVanstonePrivateKey<C>,
+        message: &str,
+        service: &NumberTheoryWithPrngService,
+    ) -> Result<MenezesVanstoneSignature> {
+        let mut generator = DeterministicNonceGenerator::new(
+            &key.x,
+            &sha256_bytes(message.as_bytes()),
+            key.curve.order_of_subgroup(),
+        );
+        Self::sign_with_nonces(key, message, service, || generator.next_nonce())
+    }
 
-impl EncryptionScheme for MenezesVanstoneScheme {}
+    /// Berechnet die Signatur (r, s) mit r = (k · G).x mod q und s = k^-1 · (H(m) + x · r) mod q.
+    /// Ist r oder s null, wird die nächste Nonce aus `next_nonce` verwendet.
+    fn sign_with_nonces(
+        key: &MenezesVanstonePrivateKey<C>,
+        message: &str,
+        service: &NumberTheoryWithPrngService,
+        mut next_nonce: impl FnMut() -> BigInt,
+    ) -> Result<MenezesVanstoneSignature> {
+        let curve = &key.curve;
+        let q = curve.order_of_subgroup();
+
+        let hashed_message = sha256(message);
 
-impl AsymmetricEncryptionScheme for MenezesVanstoneScheme {}
+        // Schleife, bis r und s jeweils ungleich 0 sind.
+        loop {
+            let k = &next_nonce();
+            let point = curve
+                .generator()
+                .multiply_secret(k, curve, &service.number_theory_service)
+                .context("Failed to calculate Point (c1, c2)")?;
+
+            ensure!(
+                !point.is_infinite,
+                "Calculated point is infinite, but cannot be since k < |H|. With k = {}",
+                k
+            );
+
+            let r = point.x.rem_euclid(q);
+            if r.is_zero() {
+                continue;
+            }
+            let inverse_k = service.number_theory_service.modulo_inverse(k, q).unwrap();
+            let s = (inverse_k * (&hashed_message + &key.x * &r)).rem_euclid(q);
+            if s.is_zero() {
+                continue;
+            }
+            debug!("MV: Signatur mit k = {}, r = {} und s = {}", k, r, s);
+            return Ok(MenezesVanstoneSignature { r, s });
+        }
+    }
+}
 
 impl MenezesVanstoneScheme {
     pub fn generate_keypair(
@@ -126,41 +243,17 @@ impl MenezesVanstoneScheme {
         )
         .context("Failed to create secure elliptic curve")?;
 
-        let order_of_subgroup = &curve.order_of_subgroup;
-        let (mut x, mut y);
-        loop {
-            x = service_wrapper
-                .take_random_number_in_range(&1.into(), &order_of_subgroup.decrement());
-            y = curve
-                .generator
-                .multiply(&x, &curve, &service_wrapper.number_theory_service)
-                .context("Failed to calculate key-component y")?;
-            if !y.x.is_zero() && !y.y.is_zero() {
-                break;
-            }
-        }
-
-        let public_key = MenezesVanstonePublicKey {
-            curve: curve.clone(),
-            y,
-        };
-
-        let private_key = MenezesVanstonePrivateKey { curve, x };
-
-        Ok(MenezesVanstoneKeyPair {
-            public_key,
-            private_key,
-        })
+        Self::generate_keypair_for_curve(&curve, service_wrapper)
     }
 }
 
-impl Encryptor<MenezesVanstoneScheme> for MenezesVanstoneScheme {
+impl<C: EllipticCurve> Encryptor<MenezesVanstoneScheme<C>> for MenezesVanstoneScheme<C> {
     type Input = MenezesVanstonePlaintext;
     type Output = Result<MenezesVanstoneCiphertext>;
-    type Key = MenezesVanstonePublicKey;
+    type Key = MenezesVanstonePublicKey<C>;
 }
 
-impl AsymmetricEncryptor<MenezesVanstoneScheme> for MenezesVanstoneScheme {
+impl<C: EllipticCurve> AsymmetricEncryptor<MenezesVanstoneScheme<C>> for MenezesVanstoneScheme<C> {
     fn encrypt(
         key: &Self::Key,
         plaintext: &Self::Input,
@@ -168,7 +261,7 @@ impl AsymmetricEncryptor<MenezesVanstoneScheme> for MenezesVanstoneScheme {
     ) -> Self::Output {
         let m1 = plaintext.first.clone();
         let m2 = plaintext.second.clone();
-        let prime = &key.curve.prime;
+        let prime = key.curve.prime();
 
         let curve = &key.curve;
 
@@ -178,10 +271,10 @@ impl AsymmetricEncryptor<MenezesVanstoneScheme> for MenezesVanstoneScheme {
             // Dadurch, dass ein Wert < |H| gewählt wird, ist garantiert, dass der Punkt k*g niemals
             // im Unendlichen liegen wird.
             k = service
-                .take_random_number_in_range(&1.into(), &curve.order_of_subgroup.decrement());
+                .take_random_number_in_range(&1.into(), &curve.order_of_subgroup().decrement());
             let point = key
                 .y
-                .multiply(&k, curve, &service.number_theory_service)
+                .multiply_secret(&k, curve, &service.number_theory_service)
                 .context("Failed to calculate Point (c1, c2)")?;
 
             ensure!(
@@ -202,8 +295,8 @@ impl AsymmetricEncryptor<MenezesVanstoneScheme> for MenezesVanstoneScheme {
         }
         let a = key
             .curve
-            .generator
-            .multiply(&k, curve, &service.number_theory_service)
+            .generator()
+            .multiply_secret(&k, curve, &service.number_theory_service)
             .context("Failed to calculate Point a")?;
         let b1 = m1.and_then(|m| Some((c1 * m) % prime));
         let b2 = m2.and_then(|m| Some((c2 * m) % prime));
@@ -216,13 +309,13 @@ impl AsymmetricEncryptor<MenezesVanstoneScheme> for MenezesVanstoneScheme {
     }
 }
 
-impl Decryptor<MenezesVanstoneScheme> for MenezesVanstoneScheme {
+impl<C: EllipticCurve> Decryptor<MenezesVanstoneScheme<C>> for MenezesVanstoneScheme<C> {
     type Input = MenezesVanstoneCiphertext;
     type Output = Result<MenezesVanstonePlaintext>;
-    type Key = MenezesVanstonePrivateKey;
+    type Key = MenezesVanstonePrivateKey<C>;
 }
 
-impl AsymmetricDecryptor<MenezesVanstoneScheme> for MenezesVanstoneScheme {
+impl<C: EllipticCurve> AsymmetricDecryptor<MenezesVanstoneScheme<C>> for MenezesVanstoneScheme<C> {
     fn decrypt(
         key: &Self::Key,
         ciphertext: &Self::Input,
@@ -231,10 +324,10 @@ impl AsymmetricDecryptor<MenezesVanstoneScheme> for MenezesVanstoneScheme {
         let a = &ciphertext.point;
         let b1 = ciphertext.first.clone();
         let b2 = ciphertext.second.clone();
-        let prime = &key.curve.prime;
+        let prime = key.curve.prime();
 
         let point = a
-            .multiply(&key.x, &key.curve, &service.number_theory_service)
+            .multiply_secret(&key.x, &key.curve, &service.number_theory_service)
             .context("Failed to calculate Point (c1, c2)")?;
         let (c1, c2) = (point.x, point.y);
         let c1_inverse = service
@@ -261,55 +354,28 @@ impl AsymmetricDecryptor<MenezesVanstoneScheme> for MenezesVanstoneScheme {
     }
 }
 
-impl<'a> Signer<MenezesVanstoneScheme> for MenezesVanstoneScheme {
+impl<C: EllipticCurve> Signer<MenezesVanstoneScheme<C>> for MenezesVanstoneScheme<C> {
     type Input = str;
     type Output = Result<MenezesVanstoneSignature>;
-    type Key = MenezesVanstonePrivateKey;
+    type Key = MenezesVanstonePrivateKey<C>;
 
     fn sign(
         key: &Self::Key,
         message: &Self::Input,
         service: &NumberTheoryWithPrngService,
     ) -> Self::Output {
-        let curve = &key.curve;
-        let q = &curve.order_of_subgroup;
-
-        let hashed_message = sha256(message);
-
-        // Schleife, bis r und s jeweils ungleich 0 sind.
-        loop {
-            let k = &service.take_random_number_in_range(&1.into(), &q.decrement());
-            let point = curve
-                .generator
-                .multiply(k, curve, &service.number_theory_service)
-                .context("Failed to calculate Point (c1, c2)")?;
-
-            ensure!(
-                !point.is_infinite,
-                "Calculated point is infinite, but cannot be since k < |H|. With k = {}",
-                k
-            );
-
-            let r = point.x.rem_euclid(q);
-            if r.is_zero() {
-                continue;
-            }
-            let inverse_k = service.number_theory_service.modulo_inverse(k, q).unwrap();
-            let s = (inverse_k * (&hashed_message + &key.x * &r)).rem_euclid(q);
-            if s.is_zero() {
-                continue;
-            }
-            debug!("MV: Signatur mit k = {}, r = {} und s = {}", k, r, s);
-            return Ok(MenezesVanstoneSignature { r, s });
-        }
+        let q = key.curve.order_of_subgroup().clone();
+        Self::sign_with_nonces(key, message, service, || {
+            service.take_random_number_in_range(&1.into(), &q.decrement())
+        })
     }
 }
 
-impl<'a> Verifier<MenezesVanstoneScheme> for MenezesVanstoneScheme {
+impl<C: EllipticCurve> Verifier<MenezesVanstoneScheme<C>> for MenezesVanstoneScheme<C> {
     type Signature = MenezesVanstoneSignature;
     type Message = str;
     type Output = Result<bool>;
-    type Key = MenezesVanstonePublicKey;
+    type Key = MenezesVanstonePublicKey<C>;
 
     fn verify(
         key: &Self::Key,
@@ -320,7 +386,7 @@ impl<'a> Verifier<MenezesVanstoneScheme> for MenezesVanstoneScheme {
         let curve = &key.curve;
         let r = &signature.r;
         let s = &signature.s;
-        let q = &curve.order_of_subgroup;
+        let q = curve.order_of_subgroup();
 
         let hashed_message = sha256(message);
         let w = &service.number_theory_service.modulo_inverse(s, q).unwrap();
@@ -328,7 +394,7 @@ impl<'a> Verifier<MenezesVanstoneScheme> for MenezesVanstoneScheme {
         let u2 = (r * w).rem_euclid(q);
 
         let first_point = curve
-            .generator
+            .generator()
             .multiply(&u1, curve, &service.number_theory_service)
             .context("Failed to calculate first point")?;
         let second_point = key
@@ -354,7 +420,10 @@ impl<'a> Verifier<MenezesVanstoneScheme> for MenezesVanstoneScheme {
 mod tests {
     use rand::Rng;
 
+    use crate::math_core::ecc::named_curves::NamedCurve;
+    use crate::math_core::ecc::weierstrass_elliptic_curve::WeierstrassEllipticCurve;
     use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+    use crate::math_core::random_source::RecordedRandomSource;
 
     use super::*;
 
@@ -517,4 +586,152 @@ mod tests {
                 .unwrap();
         assert!(!is_verified);
     }
+
+    #[test]
+    fn test_general_weierstrass_curve() {
+        // y^2 = x^3 + 2x + 2 (mod 17) mit dem Generator (5, 1) der Ordnung 19.
+        let curve = WeierstrassEllipticCurve::new(
+            2.into(),
+            2.into(),
+            17.into(),
+            19.into(),
+            1.into(),
+            FiniteFieldEllipticCurvePoint::new(5.into(), 1.into()),
+        )
+        .unwrap();
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        let key_pair = MenezesVanstoneScheme::generate_keypair_for_curve(&curve, &service).unwrap();
+
+        let plaintext = MenezesVanstonePlaintext::double(3.into(), 11.into());
+        let ciphertext =
+            MenezesVanstoneScheme::encrypt(&key_pair.public_key, &plaintext, &service).unwrap();
+        let decrypted_plaintext =
+            MenezesVanstoneScheme::decrypt(&key_pair.private_key, &ciphertext, &service).unwrap();
+        assert_eq!(plaintext, decrypted_plaintext);
+
+        let message = "Hello World!";
+        let signature =
+            MenezesVanstoneScheme::sign(&key_pair.private_key, message, &service).unwrap();
+        assert!(
+            MenezesVanstoneScheme::verify(&key_pair.public_key, &signature, message, &service)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_sign_verify_on_named_curves() {
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        for named_curve in NamedCurve::ALL {
+            let key_pair =
+                MenezesVanstoneScheme::generate_keypair_for_curve(&named_curve.curve(), &service)
+                    .unwrap();
+
+            let message = "Hello World!";
+            let signature =
+                MenezesVanstoneScheme::sign(&key_pair.private_key, message, &service).unwrap();
+            assert!(
+                MenezesVanstoneScheme::verify(&key_pair.public_key, &signature, message, &service)
+                    .unwrap(),
+                "{}",
+                named_curve
+            );
+            assert!(!MenezesVanstoneScheme::verify(
+                &key_pair.public_key,
+                &signature,
+                "Hello World?",
+                &service
+            )
+            .unwrap());
+        }
+    }
+
+    #[test]
+    fn test_sign_deterministic_matches_rfc6979() {
+        // Die Signatur entspricht ECDSA, daher gilt der Testvektor aus RFC 6979, Anhang A.2.5.
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        let curve = NamedCurve::P256.curve();
+        let x = BigInt::parse_bytes(
+            b"C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721",
+            16,
+        )
+        .unwrap();
+        let private_key = MenezesVanstonePrivateKey { curve, x };
+
+        let signature =
+            MenezesVanstoneScheme::sign_deterministic(&private_key, "sample", &service).unwrap();
+        assert_eq!(
+            signature.r,
+            BigInt::parse_bytes(
+                b"EFD48B2AACB6A8FD1140DD9CD45E81D69D2C877B56AAF991C34D0EA84EAF3716",
+                16
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            signature.s,
+            BigInt::parse_bytes(
+                b"F7CB1C942D657C41D436C7A1B6E29F65F3E900DBB9AFF4064DC4AB2F843ACDA8",
+                16
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_sign_with_recorded_nonce() {
+        // Wird die Nonce k aus RFC 6979, Anhang A.2.5 eingespielt, entsteht die Signatur des
+        // Testvektors auch beim gewöhnlichen Signieren.
+        let k = BigInt::parse_bytes(
+            b"A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60",
+            16,
+        )
+        .unwrap();
+        let service =
+            NumberTheoryWithPrngService::with_random_source(Fast, RecordedRandomSource::new([k]));
+        let curve = NamedCurve::P256.curve();
+        let x = BigInt::parse_bytes(
+            b"C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721",
+            16,
+        )
+        .unwrap();
+        let private_key = MenezesVanstonePrivateKey { curve, x };
+
+        let signature = MenezesVanstoneScheme::sign(&private_key, "sample", &service).unwrap();
+        let expected = MenezesVanstoneScheme::sign_deterministic(&private_key, "sample", &service);
+        assert_eq!(signature, expected.unwrap());
+    }
+
+    #[test]
+    fn test_sign_deterministic_ignores_seed() {
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        let key_pair = MenezesVanstoneScheme::generate_keypair(5, 64, 40, &service).unwrap();
+        let message = "Hello World!";
+
+        let signatures: Vec<MenezesVanstoneSignature> = [1, 2, 3]
+            .iter()
+            .map(|&seed| {
+                let service = NumberTheoryWithPrngService::new(Fast, seed);
+                MenezesVanstoneScheme::sign_deterministic(&key_pair.private_key, message, &service)
+                    .unwrap()
+            })
+            .collect();
+        assert!(signatures
+            .iter()
+            .all(|signature| signature == &signatures[0]));
+        assert!(MenezesVanstoneScheme::verify(
+            &key_pair.public_key,
+            &signatures[0],
+            message,
+            &service
+        )
+        .unwrap());
+
+        let other = MenezesVanstoneScheme::sign_deterministic(
+            &key_pair.private_key,
+            "Hello World?",
+            &service,
+        )
+        .unwrap();
+        assert_ne!(other.r, signatures[0].r);
+    }
 }
@@ -1,3 +1,9 @@
+pub mod blum_blum_shub;
+pub mod dsa;
 pub mod el_gamal;
+pub mod key_exchange;
 pub mod menezes_vanstone;
+pub mod paillier;
+pub mod rabin;
 pub mod rsa;
+pub mod schnorr;
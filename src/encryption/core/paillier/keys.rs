@@ -0,0 +1,64 @@
+use crate::encryption::asymmetric_encryption_types::{
+    AsymmetricDecryptionKey, AsymmetricEncryptionKey, AsymmetricKey, AsymmetricKeyPair, PrivateKey,
+    PublicKey,
+};
+use crate::encryption::core::paillier::paillier_scheme::PaillierScheme;
+use crate::encryption::encryption_types::Key;
+use bigdecimal::num_bigint::BigInt;
+
+/// Ein öffentlicher Schlüssel für das Paillier-Kryptosystem.
+/// Besteht aus dem Modulus `n = p * q` und dem Generator `g`.
+#[derive(Clone, Debug)]
+pub struct PaillierPublicKey {
+    pub n: BigInt,
+    pub g: BigInt,
+}
+
+impl PaillierPublicKey {
+    /// Gibt den Modulus `n^2` zurück, in dem die Chiffretexte liegen.
+    pub fn n_squared(&self) -> BigInt {
+        &self.n * &self.n
+    }
+}
+
+impl Key<PaillierScheme> for PaillierPublicKey {}
+
+impl AsymmetricKey<PaillierScheme> for PaillierPublicKey {}
+
+impl PublicKey<PaillierScheme> for PaillierPublicKey {}
+
+impl AsymmetricEncryptionKey<PaillierScheme> for PaillierPublicKey {}
+
+/// Ein privater Schlüssel für das Paillier-Kryptosystem.
+/// Besteht aus dem Modulus `n`, der Carmichael-Funktion `lambda = kgV(p - 1, q - 1)`
+/// und dem Wert `mu = L(g^lambda mod n^2)^-1 mod n`.
+#[derive(Clone, Debug)]
+pub struct PaillierPrivateKey {
+    pub n: BigInt,
+    pub lambda: BigInt,
+    pub mu: BigInt,
+}
+
+impl Key<PaillierScheme> for PaillierPrivateKey {}
+
+impl AsymmetricKey<PaillierScheme> for PaillierPrivateKey {}
+
+impl PrivateKey<PaillierScheme> for PaillierPrivateKey {}
+
+impl AsymmetricDecryptionKey<PaillierScheme> for PaillierPrivateKey {}
+
+#[derive(Clone, Debug)]
+pub struct PaillierKeyPair {
+    pub public_key: PaillierPublicKey,
+    pub private_key: PaillierPrivateKey,
+}
+
+impl AsymmetricKeyPair<PaillierPublicKey, PaillierPrivateKey, PaillierScheme> for PaillierKeyPair {
+    fn public(&self) -> PaillierPublicKey {
+        self.public_key.clone()
+    }
+
+    fn private(&self) -> PaillierPrivateKey {
+        self.private_key.clone()
+    }
+}
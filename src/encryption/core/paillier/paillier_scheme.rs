@@ -0,0 +1,360 @@
+use crate::encryption::asymmetric_encryption_types::{
+    AsymmetricDecryptor, AsymmetricEncryptionScheme, AsymmetricEncryptor, KeyGenWithPrimeConfig,
+    KeyGenerator,
+};
+use crate::encryption::core::paillier::keys::{
+    PaillierKeyPair, PaillierPrivateKey, PaillierPublicKey,
+};
+use crate::encryption::encryption_types::{Decryptor, EncryptionScheme, Encryptor};
+use crate::math_core::number_theory::number_theory_service::{
+    NumberTheoryService, NumberTheoryServiceTrait,
+};
+use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+use crate::math_core::prime_generator::PrimeGenerator;
+use crate::math_core::random_source::RandomSource;
+use crate::math_core::traits::increment::Increment;
+use crate::shared::errors::PaillierError;
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::num_traits::Euclid;
+use bigdecimal::One;
+use log::{debug, trace};
+use num::Integer;
+
+/// Die minimale Bit-Breite des Modulus. Darunter gibt es zu wenige Primzahlen, um zwei
+/// verschiedene Faktoren zu finden, und die Suche würde nicht enden.
+pub const MIN_MODULUS_WIDTH: u32 = 32;
+
+/// Das Paillier-Kryptosystem. Es ist additiv homomorph: Das Produkt zweier Chiffretexte ist
+/// eine Verschlüsselung der Summe der Klartexte. Klartexte sind Elemente von `Z_n`,
+/// Chiffretexte Elemente von `Z_(n^2)`.
+pub struct PaillierScheme;
+
+impl EncryptionScheme for PaillierScheme {}
+
+impl AsymmetricEncryptionScheme for PaillierScheme {}
+
+/// Die Konfiguration für die Schlüsselgenerierung für das Paillier-Kryptosystem.
+///
+/// # Felder
+/// * `key_size` - Die Breite des Modulus `n` in Bits.
+/// * `miller_rabin_iterations` - Die Anzahl der Iterationen für den Miller-Rabin-Test bei der Generierung von Primzahlen.
+/// * `random_seed` - Der Seed für die gleichverteilte Zufallszahlerzeugung.
+/// * `number_theory_service` - Der Service für die Zahlentheorie.
+#[derive(Clone, Debug)]
+pub struct PaillierKeyGenConfig {
+    pub key_size: u32,
+    pub miller_rabin_iterations: u32,
+    pub random_seed: u32,
+    pub number_theory_service: NumberTheoryService,
+}
+
+impl KeyGenWithPrimeConfig for PaillierKeyGenConfig {
+    fn characteristic(&self) -> u32 {
+        self.key_size
+    }
+
+    fn miller_rabin_iterations(&self) -> u32 {
+        self.miller_rabin_iterations
+    }
+
+    fn random_seed(&self) -> u32 {
+        self.random_seed
+    }
+
+    fn number_theory_service(&self) -> NumberTheoryService {
+        self.number_theory_service
+    }
+}
+
+impl KeyGenerator<PaillierPublicKey, PaillierPrivateKey, PaillierScheme> for PaillierScheme {
+    type KeyPair = PaillierKeyPair;
+
+    /// Generiert ein Schlüsselpaar für das Paillier-Kryptosystem.
+    /// Es wird die vereinfachte Variante mit `g = n + 1` verwendet, wodurch sich
+    /// `mu = lambda^-1 mod n` ergibt.
+    ///
+    /// # Argumente
+    /// * `config` - Die Konfiguration für den Schlüsselgenerierungsvorgang.
+    /// * `random` - Die Quelle der Zufallszahlen.
+    ///
+    /// # Rückgabe
+    /// Das Schlüsselpaar.
+    ///
+    /// # Fehler
+    /// * `PaillierError::InvalidModulusWidthError` - Wenn der Modulus schmaler als
+    ///   `MIN_MODULUS_WIDTH` ist.
+    fn generate_keypair_with_source(
+        config: &impl KeyGenWithPrimeConfig,
+        random: &dyn RandomSource,
+    ) -> Result<Self::KeyPair> {
+        debug!(
+            "Generieren eines neuen Paillier-Schlüsselpaares mit Konfiguration: {:?}",
+            config
+        );
+        ensure!(
+            config.characteristic() >= MIN_MODULUS_WIDTH,
+            PaillierError::InvalidModulusWidthError(config.characteristic(), MIN_MODULUS_WIDTH)
+        );
+        let service = config.number_theory_service();
+        let prime_generator = PrimeGenerator::new(config.number_theory_service());
+
+        let (n, lambda, mu) = loop {
+            let primes = prime_generator.get_distinct_primes(
+                config.characteristic(),
+                2,
+                config.miller_rabin_iterations(),
+                random,
+            );
+            let (p, q) = (primes[0].clone(), primes[1].clone());
+            let n = &p * &q;
+            let lambda = p.decrement().lcm(&q.decrement());
+
+            // Bei gleicher Bit-Breite gilt p < q < 2p und damit immer ggT(n, lambda) = 1. Bei
+            // ungerader Breite des Modulus ist p ein Bit breiter, dann kann q die Zahl p - 1
+            // teilen und lambda ist nicht invertierbar. Dann werden neue Primzahlen gezogen.
+            match service.modulo_inverse(&lambda, &n) {
+                Ok(mu) => break (n, lambda, mu),
+                Err(_) => trace!("lambda ist nicht invertierbar modulo n {}", n),
+            }
+        };
+        debug!("n ist {}", n);
+
+        Ok(PaillierKeyPair {
+            public_key: PaillierPublicKey {
+                n: n.clone(),
+                g: n.increment(),
+            },
+            private_key: PaillierPrivateKey { n, lambda, mu },
+        })
+    }
+}
+
+impl Encryptor<PaillierScheme> for PaillierScheme {
+    type Input = BigInt;
+    type Output = BigInt;
+    type Key = PaillierPublicKey;
+}
+
+impl AsymmetricEncryptor<PaillierScheme> for PaillierScheme {
+    /// Verschlüsselt eine Nachricht mit dem öffentlichen Schlüssel: `c = g^m * r^n mod n^2`
+    /// mit einer zufälligen Zahl `r`, die teilerfremd zu `n` ist.
+    ///
+    /// # Argumente
+    /// * `key` - Der öffentliche Schlüssel.
+    /// * `plaintext` - Die zu verschlüsselnde Nachricht. Sie wird modulo `n` interpretiert.
+    /// * `service` - Der Service für die Zahlentheorie und die Zufallszahlen.
+    ///
+    /// # Rückgabe
+    /// Der Chiffretext.
+    fn encrypt(
+        key: &Self::Key,
+        plaintext: &Self::Input,
+        service: &NumberTheoryWithPrngService,
+    ) -> Self::Output {
+        let n = &key.n;
+        let n_squared = key.n_squared();
+        let plaintext = plaintext.rem_euclid(n);
+
+        let mut r = service.take_random_number_in_range(&BigInt::one(), &n.decrement());
+        while !service
+            .number_theory_service
+            .extended_euclid(&r, n)
+            .ggt
+            .is_one()
+        {
+            trace!("Zufallszahl r {} ist nicht teilerfremd zu n", r);
+            r = service.take_random_number_in_range(&BigInt::one(), &n.decrement());
+        }
+
+        let g_to_m = service
+            .number_theory_service
+            .fast_exponentiation(&key.g, &plaintext, &n_squared);
+        let r_to_n = service
+            .number_theory_service
+            .fast_exponentiation(&r, n, &n_squared);
+        (g_to_m * r_to_n) % n_squared
+    }
+}
+
+impl Decryptor<PaillierScheme> for PaillierScheme {
+    type Input = BigInt;
+    type Output = BigInt;
+    type Key = PaillierPrivateKey;
+}
+
+impl AsymmetricDecryptor<PaillierScheme> for PaillierScheme {
+    /// Entschlüsselt einen Chiffretext mit dem privaten Schlüssel:
+    /// `m = L(c^lambda mod n^2) * mu mod n` mit `L(x) = (x - 1) / n`.
+    ///
+    /// # Argumente
+    /// * `key` - Der private Schlüssel.
+    /// * `ciphertext` - Der zu entschlüsselnde Chiffretext.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// Die entschlüsselte Nachricht.
+    fn decrypt(
+        key: &Self::Key,
+        ciphertext: &Self::Input,
+        service: &NumberTheoryWithPrngService,
+    ) -> Self::Output {
+        let n = &key.n;
+        let u =
+            service
+                .number_theory_service
+                .fast_exponentiation(ciphertext, &key.lambda, &(n * n));
+        let l = u.decrement() / n;
+        (l * &key.mu) % n
+    }
+}
+
+impl PaillierScheme {
+    /// Addiert zwei verschlüsselte Zahlen, ohne sie zu entschlüsseln.
+    /// Das Ergebnis ist eine Verschlüsselung von `m_1 + m_2 mod n`.
+    ///
+    /// # Argumente
+    /// * `key` - Der öffentliche Schlüssel, mit dem beide Chiffretexte erzeugt wurden.
+    /// * `ciphertext_one` - Der erste Chiffretext.
+    /// * `ciphertext_two` - Der zweite Chiffretext.
+    ///
+    /// # Rückgabe
+    /// Der Chiffretext der Summe.
+    pub fn add(
+        key: &PaillierPublicKey,
+        ciphertext_one: &BigInt,
+        ciphertext_two: &BigInt,
+    ) -> BigInt {
+        (ciphertext_one * ciphertext_two) % key.n_squared()
+    }
+
+    /// Multipliziert eine verschlüsselte Zahl mit einem unverschlüsselten Skalar, ohne sie zu
+    /// entschlüsseln. Das Ergebnis ist eine Verschlüsselung von `k * m mod n`.
+    ///
+    /// # Argumente
+    /// * `key` - Der öffentliche Schlüssel, mit dem der Chiffretext erzeugt wurde.
+    /// * `ciphertext` - Der Chiffretext.
+    /// * `scalar` - Der Skalar `k`. Er wird modulo `n` interpretiert.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// Der Chiffretext des Produkts.
+    pub fn scalar_multiply(
+        key: &PaillierPublicKey,
+        ciphertext: &BigInt,
+        scalar: &BigInt,
+        service: &NumberTheoryService,
+    ) -> BigInt {
+        service.fast_exponentiation(ciphertext, &scalar.rem_euclid(&key.n), &key.n_squared())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
+        Fast, Slow,
+    };
+
+    fn run_test_for_all_services(test: impl Fn(NumberTheoryWithPrngService)) {
+        test(NumberTheoryWithPrngService::new(Slow, 13)); // Langsame, eigene Implementierung
+        test(NumberTheoryWithPrngService::new(Fast, 13)); // Schnelle, externe Implementierung
+    }
+
+    fn generate_key_pair(key_size: u32, random_seed: u32) -> PaillierKeyPair {
+        let config = PaillierKeyGenConfig {
+            key_size,
+            miller_rabin_iterations: 30,
+            random_seed,
+            number_theory_service: NumberTheoryService::new(Fast),
+        };
+        PaillierScheme::generate_keypair(&config).unwrap()
+    }
+
+    #[test]
+    fn test_encryption_decryption_happy_flow() {
+        run_test_for_all_services(|service| {
+            let key_pair = generate_key_pair(512, 11);
+            let plaintext = BigInt::from(1234567890);
+
+            let ciphertext = PaillierScheme::encrypt(&key_pair.public_key, &plaintext, &service);
+            assert!(ciphertext < key_pair.public_key.n_squared());
+            let decrypted = PaillierScheme::decrypt(&key_pair.private_key, &ciphertext, &service);
+            assert_eq!(decrypted, plaintext);
+        });
+    }
+
+    #[test]
+    fn test_key_generation_checks_modulus_width() {
+        let config = |key_size| PaillierKeyGenConfig {
+            key_size,
+            miller_rabin_iterations: 30,
+            random_seed: 13,
+            number_theory_service: NumberTheoryService::new(Fast),
+        };
+        assert!(PaillierScheme::generate_keypair(&config(MIN_MODULUS_WIDTH - 1)).is_err());
+        assert!(PaillierScheme::generate_keypair(&config(2)).is_err());
+
+        // Bei ungerader Breite sind die Primfaktoren verschieden breit.
+        for random_seed in 0..20 {
+            let key_pair = generate_key_pair(MIN_MODULUS_WIDTH + 1, random_seed);
+            let plaintext = BigInt::from(12345);
+            let service = NumberTheoryWithPrngService::new(Fast, random_seed);
+            let ciphertext = PaillierScheme::encrypt(&key_pair.public_key, &plaintext, &service);
+            let decrypted = PaillierScheme::decrypt(&key_pair.private_key, &ciphertext, &service);
+            assert_eq!(decrypted, plaintext);
+        }
+    }
+
+    #[test]
+    fn test_encryption_is_randomized() {
+        let key_pair = generate_key_pair(256, 12);
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        let plaintext = BigInt::from(42);
+
+        let first = PaillierScheme::encrypt(&key_pair.public_key, &plaintext, &service);
+        let second = PaillierScheme::encrypt(&key_pair.public_key, &plaintext, &service);
+        assert_ne!(first, second);
+        assert_eq!(
+            PaillierScheme::decrypt(&key_pair.private_key, &first, &service),
+            PaillierScheme::decrypt(&key_pair.private_key, &second, &service)
+        );
+    }
+
+    #[test]
+    fn test_homomorphic_addition() {
+        run_test_for_all_services(|service| {
+            let key_pair = generate_key_pair(256, 13);
+            let summand_one = BigInt::from(700);
+            let summand_two = BigInt::from(-20);
+
+            let ciphertext_one =
+                PaillierScheme::encrypt(&key_pair.public_key, &summand_one, &service);
+            let ciphertext_two =
+                PaillierScheme::encrypt(&key_pair.public_key, &summand_two, &service);
+            let sum = PaillierScheme::add(&key_pair.public_key, &ciphertext_one, &ciphertext_two);
+
+            let decrypted = PaillierScheme::decrypt(&key_pair.private_key, &sum, &service);
+            assert_eq!(decrypted, BigInt::from(680));
+        });
+    }
+
+    #[test]
+    fn test_homomorphic_scalar_multiplication() {
+        run_test_for_all_services(|service| {
+            let key_pair = generate_key_pair(256, 14);
+            let plaintext = BigInt::from(12345);
+
+            let ciphertext = PaillierScheme::encrypt(&key_pair.public_key, &plaintext, &service);
+            let product = PaillierScheme::scalar_multiply(
+                &key_pair.public_key,
+                &ciphertext,
+                &BigInt::from(1000),
+                &service.number_theory_service,
+            );
+
+            let decrypted = PaillierScheme::decrypt(&key_pair.private_key, &product, &service);
+            assert_eq!(decrypted, BigInt::from(12345000));
+        });
+    }
+}
@@ -0,0 +1,59 @@
+use crate::encryption::asymmetric_encryption_types::{
+    AsymmetricDecryptionKey, AsymmetricEncryptionKey, AsymmetricKey, AsymmetricKeyPair, PrivateKey,
+    PublicKey, SignatureKey, VerificationKey,
+};
+use crate::encryption::core::rabin::rabin_scheme::RabinScheme;
+use crate::encryption::encryption_types::Key;
+use bigdecimal::num_bigint::BigInt;
+
+/// Ein öffentlicher Schlüssel für das Rabin-Kryptosystem.
+/// Besteht nur aus dem Modulus `n = p * q`.
+#[derive(Clone, Debug)]
+pub struct RabinPublicKey {
+    pub n: BigInt,
+}
+
+impl Key<RabinScheme> for RabinPublicKey {}
+
+impl AsymmetricKey<RabinScheme> for RabinPublicKey {}
+
+impl PublicKey<RabinScheme> for RabinPublicKey {}
+
+impl AsymmetricEncryptionKey<RabinScheme> for RabinPublicKey {}
+
+impl VerificationKey<RabinScheme> for RabinPublicKey {}
+
+/// Ein privater Schlüssel für das Rabin-Kryptosystem.
+/// Besteht aus den beiden Blum-Primzahlen `p` und `q` (`p ≡ q ≡ 3 mod 4`) und dem Modulus `n`.
+#[derive(Clone, Debug)]
+pub struct RabinPrivateKey {
+    pub p: BigInt,
+    pub q: BigInt,
+    pub n: BigInt,
+}
+
+impl Key<RabinScheme> for RabinPrivateKey {}
+
+impl AsymmetricKey<RabinScheme> for RabinPrivateKey {}
+
+impl PrivateKey<RabinScheme> for RabinPrivateKey {}
+
+impl AsymmetricDecryptionKey<RabinScheme> for RabinPrivateKey {}
+
+impl SignatureKey<RabinScheme> for RabinPrivateKey {}
+
+#[derive(Clone, Debug)]
+pub struct RabinKeyPair {
+    pub public_key: RabinPublicKey,
+    pub private_key: RabinPrivateKey,
+}
+
+impl AsymmetricKeyPair<RabinPublicKey, RabinPrivateKey, RabinScheme> for RabinKeyPair {
+    fn public(&self) -> RabinPublicKey {
+        self.public_key.clone()
+    }
+
+    fn private(&self) -> RabinPrivateKey {
+        self.private_key.clone()
+    }
+}
@@ -0,0 +1,3 @@
+pub mod keys;
+pub mod rabin_scheme;
+pub mod rabin_signature_scheme;
@@ -0,0 +1,392 @@
+use crate::encryption::asymmetric_encryption_types::{
+    AsymmetricDecryptor, AsymmetricEncryptionScheme, AsymmetricEncryptor, KeyGenWithPrimeConfig,
+    KeyGenerator,
+};
+use crate::encryption::core::rabin::keys::{RabinKeyPair, RabinPrivateKey, RabinPublicKey};
+use crate::encryption::encryption_types::{Decryptor, EncryptionScheme, Encryptor};
+use crate::math_core::number_theory::number_theory_service::{
+    NumberTheoryService, NumberTheoryServiceTrait,
+};
+use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+use crate::math_core::prime_generator::PrimeGenerator;
+use crate::math_core::random_source::RandomSource;
+use crate::math_core::traits::increment::Increment;
+use crate::shared::errors::RabinError;
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::{BigInt, Sign};
+use bigdecimal::num_traits::Euclid;
+use bigdecimal::{One, Zero};
+use log::{debug, trace};
+
+/// Die Anzahl der Bits, die zur Redundanz an die Nachricht angehängt werden. Es werden die
+/// niederwertigsten Bits der Nachricht wiederholt.
+pub const REDUNDANCY_BITS: u32 = 64;
+
+/// Die minimale Bit-Breite des Modulus. Darunter gibt es zu wenige Blum-Primzahlen, um zwei
+/// verschiedene Faktoren zu finden, und die Suche würde nicht enden.
+pub const MIN_MODULUS_WIDTH: u32 = 32;
+
+/// Das Rabin-Kryptosystem. Verschlüsselt wird durch Quadrieren modulo `n = p * q`, entschlüsselt
+/// durch das Ziehen der Quadratwurzeln modulo `p` und `q` und das Zusammensetzen mit dem
+/// Chinesischen Restsatz. Von den vier Wurzeln wird die mit gültiger Redundanz ausgewählt.
+pub struct RabinScheme;
+
+impl EncryptionScheme for RabinScheme {}
+
+impl AsymmetricEncryptionScheme for RabinScheme {}
+
+/// Die Konfiguration für die Schlüsselgenerierung für das Rabin-Kryptosystem.
+///
+/// # Felder
+/// * `key_size` - Die Breite des Modulus `n` in Bits.
+/// * `miller_rabin_iterations` - Die Anzahl der Iterationen für den Miller-Rabin-Test bei der Generierung von Primzahlen.
+/// * `random_seed` - Der Seed für die gleichverteilte Zufallszahlerzeugung.
+/// * `number_theory_service` - Der Service für die Zahlentheorie.
+#[derive(Clone, Debug)]
+pub struct RabinKeyGenConfig {
+    pub key_size: u32,
+    pub miller_rabin_iterations: u32,
+    pub random_seed: u32,
+    pub number_theory_service: NumberTheoryService,
+}
+
+impl KeyGenWithPrimeConfig for RabinKeyGenConfig {
+    fn characteristic(&self) -> u32 {
+        self.key_size
+    }
+
+    fn miller_rabin_iterations(&self) -> u32 {
+        self.miller_rabin_iterations
+    }
+
+    fn random_seed(&self) -> u32 {
+        self.random_seed
+    }
+
+    fn number_theory_service(&self) -> NumberTheoryService {
+        self.number_theory_service
+    }
+}
+
+impl KeyGenerator<RabinPublicKey, RabinPrivateKey, RabinScheme> for RabinScheme {
+    type KeyPair = RabinKeyPair;
+
+    /// Generiert ein Schlüsselpaar für das Rabin-Kryptosystem aus zwei verschiedenen
+    /// Blum-Primzahlen.
+    ///
+    /// # Argumente
+    /// * `config` - Die Konfiguration für den Schlüsselgenerierungsvorgang.
+    /// * `random` - Die Quelle der Zufallszahlen.
+    ///
+    /// # Rückgabe
+    /// Das Schlüsselpaar.
+    ///
+    /// # Fehler
+    /// * `RabinError::InvalidModulusWidthError` - Wenn der Modulus schmaler als
+    ///   `MIN_MODULUS_WIDTH` ist.
+    fn generate_keypair_with_source(
+        config: &impl KeyGenWithPrimeConfig,
+        random: &dyn RandomSource,
+    ) -> Result<Self::KeyPair> {
+        debug!(
+            "Generieren eines neuen Rabin-Schlüsselpaares mit Konfiguration: {:?}",
+            config
+        );
+        ensure!(
+            config.characteristic() >= MIN_MODULUS_WIDTH,
+            RabinError::InvalidModulusWidthError(config.characteristic(), MIN_MODULUS_WIDTH)
+        );
+        let prime_generator = PrimeGenerator::new(config.number_theory_service());
+
+        let size = config.characteristic();
+        let p = prime_generator.generate_blum_prime(
+            size - size / 2,
+            config.miller_rabin_iterations(),
+            random,
+        );
+        let mut q =
+            prime_generator.generate_blum_prime(size / 2, config.miller_rabin_iterations(), random);
+        while p == q {
+            trace!("p und q sind gleich. Generiere neues q");
+            q = prime_generator.generate_blum_prime(
+                size / 2,
+                config.miller_rabin_iterations(),
+                random,
+            );
+        }
+
+        let n = &p * &q;
+        debug!("n ist {}", n);
+
+        Ok(RabinKeyPair {
+            public_key: RabinPublicKey { n: n.clone() },
+            private_key: RabinPrivateKey { p, q, n },
+        })
+    }
+}
+
+impl Encryptor<RabinScheme> for RabinScheme {
+    type Input = BigInt;
+    type Output = Result<BigInt>;
+    type Key = RabinPublicKey;
+}
+
+impl AsymmetricEncryptor<RabinScheme> for RabinScheme {
+    /// Verschlüsselt eine Nachricht mit dem öffentlichen Schlüssel: `c = m'^2 mod n`,
+    /// wobei `m'` die Nachricht mit angehängter Redundanz ist.
+    ///
+    /// # Argumente
+    /// * `key` - Der öffentliche Schlüssel.
+    /// * `plaintext` - Die zu verschlüsselnde, nicht negative Nachricht.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// Der Chiffretext.
+    ///
+    /// # Fehler
+    /// * `RabinError::MessageTooLargeError` - Falls die Nachricht negativ ist oder mit Redundanz
+    ///   nicht kleiner als `n` ist.
+    fn encrypt(
+        key: &Self::Key,
+        plaintext: &Self::Input,
+        service: &NumberTheoryWithPrngService,
+    ) -> Self::Output {
+        let padded = Self::add_redundancy(plaintext);
+        ensure!(
+            plaintext.sign() != Sign::Minus && padded < key.n,
+            RabinError::MessageTooLargeError(plaintext.to_string(), key.n.to_string())
+        );
+        Ok(service
+            .number_theory_service
+            .fast_exponentiation(&padded, &2.into(), &key.n))
+    }
+}
+
+impl Decryptor<RabinScheme> for RabinScheme {
+    type Input = BigInt;
+    type Output = Result<BigInt>;
+    type Key = RabinPrivateKey;
+}
+
+impl AsymmetricDecryptor<RabinScheme> for RabinScheme {
+    /// Entschlüsselt einen Chiffretext mit dem privaten Schlüssel. Von den vier Quadratwurzeln
+    /// wird diejenige gewählt, deren Redundanz gültig ist.
+    ///
+    /// # Argumente
+    /// * `key` - Der private Schlüssel.
+    /// * `ciphertext` - Der zu entschlüsselnde Chiffretext.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// Die entschlüsselte Nachricht.
+    ///
+    /// # Fehler
+    /// * `RabinError::NotQuadraticResidueError` - Falls der Chiffretext kein Quadrat ist.
+    /// * `RabinError::AmbiguousPlaintextError` - Falls nicht genau eine Wurzel gültig ist.
+    fn decrypt(
+        key: &Self::Key,
+        ciphertext: &Self::Input,
+        service: &NumberTheoryWithPrngService,
+    ) -> Self::Output {
+        let mut roots = Self::square_roots(key, ciphertext, &service.number_theory_service)?;
+        // Für Chiffretexte, die nicht teilerfremd zu n sind, fallen Wurzeln zusammen.
+        roots.sort();
+        roots.dedup();
+
+        let valid_roots: Vec<&BigInt> = roots
+            .iter()
+            .filter(|root| Self::has_valid_redundancy(root))
+            .collect();
+        ensure!(
+            valid_roots.len() == 1,
+            RabinError::AmbiguousPlaintextError(valid_roots.len())
+        );
+        Ok(valid_roots[0] >> REDUNDANCY_BITS)
+    }
+}
+
+impl RabinScheme {
+    /// Berechnet die vier Quadratwurzeln eines Wertes modulo `n`. Modulo der Blum-Primzahlen
+    /// ergeben sich die Wurzeln als `a^((p + 1) / 4) mod p`, die anschließend mit dem
+    /// Chinesischen Restsatz kombiniert werden.
+    ///
+    /// # Argumente
+    /// * `key` - Der private Schlüssel.
+    /// * `value` - Der Wert, dessen Wurzeln berechnet werden.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// * `Vec<BigInt>` - Die vier Wurzeln `r`, `n - r`, `s` und `n - s`.
+    ///
+    /// # Fehler
+    /// * `RabinError::NotQuadraticResidueError` - Falls `value` kein quadratischer Rest ist.
+    pub fn square_roots(
+        key: &RabinPrivateKey,
+        value: &BigInt,
+        service: &NumberTheoryService,
+    ) -> Result<Vec<BigInt>> {
+        let (p, q, n) = (&key.p, &key.q, &key.n);
+        let value = value.rem_euclid(n);
+
+        let root_p = Self::blum_prime_square_root(&value, p, service);
+        let root_q = Self::blum_prime_square_root(&value, q, service);
+        ensure!(
+            (&root_p * &root_p - &value).rem_euclid(p).is_zero()
+                && (&root_q * &root_q - &value).rem_euclid(q).is_zero(),
+            RabinError::NotQuadraticResidueError(value.to_string())
+        );
+
+        // x * p + y * q = 1
+        let euclid = service.extended_euclid(p, q);
+        let from_q = &euclid.x * p * &root_q;
+        let from_p = &euclid.y * q * &root_p;
+        let r = (&from_q + &from_p).rem_euclid(n);
+        let s = (&from_q - &from_p).rem_euclid(n);
+
+        Ok(vec![
+            r.clone(),
+            (n - &r).rem_euclid(n),
+            s.clone(),
+            (n - &s).rem_euclid(n),
+        ])
+    }
+
+    /// Berechnet eine Quadratwurzel modulo einer Blum-Primzahl: `a^((p + 1) / 4) mod p`.
+    fn blum_prime_square_root(
+        value: &BigInt,
+        prime: &BigInt,
+        service: &NumberTheoryService,
+    ) -> BigInt {
+        let exponent = prime.increment() / 4;
+        service.fast_exponentiation(&value.rem_euclid(prime), &exponent, prime)
+    }
+
+    /// Hängt die niederwertigsten `REDUNDANCY_BITS` Bits der Nachricht an die Nachricht an.
+    fn add_redundancy(message: &BigInt) -> BigInt {
+        (message << REDUNDANCY_BITS) | Self::low_bits(message)
+    }
+
+    /// Prüft, ob die niederwertigsten `REDUNDANCY_BITS` Bits des Wertes mit den
+    /// niederwertigsten Bits der eigentlichen Nachricht übereinstimmen.
+    fn has_valid_redundancy(value: &BigInt) -> bool {
+        Self::low_bits(value) == Self::low_bits(&(value >> REDUNDANCY_BITS))
+    }
+
+    fn low_bits(value: &BigInt) -> BigInt {
+        let mask = (BigInt::one() << REDUNDANCY_BITS).decrement();
+        value & mask
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
+        Fast, Slow,
+    };
+
+    fn run_test_for_all_services(test: impl Fn(NumberTheoryWithPrngService)) {
+        test(NumberTheoryWithPrngService::new(Slow, 13)); // Langsame, eigene Implementierung
+        test(NumberTheoryWithPrngService::new(Fast, 13)); // Schnelle, externe Implementierung
+    }
+
+    fn generate_key_pair(key_size: u32, random_seed: u32) -> RabinKeyPair {
+        let config = RabinKeyGenConfig {
+            key_size,
+            miller_rabin_iterations: 30,
+            random_seed,
+            number_theory_service: NumberTheoryService::new(Fast),
+        };
+        RabinScheme::generate_keypair(&config).unwrap()
+    }
+
+    #[test]
+    fn test_key_generation_uses_blum_primes() {
+        let key_pair = generate_key_pair(256, 11);
+        let private_key = &key_pair.private_key;
+
+        assert_ne!(private_key.p, private_key.q);
+        assert_eq!(&private_key.p % 4, BigInt::from(3));
+        assert_eq!(&private_key.q % 4, BigInt::from(3));
+        assert_eq!(&private_key.p * &private_key.q, key_pair.public_key.n);
+        assert!((255..=256).contains(&key_pair.public_key.n.bits()));
+    }
+
+    #[test]
+    fn test_key_generation_checks_modulus_width() {
+        for key_size in [0, 3, 4, MIN_MODULUS_WIDTH - 1] {
+            let config = RabinKeyGenConfig {
+                key_size,
+                miller_rabin_iterations: 30,
+                random_seed: 13,
+                number_theory_service: NumberTheoryService::new(Fast),
+            };
+            assert!(RabinScheme::generate_keypair(&config).is_err());
+        }
+
+        let key_pair = generate_key_pair(MIN_MODULUS_WIDTH, 13);
+        assert_ne!(key_pair.private_key.p, key_pair.private_key.q);
+    }
+
+    #[test]
+    fn test_encryption_decryption_happy_flow() {
+        run_test_for_all_services(|service| {
+            let key_pair = generate_key_pair(256, 12);
+            for plaintext in [0, 1, 42, 1234567890] {
+                let plaintext = BigInt::from(plaintext);
+                let ciphertext =
+                    RabinScheme::encrypt(&key_pair.public_key, &plaintext, &service).unwrap();
+                let decrypted =
+                    RabinScheme::decrypt(&key_pair.private_key, &ciphertext, &service).unwrap();
+                assert_eq!(decrypted, plaintext);
+            }
+        });
+    }
+
+    #[test]
+    fn test_square_roots_yields_four_distinct_roots() {
+        run_test_for_all_services(|service| {
+            let key_pair = generate_key_pair(128, 13);
+            let n = &key_pair.public_key.n;
+            let value = BigInt::from(987654321);
+            let square = (&value * &value) % n;
+
+            let roots = RabinScheme::square_roots(
+                &key_pair.private_key,
+                &square,
+                &service.number_theory_service,
+            )
+            .unwrap();
+            assert!(roots.contains(&value));
+            for (index, root) in roots.iter().enumerate() {
+                assert_eq!((root * root) % n, square);
+                assert!(!roots[index + 1..].contains(root));
+            }
+        });
+    }
+
+    #[test]
+    fn test_encrypt_rejects_too_large_message() {
+        let key_pair = generate_key_pair(128, 14);
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        let too_large = &key_pair.public_key.n >> (REDUNDANCY_BITS - 1);
+
+        assert!(RabinScheme::encrypt(&key_pair.public_key, &too_large, &service).is_err());
+        assert!(RabinScheme::encrypt(&key_pair.public_key, &BigInt::from(-1), &service).is_err());
+    }
+
+    #[test]
+    fn test_decrypt_rejects_invalid_ciphertext() {
+        let key_pair = generate_key_pair(128, 15);
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        let private_key = &key_pair.private_key;
+
+        // Das Quadrat einer Zahl ohne Redundanz hat keine gültige Wurzel.
+        let square = BigInt::from(123456789).pow(2) % &private_key.n;
+        assert!(RabinScheme::decrypt(private_key, &square, &service).is_err());
+
+        // -1 ist modulo einer Blum-Primzahl kein quadratischer Rest.
+        let non_residue = &private_key.n - 1;
+        assert!(RabinScheme::decrypt(private_key, &non_residue, &service).is_err());
+    }
+}
@@ -0,0 +1,190 @@
+use crate::encryption::asymmetric_encryption_types::{Signer, Verifier};
+use crate::encryption::core::rabin::keys::{RabinPrivateKey, RabinPublicKey};
+use crate::encryption::core::rabin::rabin_scheme::RabinScheme;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceTrait;
+use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+use crate::shared::hashing::sha256_bytes;
+use bigdecimal::num_bigint::{BigInt, Sign};
+use log::trace;
+
+/// Rabin-Signaturen. Signiert wird `H(m || counter) mod n`, wobei der Zähler so lange erhöht
+/// wird, bis der Hashwert ein quadratischer Rest modulo `n` ist. Die Signatur ist eine
+/// Quadratwurzel dieses Wertes, im Mittel sind dafür vier Versuche nötig.
+pub struct RabinSignatureScheme;
+
+/// Eine Rabin-Signatur.
+///
+/// # Felder
+/// * `root` - Die Quadratwurzel des Hashwertes modulo `n`.
+/// * `counter` - Der Zähler, mit dem der Hashwert ein quadratischer Rest ist.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RabinSignature {
+    pub root: BigInt,
+    pub counter: u32,
+}
+
+impl Signer<RabinScheme> for RabinSignatureScheme {
+    type Input = str;
+    type Output = RabinSignature;
+    type Key = RabinPrivateKey;
+
+    /// Signiert eine Nachricht. Von den vier Wurzeln wird die kleinste gewählt, damit die
+    /// Signatur deterministisch ist.
+    ///
+    /// # Argumente
+    /// * `key` - Der private Schlüssel.
+    /// * `message` - Die zu signierende Nachricht.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// Die Signatur.
+    fn sign(
+        key: &Self::Key,
+        message: &Self::Input,
+        service: &NumberTheoryWithPrngService,
+    ) -> Self::Output {
+        let mut counter: u32 = 0;
+        loop {
+            let hash = Self::hash_with_counter(message, counter, &key.n);
+            match RabinScheme::square_roots(key, &hash, &service.number_theory_service) {
+                // Die Liste enthält immer vier Wurzeln, das unwrap ist sicher.
+                Ok(roots) => {
+                    let root = roots.into_iter().min().unwrap();
+                    return RabinSignature { root, counter };
+                }
+                Err(_) => trace!("Hash mit Zähler {} ist kein quadratischer Rest", counter),
+            }
+            counter += 1;
+        }
+    }
+}
+
+impl Verifier<RabinScheme> for RabinSignatureScheme {
+    type Signature = RabinSignature;
+    type Message = str;
+    type Output = bool;
+    type Key = RabinPublicKey;
+
+    /// Überprüft eine Signatur: `root^2 mod n` muss dem Hashwert `H(m || counter) mod n`
+    /// entsprechen.
+    ///
+    /// # Argumente
+    /// * `key` - Der öffentliche Schlüssel.
+    /// * `signature` - Die Signatur.
+    /// * `message` - Die signierte Nachricht.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// `true`, wenn die Signatur gültig ist, ansonsten `false`.
+    fn verify(
+        key: &Self::Key,
+        signature: &Self::Signature,
+        message: &Self::Message,
+        service: &NumberTheoryWithPrngService,
+    ) -> Self::Output {
+        let hash = Self::hash_with_counter(message, signature.counter, &key.n);
+        let square =
+            service
+                .number_theory_service
+                .fast_exponentiation(&signature.root, &2.into(), &key.n);
+        square == hash
+    }
+}
+
+impl RabinSignatureScheme {
+    /// Berechnet `SHA256(m || counter) mod n`, wobei der Zähler als 4 Byte Big-Endian angehängt wird.
+    fn hash_with_counter(message: &str, counter: u32, n: &BigInt) -> BigInt {
+        let mut data = message.as_bytes().to_vec();
+        data.extend_from_slice(&counter.to_be_bytes());
+        BigInt::from_bytes_be(Sign::Plus, &sha256_bytes(&data)) % n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::asymmetric_encryption_types::KeyGenerator;
+    use crate::encryption::core::rabin::keys::RabinKeyPair;
+    use crate::encryption::core::rabin::rabin_scheme::RabinKeyGenConfig;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
+        Fast, Slow,
+    };
+
+    fn run_test_for_all_services(test: impl Fn(NumberTheoryWithPrngService)) {
+        test(NumberTheoryWithPrngService::new(Slow, 13)); // Langsame, eigene Implementierung
+        test(NumberTheoryWithPrngService::new(Fast, 13)); // Schnelle, externe Implementierung
+    }
+
+    fn generate_key_pair(key_size: u32, random_seed: u32) -> RabinKeyPair {
+        let config = RabinKeyGenConfig {
+            key_size,
+            miller_rabin_iterations: 30,
+            random_seed,
+            number_theory_service: NumberTheoryService::new(Fast),
+        };
+        RabinScheme::generate_keypair(&config).unwrap()
+    }
+
+    #[test]
+    fn test_sign_verify_happy_flow() {
+        run_test_for_all_services(|service| {
+            let key_pair = generate_key_pair(512, 16);
+            let message = "Das ist eine Nachricht";
+
+            let signature = RabinSignatureScheme::sign(&key_pair.private_key, message, &service);
+            assert!(RabinSignatureScheme::verify(
+                &key_pair.public_key,
+                &signature,
+                message,
+                &service
+            ));
+        });
+    }
+
+    #[test]
+    fn test_signature_is_deterministic() {
+        let key_pair = generate_key_pair(512, 17);
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+
+        let first = RabinSignatureScheme::sign(&key_pair.private_key, "Nachricht", &service);
+        let second = RabinSignatureScheme::sign(&key_pair.private_key, "Nachricht", &service);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_verify_rejects_manipulation() {
+        let key_pair = generate_key_pair(512, 18);
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        let signature = RabinSignatureScheme::sign(&key_pair.private_key, "Nachricht", &service);
+
+        assert!(!RabinSignatureScheme::verify(
+            &key_pair.public_key,
+            &signature,
+            "Nachricht!",
+            &service
+        ));
+
+        let manipulated_root = RabinSignature {
+            root: &signature.root + 1,
+            counter: signature.counter,
+        };
+        assert!(!RabinSignatureScheme::verify(
+            &key_pair.public_key,
+            &manipulated_root,
+            "Nachricht",
+            &service
+        ));
+
+        let manipulated_counter = RabinSignature {
+            root: signature.root.clone(),
+            counter: signature.counter + 1,
+        };
+        assert!(!RabinSignatureScheme::verify(
+            &key_pair.public_key,
+            &manipulated_counter,
+            "Nachricht",
+            &service
+        ));
+    }
+}
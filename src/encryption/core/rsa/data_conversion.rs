@@ -0,0 +1,99 @@
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::{BigInt, Sign};
+use bigdecimal::{One, Signed, Zero};
+
+use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+use crate::shared::errors::RsaError;
+
+/// Wandelt eine nicht-negative Ganzzahl in eine Bytefolge der festen Länge `length` um
+/// (I2OSP aus RFC 8017, Abschnitt 4.1). Die Bytefolge wird mit führenden Nullen aufgefüllt.
+///
+/// # Argumente
+/// * `value` - Die umzuwandelnde Zahl.
+/// * `length` - Die gewünschte Länge der Bytefolge.
+///
+/// # Rückgabe
+/// * `Vec<u8>` - Die Big-Endian-Darstellung der Zahl.
+///
+/// # Fehler
+/// * `RsaError::MessageTooLongError` - Falls die Zahl nicht in `length` Bytes passt.
+pub fn integer_to_octets(value: &BigInt, length: usize) -> Result<Vec<u8>> {
+    ensure!(!value.is_negative(), "Negative Zahlen sind nicht erlaubt.");
+    let (_, bytes) = value.to_bytes_be();
+    // Die Null wird von to_bytes_be als [0] dargestellt.
+    let bytes: Vec<u8> = bytes.into_iter().skip_while(|byte| *byte == 0).collect();
+    ensure!(
+        bytes.len() <= length,
+        RsaError::MessageTooLongError(bytes.len(), length)
+    );
+
+    let mut result = vec![0u8; length - bytes.len()];
+    result.extend_from_slice(&bytes);
+    Ok(result)
+}
+
+/// Wandelt eine Bytefolge in eine nicht-negative Ganzzahl um (OS2IP aus RFC 8017, Abschnitt 4.2).
+///
+/// # Argumente
+/// * `octets` - Die Big-Endian-Bytefolge.
+///
+/// # Rückgabe
+/// * `BigInt` - Die dargestellte Zahl.
+pub fn octets_to_integer(octets: &[u8]) -> BigInt {
+    BigInt::from_bytes_be(Sign::Plus, octets)
+}
+
+/// Gibt die Länge des Modulus in Bytes zurück (`k` aus RFC 8017).
+pub fn octet_length(modulus: &BigInt) -> usize {
+    modulus.bits().div_ceil(8) as usize
+}
+
+/// Verknüpft zwei gleich lange Bytefolgen byteweise mit XOR.
+pub fn xor_octets(left: &[u8], right: &[u8]) -> Vec<u8> {
+    left.iter().zip(right.iter()).map(|(l, r)| l ^ r).collect()
+}
+
+/// Erzeugt eine zufällige Bytefolge der gegebenen Länge, z.B. für Seeds und Salts.
+///
+/// # Argumente
+/// * `length` - Die gewünschte Länge der Bytefolge.
+/// * `service` - Der Service, aus dem die Zufallszahl gezogen wird.
+///
+/// # Rückgabe
+/// * `Vec<u8>` - Die zufällige Bytefolge.
+pub fn random_octets(length: usize, service: &NumberTheoryWithPrngService) -> Vec<u8> {
+    if length == 0 {
+        return vec![];
+    }
+    let upper_bound = (BigInt::one() << (8 * length)) - 1;
+    let random_number = service.take_random_number_in_range(&BigInt::zero(), &upper_bound);
+    // Das unwrap ist sicher, weil die Zufallszahl nach Konstruktion in `length` Bytes passt.
+    integer_to_octets(&random_number, length).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integer_to_octets_pads_with_zeros() {
+        let octets = integer_to_octets(&BigInt::from(0x0102), 4).unwrap();
+        assert_eq!(octets, vec![0, 0, 1, 2]);
+        assert_eq!(octets_to_integer(&octets), BigInt::from(0x0102));
+
+        assert_eq!(integer_to_octets(&BigInt::from(0), 2).unwrap(), vec![0, 0]);
+        assert!(integer_to_octets(&BigInt::from(0x010203), 2).is_err());
+    }
+
+    #[test]
+    fn test_octet_length() {
+        assert_eq!(octet_length(&BigInt::from(255)), 1);
+        assert_eq!(octet_length(&BigInt::from(256)), 2);
+        assert_eq!(octet_length(&BigInt::from(2).pow(1024)), 129);
+    }
+
+    #[test]
+    fn test_xor_octets() {
+        assert_eq!(xor_octets(&[0xff, 0x0f], &[0x0f, 0x0f]), vec![0xf0, 0x00]);
+    }
+}
@@ -27,6 +27,43 @@ impl VerificationKey<RsaScheme> for RsaPublicKey {}
 pub struct RsaPrivateKey {
     pub d: BigInt,
     pub n: BigInt,
+    /// Die optionalen Parameter für die Entschlüsselung mittels Chinesischem Restsatz.
+    /// Sind sie nicht gesetzt, wird mit `d` und `n` direkt gerechnet.
+    pub crt: Option<RsaCrtParameters>,
+}
+
+/// Die Parameter eines privaten RSA-Schlüssels für die Berechnung mittels Chinesischem Restsatz
+/// (Garner-Verfahren), wie sie in RFC 8017 vorgesehen sind.
+///
+/// # Felder
+/// * `p` - Der erste Primfaktor von `n`.
+/// * `q` - Der zweite Primfaktor von `n`.
+/// * `d_p` - Der Exponent `d mod (p - 1)`.
+/// * `d_q` - Der Exponent `d mod (q - 1)`.
+/// * `q_inv` - Das Inverse `q^-1 mod p`.
+/// * `other_primes` - Die weiteren Primfaktoren bei Multi-Prime-RSA, sonst leer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RsaCrtParameters {
+    pub p: BigInt,
+    pub q: BigInt,
+    pub d_p: BigInt,
+    pub d_q: BigInt,
+    pub q_inv: BigInt,
+    pub other_primes: Vec<RsaOtherPrimeInfo>,
+}
+
+/// Ein weiterer Primfaktor `r_i` (mit `i >= 3`) eines Multi-Prime-RSA-Schlüssels
+/// (`OtherPrimeInfo` aus RFC 8017, Abschnitt 3.2).
+///
+/// # Felder
+/// * `r` - Der Primfaktor `r_i`.
+/// * `d` - Der Exponent `d mod (r_i - 1)`.
+/// * `t` - Der Koeffizient `(r_1 * ... * r_(i-1))^-1 mod r_i`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RsaOtherPrimeInfo {
+    pub r: BigInt,
+    pub d: BigInt,
+    pub t: BigInt,
 }
 
 impl Key<RsaScheme> for RsaPrivateKey {}
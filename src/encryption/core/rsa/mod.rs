@@ -1,2 +1,7 @@
+pub mod data_conversion;
+pub mod key_encoding;
 pub mod keys;
+pub mod rsa_oaep_scheme;
+pub mod rsa_pkcs1v15_signature_scheme;
+pub mod rsa_pss_scheme;
 pub mod rsa_scheme;
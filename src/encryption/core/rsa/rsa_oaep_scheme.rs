@@ -0,0 +1,278 @@
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+use log::{debug, trace};
+
+use crate::encryption::asymmetric_encryption_types::{AsymmetricDecryptor, AsymmetricEncryptor};
+use crate::encryption::core::rsa::data_conversion::{
+    integer_to_octets, octet_length, octets_to_integer, random_octets, xor_octets,
+};
+use crate::encryption::core::rsa::keys::{RsaPrivateKey, RsaPublicKey};
+use crate::encryption::core::rsa::rsa_scheme::RsaScheme;
+use crate::encryption::encryption_types::{Decryptor, Encryptor};
+use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+use crate::shared::errors::RsaError;
+use crate::shared::hashing::{mgf1_sha256, sha256_bytes, SHA256_LENGTH};
+
+/// RSA-Verschlüsselung mit OAEP-Padding (RSAES-OAEP aus RFC 8017, Abschnitt 7.1).
+/// Als Hashfunktion wird SHA256 und als Maskengenerierungsfunktion MGF1 mit SHA256 verwendet.
+/// Das Label ist immer leer.
+///
+/// Im Gegensatz zum Lehrbuch-RSA aus `RsaScheme` ist die Verschlüsselung randomisiert und
+/// Manipulationen am Chiffretext werden beim Entschlüsseln erkannt.
+pub struct RsaOaepScheme {}
+
+impl Encryptor<RsaScheme> for RsaOaepScheme {
+    type Input = [u8];
+    type Output = Result<BigInt>;
+    type Key = RsaPublicKey;
+}
+
+impl AsymmetricEncryptor<RsaScheme> for RsaOaepScheme {
+    /// Kodiert die Nachricht mittels OAEP und verschlüsselt sie anschließend mit RSA.
+    ///
+    /// # Argumente
+    /// * `key` - Der öffentliche Schlüssel.
+    /// * `plaintext` - Die Nachricht. Sie darf höchstens `max_message_length` Bytes lang sein.
+    /// * `service` - Der Service für die Zahlentheorie und die Erzeugung des zufälligen Seeds.
+    ///
+    /// # Rückgabe
+    /// Der Chiffretext.
+    ///
+    /// # Fehler
+    /// * `RsaError::ModulusTooShortError` - Falls der Modulus für OAEP zu klein ist.
+    /// * `RsaError::MessageTooLongError` - Falls die Nachricht zu lang ist.
+    fn encrypt(
+        key: &Self::Key,
+        plaintext: &Self::Input,
+        service: &NumberTheoryWithPrngService,
+    ) -> Self::Output {
+        let k = octet_length(&key.n);
+        let seed = random_octets(SHA256_LENGTH, service);
+
+        let encoded_message = Self::encode(plaintext, k, &seed)?;
+        let message_representative = octets_to_integer(&encoded_message);
+        debug!(
+            "OAEP: Verschlüsselung einer Nachricht mit {} Bytes",
+            plaintext.len()
+        );
+        Ok(RsaScheme::encrypt(key, &message_representative, service))
+    }
+}
+
+impl Decryptor<RsaScheme> for RsaOaepScheme {
+    type Input = BigInt;
+    type Output = Result<Vec<u8>>;
+    type Key = RsaPrivateKey;
+}
+
+impl AsymmetricDecryptor<RsaScheme> for RsaOaepScheme {
+    /// Entschlüsselt den Chiffretext mit RSA und entfernt anschließend das OAEP-Padding.
+    ///
+    /// # Argumente
+    /// * `key` - Der private Schlüssel.
+    /// * `ciphertext` - Der Chiffretext.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// Die ursprüngliche Nachricht.
+    ///
+    /// # Fehler
+    /// * `RsaError::DecryptionError` - Falls der Chiffretext ungültig ist.
+    fn decrypt(
+        key: &Self::Key,
+        ciphertext: &Self::Input,
+        service: &NumberTheoryWithPrngService,
+    ) -> Self::Output {
+        let k = octet_length(&key.n);
+        ensure!(
+            ciphertext < &key.n && k >= 2 * SHA256_LENGTH + 2,
+            RsaError::DecryptionError
+        );
+
+        let message_representative = RsaScheme::decrypt(key, ciphertext, service);
+        let encoded_message =
+            integer_to_octets(&message_representative, k).map_err(|_| RsaError::DecryptionError)?;
+        Self::decode(&encoded_message, k)
+    }
+}
+
+impl RsaOaepScheme {
+    /// Gibt die maximale Länge einer Nachricht in Bytes zurück, die mit dem gegebenen Modulus
+    /// verschlüsselt werden kann (`k - 2 * hLen - 2`).
+    ///
+    /// # Argumente
+    /// * `modulus` - Der Modulus `n` des Schlüssels.
+    ///
+    /// # Rückgabe
+    /// Die maximale Nachrichtenlänge oder `None`, falls der Modulus für OAEP zu klein ist.
+    pub fn max_message_length(modulus: &BigInt) -> Option<usize> {
+        octet_length(modulus).checked_sub(2 * SHA256_LENGTH + 2)
+    }
+
+    /// Kodiert die Nachricht nach EME-OAEP (RFC 8017, Abschnitt 7.1.1, Schritt 2).
+    ///
+    /// # Argumente
+    /// * `message` - Die Nachricht.
+    /// * `k` - Die Länge des Modulus in Bytes.
+    /// * `seed` - Der zufällige Seed der Länge `hLen`.
+    ///
+    /// # Rückgabe
+    /// Die kodierte Nachricht `EM = 0x00 || maskedSeed || maskedDB` der Länge `k`.
+    fn encode(message: &[u8], k: usize, seed: &[u8]) -> Result<Vec<u8>> {
+        ensure!(
+            k >= 2 * SHA256_LENGTH + 2,
+            RsaError::ModulusTooShortError(k)
+        );
+        let max_length = k - 2 * SHA256_LENGTH - 2;
+        ensure!(
+            message.len() <= max_length,
+            RsaError::MessageTooLongError(message.len(), max_length)
+        );
+
+        // DB = lHash || PS || 0x01 || M
+        let mut data_block = sha256_bytes(&[]);
+        data_block.resize(k - message.len() - SHA256_LENGTH - 2, 0);
+        data_block.push(0x01);
+        data_block.extend_from_slice(message);
+
+        let data_block_mask = mgf1_sha256(seed, k - SHA256_LENGTH - 1);
+        let masked_data_block = xor_octets(&data_block, &data_block_mask);
+        let seed_mask = mgf1_sha256(&masked_data_block, SHA256_LENGTH);
+        let masked_seed = xor_octets(seed, &seed_mask);
+
+        let mut encoded_message = Vec::with_capacity(k);
+        encoded_message.push(0x00);
+        encoded_message.extend_from_slice(&masked_seed);
+        encoded_message.extend_from_slice(&masked_data_block);
+        Ok(encoded_message)
+    }
+
+    /// Dekodiert eine nach EME-OAEP kodierte Nachricht (RFC 8017, Abschnitt 7.1.2, Schritt 3).
+    /// Alle Prüfungen werden durchgeführt, bevor ein Fehler zurückgegeben wird, damit
+    /// keine Rückschlüsse auf die fehlgeschlagene Prüfung möglich sind.
+    ///
+    /// # Argumente
+    /// * `encoded_message` - Die kodierte Nachricht der Länge `k`.
+    /// * `k` - Die Länge des Modulus in Bytes.
+    ///
+    /// # Rückgabe
+    /// Die ursprüngliche Nachricht.
+    fn decode(encoded_message: &[u8], k: usize) -> Result<Vec<u8>> {
+        let (leading_byte, rest) = encoded_message.split_at(1);
+        let (masked_seed, masked_data_block) = rest.split_at(SHA256_LENGTH);
+
+        let seed_mask = mgf1_sha256(masked_data_block, SHA256_LENGTH);
+        let seed = xor_octets(masked_seed, &seed_mask);
+        let data_block_mask = mgf1_sha256(&seed, k - SHA256_LENGTH - 1);
+        let data_block = xor_octets(masked_data_block, &data_block_mask);
+
+        let (label_hash, padded_message) = data_block.split_at(SHA256_LENGTH);
+        let separator_index = padded_message.iter().position(|byte| *byte != 0x00);
+
+        let is_valid = leading_byte[0] == 0x00
+            && label_hash == sha256_bytes(&[]).as_slice()
+            && separator_index.is_some_and(|index| padded_message[index] == 0x01);
+        trace!("OAEP: Dekodierung gültig: {}", is_valid);
+        ensure!(is_valid, RsaError::DecryptionError);
+
+        // Das unwrap ist sicher, weil die Gültigkeit oben geprüft wurde.
+        let message_start = separator_index.unwrap() + 1;
+        Ok(padded_message[message_start..].to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::asymmetric_encryption_types::KeyGenerator;
+    use crate::encryption::core::rsa::keys::RsaKeyPair;
+    use crate::encryption::core::rsa::rsa_scheme::RsaKeyGenConfig;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+
+    fn generate_key_pair(key_size: u32, random_seed: u32) -> RsaKeyPair {
+        let config = RsaKeyGenConfig {
+            key_size,
+            miller_rabin_iterations: 30,
+            random_seed,
+            number_theory_service: NumberTheoryService::new(Fast),
+            prime_count: 2,
+        };
+        RsaScheme::generate_keypair(&config).unwrap()
+    }
+
+    #[test]
+    fn test_encode_decode_roundtrip() {
+        let k = 128;
+        let seed = [0x5a; SHA256_LENGTH];
+        let message = b"Das ist eine Testnachricht";
+
+        let encoded = RsaOaepScheme::encode(message, k, &seed).unwrap();
+        assert_eq!(encoded.len(), k);
+        assert_eq!(encoded[0], 0x00);
+
+        let decoded = RsaOaepScheme::decode(&encoded, k).unwrap();
+        assert_eq!(decoded, message);
+    }
+
+    #[test]
+    fn test_encode_rejects_long_message() {
+        let k = 128;
+        let seed = [0x01; SHA256_LENGTH];
+        let max_length = k - 2 * SHA256_LENGTH - 2;
+
+        assert!(RsaOaepScheme::encode(&vec![7; max_length], k, &seed).is_ok());
+        let result = RsaOaepScheme::encode(&vec![7; max_length + 1], k, &seed);
+        match result.unwrap_err().downcast_ref::<RsaError>() {
+            Some(RsaError::MessageTooLongError(_, _)) => {}
+            _ => panic!("Expected MessageTooLongError"),
+        }
+    }
+
+    #[test]
+    fn test_decode_detects_manipulation() {
+        let k = 128;
+        let seed = [0x33; SHA256_LENGTH];
+        let mut encoded = RsaOaepScheme::encode(b"Hallo", k, &seed).unwrap();
+        encoded[k - 1] ^= 0x01;
+        encoded[40] ^= 0x80;
+        assert!(RsaOaepScheme::decode(&encoded, k).is_err());
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_happy_flow() {
+        let key_pair = generate_key_pair(1024, 29);
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        let message = "Das ist eine OAEP-Testnachricht mit Ümläuten".as_bytes();
+
+        let ciphertext = RsaOaepScheme::encrypt(&key_pair.public_key, message, &service).unwrap();
+        let plaintext =
+            RsaOaepScheme::decrypt(&key_pair.private_key, &ciphertext, &service).unwrap();
+        assert_eq!(plaintext, message);
+    }
+
+    #[test]
+    fn test_encryption_is_randomized() {
+        let key_pair = generate_key_pair(1024, 29);
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        let message = b"Gleiche Nachricht";
+
+        let first = RsaOaepScheme::encrypt(&key_pair.public_key, message, &service).unwrap();
+        let second = RsaOaepScheme::encrypt(&key_pair.public_key, message, &service).unwrap();
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_decrypt_rejects_manipulated_ciphertext() {
+        let key_pair = generate_key_pair(1024, 31);
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+
+        let ciphertext = RsaOaepScheme::encrypt(&key_pair.public_key, b"42", &service).unwrap();
+        // Multiplikative Manipulation, die beim Lehrbuch-RSA unbemerkt bliebe.
+        let factor = RsaScheme::encrypt(&key_pair.public_key, &2.into(), &service);
+        let manipulated = (ciphertext * factor) % &key_pair.public_key.n;
+
+        let result = RsaOaepScheme::decrypt(&key_pair.private_key, &manipulated, &service);
+        assert!(result.is_err());
+    }
+}
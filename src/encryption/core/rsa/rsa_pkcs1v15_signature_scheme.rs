@@ -0,0 +1,209 @@
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+use log::debug;
+
+use crate::encryption::asymmetric_encryption_types::{AsymmetricEncryptor, Signer, Verifier};
+use crate::encryption::core::rsa::data_conversion::{
+    integer_to_octets, octet_length, octets_to_integer,
+};
+use crate::encryption::core::rsa::keys::{RsaPrivateKey, RsaPublicKey};
+use crate::encryption::core::rsa::rsa_scheme::RsaScheme;
+use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+use crate::shared::errors::RsaError;
+use crate::shared::hashing::sha256_bytes;
+
+/// Der DER-kodierte Präfix der `DigestInfo` für SHA256 (RFC 8017, Abschnitt 9.2, Anmerkung 1).
+const SHA256_DIGEST_INFO_PREFIX: [u8; 19] = [
+    0x30, 0x31, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01, 0x05,
+    0x00, 0x04, 0x20,
+];
+
+/// Die minimale Anzahl an `0xff`-Bytes im Padding.
+const MIN_PADDING_LENGTH: usize = 8;
+
+/// RSA-Signaturen mit der Kodierung EMSA-PKCS1-v1_5 (RSASSA-PKCS1-v1_5 aus RFC 8017, Abschnitt 8.2).
+/// Als Hashfunktion wird SHA256 verwendet.
+///
+/// Die Kodierung ist deterministisch, d.h. dieselbe Nachricht ergibt mit demselben Schlüssel
+/// immer dieselbe Signatur.
+pub struct RsaPkcs1v15SignatureScheme {}
+
+impl Signer<RsaScheme> for RsaPkcs1v15SignatureScheme {
+    type Input = [u8];
+    type Output = Result<BigInt>;
+    type Key = RsaPrivateKey;
+
+    /// Signiert die Nachricht, indem ihr nach EMSA-PKCS1-v1_5 kodierter Hash mit dem privaten
+    /// Schlüssel potenziert wird.
+    ///
+    /// # Argumente
+    /// * `key` - Der private Schlüssel.
+    /// * `message` - Die zu signierende Nachricht.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// Die Signatur als Ganzzahl.
+    ///
+    /// # Fehler
+    /// * `RsaError::ModulusTooShortError` - Falls der Modulus für die Kodierung zu klein ist.
+    fn sign(
+        key: &Self::Key,
+        message: &Self::Input,
+        service: &NumberTheoryWithPrngService,
+    ) -> Self::Output {
+        let k = octet_length(&key.n);
+        let encoded_message = Self::encode(message, k)?;
+        let message_representative = octets_to_integer(&encoded_message);
+        debug!(
+            "PKCS#1 v1.5: Signiere eine Nachricht mit {} Bytes",
+            message.len()
+        );
+        Ok(RsaScheme::sign(key, &message_representative, service))
+    }
+}
+
+impl Verifier<RsaScheme> for RsaPkcs1v15SignatureScheme {
+    type Signature = BigInt;
+    type Message = [u8];
+    type Output = bool;
+    type Key = RsaPublicKey;
+
+    /// Überprüft die Signatur, indem sie mit dem öffentlichen Schlüssel potenziert und mit der
+    /// erneut berechneten Kodierung der Nachricht verglichen wird.
+    ///
+    /// # Argumente
+    /// * `key` - Der öffentliche Schlüssel.
+    /// * `signature` - Die zu überprüfende Signatur.
+    /// * `message` - Die signierte Nachricht.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// `true`, falls die Signatur gültig ist, sonst `false`.
+    fn verify(
+        key: &Self::Key,
+        signature: &Self::Signature,
+        message: &Self::Message,
+        service: &NumberTheoryWithPrngService,
+    ) -> Self::Output {
+        if signature >= &key.n {
+            return false;
+        }
+        let k = octet_length(&key.n);
+        let message_representative = RsaScheme::encrypt(key, signature, service);
+
+        match (
+            integer_to_octets(&message_representative, k),
+            Self::encode(message, k),
+        ) {
+            (Ok(encoded_message), Ok(expected_message)) => encoded_message == expected_message,
+            _ => false,
+        }
+    }
+}
+
+impl RsaPkcs1v15SignatureScheme {
+    /// Kodiert den Hash der Nachricht nach EMSA-PKCS1-v1_5 (RFC 8017, Abschnitt 9.2).
+    ///
+    /// # Argumente
+    /// * `message` - Die Nachricht.
+    /// * `k` - Die Länge des Modulus in Bytes.
+    ///
+    /// # Rückgabe
+    /// Die kodierte Nachricht `EM = 0x00 || 0x01 || PS || 0x00 || T` der Länge `k`.
+    fn encode(message: &[u8], k: usize) -> Result<Vec<u8>> {
+        let mut digest_info = SHA256_DIGEST_INFO_PREFIX.to_vec();
+        digest_info.extend_from_slice(&sha256_bytes(message));
+
+        ensure!(
+            k >= digest_info.len() + MIN_PADDING_LENGTH + 3,
+            RsaError::ModulusTooShortError(k)
+        );
+
+        let mut encoded_message = vec![0x00, 0x01];
+        encoded_message.resize(k - digest_info.len() - 1, 0xff);
+        encoded_message.push(0x00);
+        encoded_message.extend_from_slice(&digest_info);
+        Ok(encoded_message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::asymmetric_encryption_types::KeyGenerator;
+    use crate::encryption::core::rsa::keys::RsaKeyPair;
+    use crate::encryption::core::rsa::rsa_scheme::RsaKeyGenConfig;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+
+    fn generate_key_pair(key_size: u32, random_seed: u32) -> RsaKeyPair {
+        let config = RsaKeyGenConfig {
+            key_size,
+            miller_rabin_iterations: 30,
+            random_seed,
+            number_theory_service: NumberTheoryService::new(Fast),
+            prime_count: 2,
+        };
+        RsaScheme::generate_keypair(&config).unwrap()
+    }
+
+    #[test]
+    fn test_encode_structure() {
+        let k = 128;
+        let encoded = RsaPkcs1v15SignatureScheme::encode(b"abc", k).unwrap();
+        assert_eq!(encoded.len(), k);
+        assert_eq!(&encoded[..2], &[0x00, 0x01]);
+
+        let separator = k - 32 - SHA256_DIGEST_INFO_PREFIX.len() - 1;
+        assert!(encoded[2..separator].iter().all(|byte| *byte == 0xff));
+        assert_eq!(encoded[separator], 0x00);
+        assert_eq!(&encoded[k - 32..], sha256_bytes(b"abc").as_slice());
+    }
+
+    #[test]
+    fn test_encode_rejects_short_modulus() {
+        assert!(RsaPkcs1v15SignatureScheme::encode(b"abc", 62).is_ok());
+        assert!(RsaPkcs1v15SignatureScheme::encode(b"abc", 61).is_err());
+    }
+
+    #[test]
+    fn test_sign_verify_happy_flow() {
+        let key_pair = generate_key_pair(1024, 17);
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        let message = "Das ist eine PKCS#1-Testnachricht".as_bytes();
+
+        let signature =
+            RsaPkcs1v15SignatureScheme::sign(&key_pair.private_key, message, &service).unwrap();
+        assert!(RsaPkcs1v15SignatureScheme::verify(
+            &key_pair.public_key,
+            &signature,
+            message,
+            &service
+        ));
+        // Die Kodierung ist deterministisch.
+        let second_signature =
+            RsaPkcs1v15SignatureScheme::sign(&key_pair.private_key, message, &service).unwrap();
+        assert_eq!(signature, second_signature);
+    }
+
+    #[test]
+    fn test_verify_rejects_other_message_and_signature() {
+        let key_pair = generate_key_pair(1024, 17);
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+
+        let signature =
+            RsaPkcs1v15SignatureScheme::sign(&key_pair.private_key, b"Hallo", &service).unwrap();
+        assert!(!RsaPkcs1v15SignatureScheme::verify(
+            &key_pair.public_key,
+            &signature,
+            b"Hallo!",
+            &service
+        ));
+        assert!(!RsaPkcs1v15SignatureScheme::verify(
+            &key_pair.public_key,
+            &(signature + 1),
+            b"Hallo",
+            &service
+        ));
+    }
+}
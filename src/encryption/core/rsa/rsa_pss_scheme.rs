@@ -0,0 +1,286 @@
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+use log::{debug, trace};
+
+use crate::encryption::asymmetric_encryption_types::{AsymmetricEncryptor, Signer, Verifier};
+use crate::encryption::core::rsa::data_conversion::{
+    integer_to_octets, octets_to_integer, random_octets, xor_octets,
+};
+use crate::encryption::core::rsa::keys::{RsaPrivateKey, RsaPublicKey};
+use crate::encryption::core::rsa::rsa_scheme::RsaScheme;
+use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+use crate::shared::errors::RsaError;
+use crate::shared::hashing::{mgf1_sha256, sha256_bytes, SHA256_LENGTH};
+
+/// Die Länge des Salts in Bytes. Wie in RFC 8017 empfohlen entspricht sie der Länge des Hashes.
+pub const PSS_SALT_LENGTH: usize = SHA256_LENGTH;
+
+/// Das Byte, mit dem jede nach EMSA-PSS kodierte Nachricht endet.
+const TRAILER_FIELD: u8 = 0xbc;
+
+/// RSA-Signaturen mit der Kodierung EMSA-PSS (RSASSA-PSS aus RFC 8017, Abschnitt 8.1).
+/// Als Hashfunktion wird SHA256, als Maskengenerierungsfunktion MGF1 mit SHA256 und ein
+/// Salt der Länge `PSS_SALT_LENGTH` verwendet.
+///
+/// Durch den zufälligen Salt ist die Signatur randomisiert.
+pub struct RsaPssScheme {}
+
+impl Signer<RsaScheme> for RsaPssScheme {
+    type Input = [u8];
+    type Output = Result<BigInt>;
+    type Key = RsaPrivateKey;
+
+    /// Signiert die Nachricht, indem ihr nach EMSA-PSS kodierter Hash mit dem privaten
+    /// Schlüssel potenziert wird.
+    ///
+    /// # Argumente
+    /// * `key` - Der private Schlüssel.
+    /// * `message` - Die zu signierende Nachricht.
+    /// * `service` - Der Service für die Zahlentheorie und die Erzeugung des Salts.
+    ///
+    /// # Rückgabe
+    /// Die Signatur als Ganzzahl.
+    ///
+    /// # Fehler
+    /// * `RsaError::ModulusTooShortError` - Falls der Modulus für die Kodierung zu klein ist.
+    fn sign(
+        key: &Self::Key,
+        message: &Self::Input,
+        service: &NumberTheoryWithPrngService,
+    ) -> Self::Output {
+        let em_bits = Self::encoded_message_bits(&key.n);
+        let salt = random_octets(PSS_SALT_LENGTH, service);
+        let encoded_message = Self::encode(message, em_bits, &salt)?;
+        let message_representative = octets_to_integer(&encoded_message);
+        debug!("PSS: Signiere eine Nachricht mit {} Bytes", message.len());
+        Ok(RsaScheme::sign(key, &message_representative, service))
+    }
+}
+
+impl Verifier<RsaScheme> for RsaPssScheme {
+    type Signature = BigInt;
+    type Message = [u8];
+    type Output = bool;
+    type Key = RsaPublicKey;
+
+    /// Überprüft die Signatur, indem sie mit dem öffentlichen Schlüssel potenziert und die
+    /// entstandene Kodierung gegen die Nachricht geprüft wird.
+    ///
+    /// # Argumente
+    /// * `key` - Der öffentliche Schlüssel.
+    /// * `signature` - Die zu überprüfende Signatur.
+    /// * `message` - Die signierte Nachricht.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// `true`, falls die Signatur gültig ist, sonst `false`.
+    fn verify(
+        key: &Self::Key,
+        signature: &Self::Signature,
+        message: &Self::Message,
+        service: &NumberTheoryWithPrngService,
+    ) -> Self::Output {
+        if signature >= &key.n {
+            return false;
+        }
+        let em_bits = Self::encoded_message_bits(&key.n);
+        let message_representative = RsaScheme::encrypt(key, signature, service);
+        match integer_to_octets(&message_representative, em_bits.div_ceil(8)) {
+            Ok(encoded_message) => Self::is_consistent(message, &encoded_message, em_bits),
+            Err(_) => false,
+        }
+    }
+}
+
+impl RsaPssScheme {
+    /// Gibt die Länge der kodierten Nachricht in Bits zurück (`emBits = modBits - 1`).
+    /// Dadurch ist die kodierte Nachricht als Zahl garantiert kleiner als der Modulus.
+    fn encoded_message_bits(modulus: &BigInt) -> usize {
+        modulus.bits() as usize - 1
+    }
+
+    /// Gibt die Maske für das erste Byte zurück, die die überzähligen, führenden Bits
+    /// (`8 * emLen - emBits`) entfernt.
+    fn leading_byte_mask(em_bits: usize) -> u8 {
+        let unused_bits = 8 * em_bits.div_ceil(8) - em_bits;
+        0xff >> unused_bits
+    }
+
+    /// Berechnet `H = Hash(0x00 x 8 || mHash || salt)`.
+    fn hash_with_salt(message_hash: &[u8], salt: &[u8]) -> Vec<u8> {
+        let mut salted_message = vec![0u8; 8];
+        salted_message.extend_from_slice(message_hash);
+        salted_message.extend_from_slice(salt);
+        sha256_bytes(&salted_message)
+    }
+
+    /// Kodiert den Hash der Nachricht nach EMSA-PSS (RFC 8017, Abschnitt 9.1.1).
+    ///
+    /// # Argumente
+    /// * `message` - Die Nachricht.
+    /// * `em_bits` - Die maximale Bitlänge der kodierten Nachricht.
+    /// * `salt` - Der Salt.
+    ///
+    /// # Rückgabe
+    /// Die kodierte Nachricht `EM = maskedDB || H || 0xbc`.
+    fn encode(message: &[u8], em_bits: usize, salt: &[u8]) -> Result<Vec<u8>> {
+        let em_length = em_bits.div_ceil(8);
+        ensure!(
+            em_length >= SHA256_LENGTH + salt.len() + 2,
+            RsaError::ModulusTooShortError(em_length)
+        );
+
+        let hash = Self::hash_with_salt(&sha256_bytes(message), salt);
+
+        // DB = PS || 0x01 || salt
+        let mut data_block = vec![0u8; em_length - salt.len() - SHA256_LENGTH - 2];
+        data_block.push(0x01);
+        data_block.extend_from_slice(salt);
+
+        let data_block_mask = mgf1_sha256(&hash, em_length - SHA256_LENGTH - 1);
+        let mut masked_data_block = xor_octets(&data_block, &data_block_mask);
+        masked_data_block[0] &= Self::leading_byte_mask(em_bits);
+
+        let mut encoded_message = masked_data_block;
+        encoded_message.extend_from_slice(&hash);
+        encoded_message.push(TRAILER_FIELD);
+        Ok(encoded_message)
+    }
+
+    /// Prüft, ob die kodierte Nachricht zur Nachricht passt (RFC 8017, Abschnitt 9.1.2).
+    ///
+    /// # Argumente
+    /// * `message` - Die Nachricht.
+    /// * `encoded_message` - Die kodierte Nachricht der Länge `emLen`.
+    /// * `em_bits` - Die maximale Bitlänge der kodierten Nachricht.
+    ///
+    /// # Rückgabe
+    /// `true`, falls die Kodierung konsistent ist, sonst `false`.
+    fn is_consistent(message: &[u8], encoded_message: &[u8], em_bits: usize) -> bool {
+        let em_length = em_bits.div_ceil(8);
+        if em_length < SHA256_LENGTH + PSS_SALT_LENGTH + 2
+            || encoded_message.last() != Some(&TRAILER_FIELD)
+        {
+            trace!("PSS: Ungültige Länge oder ungültiges Trailer-Feld");
+            return false;
+        }
+
+        let (masked_data_block, rest) = encoded_message.split_at(em_length - SHA256_LENGTH - 1);
+        let hash = &rest[..SHA256_LENGTH];
+        let leading_byte_mask = Self::leading_byte_mask(em_bits);
+        if masked_data_block[0] & !leading_byte_mask != 0 {
+            trace!("PSS: Die führenden Bits sind nicht null");
+            return false;
+        }
+
+        let data_block_mask = mgf1_sha256(hash, masked_data_block.len());
+        let mut data_block = xor_octets(masked_data_block, &data_block_mask);
+        data_block[0] &= leading_byte_mask;
+
+        let separator_index = em_length - SHA256_LENGTH - PSS_SALT_LENGTH - 2;
+        let (padding, rest) = data_block.split_at(separator_index);
+        if padding.iter().any(|byte| *byte != 0x00) || rest[0] != 0x01 {
+            trace!("PSS: Ungültiges Padding im Datenblock");
+            return false;
+        }
+
+        let salt = &rest[1..];
+        Self::hash_with_salt(&sha256_bytes(message), salt) == hash
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::asymmetric_encryption_types::KeyGenerator;
+    use crate::encryption::core::rsa::keys::RsaKeyPair;
+    use crate::encryption::core::rsa::rsa_scheme::RsaKeyGenConfig;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+
+    fn generate_key_pair(key_size: u32, random_seed: u32) -> RsaKeyPair {
+        let config = RsaKeyGenConfig {
+            key_size,
+            miller_rabin_iterations: 30,
+            random_seed,
+            number_theory_service: NumberTheoryService::new(Fast),
+            prime_count: 2,
+        };
+        RsaScheme::generate_keypair(&config).unwrap()
+    }
+
+    #[test]
+    fn test_encode_is_consistent() {
+        // Eine Bitlänge, die kein Vielfaches von 8 ist, prüft auch die Maskierung des ersten Bytes.
+        let em_bits = 1021;
+        let salt = [0x42; PSS_SALT_LENGTH];
+        let message = b"Das ist eine PSS-Testnachricht";
+
+        let encoded = RsaPssScheme::encode(message, em_bits, &salt).unwrap();
+        assert_eq!(encoded.len(), 128);
+        assert_eq!(encoded[0] & 0xe0, 0);
+        assert_eq!(*encoded.last().unwrap(), TRAILER_FIELD);
+
+        assert!(RsaPssScheme::is_consistent(message, &encoded, em_bits));
+        assert!(!RsaPssScheme::is_consistent(
+            b"Andere Nachricht",
+            &encoded,
+            em_bits
+        ));
+
+        let mut manipulated = encoded.clone();
+        manipulated[10] ^= 0x01;
+        assert!(!RsaPssScheme::is_consistent(message, &manipulated, em_bits));
+    }
+
+    #[test]
+    fn test_encode_rejects_short_modulus() {
+        let salt = [0x42; PSS_SALT_LENGTH];
+        assert!(RsaPssScheme::encode(b"abc", 8 * 66, &salt).is_ok());
+        assert!(RsaPssScheme::encode(b"abc", 8 * 65, &salt).is_err());
+    }
+
+    #[test]
+    fn test_sign_verify_happy_flow() {
+        let key_pair = generate_key_pair(1024, 23);
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        let message = "Das ist eine PSS-Testnachricht mit Ümläuten".as_bytes();
+
+        let first = RsaPssScheme::sign(&key_pair.private_key, message, &service).unwrap();
+        let second = RsaPssScheme::sign(&key_pair.private_key, message, &service).unwrap();
+        // Durch den Salt sind die Signaturen unterschiedlich, aber beide gültig.
+        assert_ne!(first, second);
+        assert!(RsaPssScheme::verify(
+            &key_pair.public_key,
+            &first,
+            message,
+            &service
+        ));
+        assert!(RsaPssScheme::verify(
+            &key_pair.public_key,
+            &second,
+            message,
+            &service
+        ));
+    }
+
+    #[test]
+    fn test_verify_rejects_other_message_and_signature() {
+        let key_pair = generate_key_pair(1024, 23);
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+
+        let signature = RsaPssScheme::sign(&key_pair.private_key, b"Hallo", &service).unwrap();
+        assert!(!RsaPssScheme::verify(
+            &key_pair.public_key,
+            &signature,
+            b"Hallo!",
+            &service
+        ));
+        assert!(!RsaPssScheme::verify(
+            &key_pair.public_key,
+            &(signature + 1),
+            b"Hallo",
+            &service
+        ));
+    }
+}
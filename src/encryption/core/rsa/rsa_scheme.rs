@@ -2,19 +2,28 @@ use crate::encryption::asymmetric_encryption_types::{
     AsymmetricDecryptor, AsymmetricEncryptionScheme, AsymmetricEncryptor, KeyGenWithPrimeConfig,
     KeyGenerator, Signer, Verifier,
 };
-use crate::encryption::core::rsa::keys::{RsaKeyPair, RsaPrivateKey, RsaPublicKey};
+use crate::encryption::core::rsa::keys::{
+    RsaCrtParameters, RsaKeyPair, RsaOtherPrimeInfo, RsaPrivateKey, RsaPublicKey,
+};
 use crate::encryption::encryption_types::{Decryptor, EncryptionScheme, Encryptor};
 use crate::math_core::number_theory::number_theory_service::{
     NumberTheoryService, NumberTheoryServiceTrait,
 };
 use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
-use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+use crate::math_core::prime_generator::PrimeGenerator;
+use crate::math_core::random_source::RandomSource;
 use crate::math_core::traits::increment::Increment;
-use anyhow::Result;
-use atomic_counter::RelaxedCounter;
+use crate::shared::errors::RsaError;
+use anyhow::{ensure, Result};
 use bigdecimal::num_bigint::BigInt;
+use bigdecimal::num_traits::Euclid;
 use bigdecimal::One;
 use log::{debug, trace};
+use num::Integer;
+
+/// Die minimale Bit-Breite jedes Primfaktors. Darunter gibt es zu wenige Primzahlen, um
+/// verschiedene Faktoren zu finden, und die Suche würde nicht enden.
+pub const MIN_PRIME_WIDTH: u32 = 16;
 
 pub struct RsaScheme {}
 
@@ -28,6 +37,9 @@ pub struct RsaKeyGenConfig {
     pub miller_rabin_iterations: u32,
     pub random_seed: u32,
     pub number_theory_service: NumberTheoryService,
+    /// Die Anzahl der Primfaktoren des Modulus. 2 für klassisches RSA, 3 bis 5 für
+    /// Multi-Prime-RSA nach RFC 8017.
+    pub prime_count: u32,
 }
 
 impl KeyGenWithPrimeConfig for RsaKeyGenConfig {
@@ -46,34 +58,77 @@ impl KeyGenWithPrimeConfig for RsaKeyGenConfig {
     fn number_theory_service(&self) -> NumberTheoryService {
         self.number_theory_service
     }
+
+    fn prime_count(&self) -> u32 {
+        self.prime_count
+    }
 }
 
 impl KeyGenerator<RsaPublicKey, RsaPrivateKey, RsaScheme> for RsaScheme {
     type KeyPair = RsaKeyPair;
 
-    fn generate_keypair(config: &impl KeyGenWithPrimeConfig) -> Self::KeyPair {
+    /// Generiert ein Schlüsselpaar mit `prime_count` Primfaktoren.
+    ///
+    /// # Argumente
+    /// * `config` - Die Konfiguration für den Schlüsselgenerierungsvorgang.
+    /// * `random` - Die Quelle der Zufallszahlen.
+    ///
+    /// # Rückgabe
+    /// Das Schlüsselpaar.
+    ///
+    /// # Fehler
+    /// * `RsaError::InvalidPrimeCountError` - Wenn nicht zwischen 2 und 5 Primfaktoren verlangt sind.
+    /// * `RsaError::InvalidModulusWidthError` - Wenn der Modulus schmaler als `MIN_PRIME_WIDTH`
+    ///   Bits pro Primfaktor ist.
+    fn generate_keypair_with_source(
+        config: &impl KeyGenWithPrimeConfig,
+        random: &dyn RandomSource,
+    ) -> Result<Self::KeyPair> {
         debug!(
             "Generiere Schlüsselpaar mit key_size {} und Miller-Rabin-Iterations {}",
             config.characteristic(),
             config.miller_rabin_iterations()
         );
-        let random_generator =
-            &PseudoRandomNumberGenerator::new(config.random_seed(), config.number_theory_service());
+        let prime_count = config.prime_count();
+        ensure!(
+            (2..=5).contains(&prime_count),
+            RsaError::InvalidPrimeCountError(prime_count)
+        );
+        let min_width = prime_count * MIN_PRIME_WIDTH;
+        ensure!(
+            config.characteristic() >= min_width,
+            RsaError::InvalidModulusWidthError(config.characteristic(), prime_count, min_width)
+        );
+        let prime_generator = PrimeGenerator::new(config.number_theory_service());
 
-        let (prime_one, prime_two) = random_generator
-            .get_distinct_primes(config.characteristic(), config.miller_rabin_iterations());
+        let primes = prime_generator.get_distinct_primes(
+            config.characteristic(),
+            prime_count,
+            config.miller_rabin_iterations(),
+            random,
+        );
 
-        let n = &prime_one * &prime_two;
-        debug!("n ist {}", n);
+        let n: BigInt = primes.iter().product();
+        debug!("n ist {} mit {} Primfaktoren", n, primes.len());
 
-        let phi = (&prime_one.decrement()) * (&prime_two.decrement());
-        let e = Self::generate_e(&phi, random_generator, config.number_theory_service());
-        let d = Self::generate_d(&e, &phi, config.number_theory_service()).unwrap(); // TODO: Abfangen später
+        // phi(n) = (r_1 - 1) * ... * (r_u - 1) und lambda(n) = kgV(r_1 - 1, ..., r_u - 1)
+        let phi: BigInt = primes.iter().map(|prime| prime.decrement()).product();
+        let lambda = primes
+            .iter()
+            .map(|prime| prime.decrement())
+            .fold(BigInt::one(), |lambda, prime| lambda.lcm(&prime));
+        let e = Self::generate_e(&phi, random, config.number_theory_service());
+        let d = Self::generate_d(&e, &lambda, config.number_theory_service())?;
+        let crt = Self::generate_crt_parameters(&primes, &d, config.number_theory_service())?;
 
-        RsaKeyPair {
+        Ok(RsaKeyPair {
             public_key: RsaPublicKey { e, n: n.clone() },
-            private_key: RsaPrivateKey { d, n },
-        }
+            private_key: RsaPrivateKey {
+                d,
+                n,
+                crt: Some(crt),
+            },
+        })
     }
 }
 
@@ -107,9 +162,7 @@ impl AsymmetricDecryptor<RsaScheme> for RsaScheme {
         ciphertext: &Self::Input,
         service: &NumberTheoryWithPrngService,
     ) -> Self::Output {
-        service
-            .number_theory_service
-            .fast_exponentiation(ciphertext, &key.d, &key.n)
+        Self::apply_private_exponent(key, ciphertext, &service.number_theory_service)
     }
 }
 
@@ -123,9 +176,7 @@ impl Signer<RsaScheme> for RsaScheme {
         message: &Self::Input,
         service: &NumberTheoryWithPrngService,
     ) -> Self::Output {
-        service
-            .number_theory_service
-            .fast_exponentiation(message, &key.d, &key.n)
+        Self::apply_private_exponent(key, message, &service.number_theory_service)
     }
 }
 
@@ -153,20 +204,14 @@ impl RsaScheme {
     ///
     /// # Argumente
     /// * `phi` - Die Zahl `phi`.
-    /// * `random_generator` - Der Pseudo-Zufallszahlengenerator.
+    /// * `random` - Die Quelle der Zufallszahlen.
     ///
     /// # Rückgabe
     /// Die generierte Zahl `e`.
-    fn generate_e(
-        phi: &BigInt,
-        random_generator: &PseudoRandomNumberGenerator,
-        service: NumberTheoryService,
-    ) -> BigInt {
+    fn generate_e(phi: &BigInt, random: &dyn RandomSource, service: NumberTheoryService) -> BigInt {
         debug!("Generiere e mit phi {}", phi);
 
-        let n_counter = RelaxedCounter::new(1);
-
-        let mut e = random_generator.take(&3.into(), &phi.decrement(), &n_counter);
+        let mut e = random.take(&3.into(), &phi.decrement());
         while e < *phi {
             let ggt = service.extended_euclid(&e, phi).ggt;
             if ggt.is_one() {
@@ -197,4 +242,272 @@ impl RsaScheme {
         debug!("d ist {}", d);
         Ok(d)
     }
+
+    /// Berechnet die Parameter für die Entschlüsselung mittels Chinesischem Restsatz.
+    ///
+    /// # Argumente
+    /// * `primes` - Die Primfaktoren `r_1 = p`, `r_2 = q`, ... von `n`.
+    /// * `d` - Der private Exponent.
+    ///
+    /// # Rückgabe
+    /// Die CRT-Parameter `p`, `q`, `dP`, `dQ`, `qInv` und gegebenenfalls `(r_i, d_i, t_i)`
+    /// für alle weiteren Primfaktoren.
+    ///
+    /// # Fehler
+    /// * `ArithmeticError::NoInverseError` - Falls einer der Koeffizienten nicht existiert.
+    fn generate_crt_parameters(
+        primes: &[BigInt],
+        d: &BigInt,
+        service: NumberTheoryService,
+    ) -> Result<RsaCrtParameters> {
+        let (p, q) = (&primes[0], &primes[1]);
+        trace!("Generiere CRT-Parameter mit p {} und q {}", p, q);
+        let d_p = d.rem_euclid(&p.decrement());
+        let d_q = d.rem_euclid(&q.decrement());
+        let q_inv = service.modulo_inverse(q, p)?;
+
+        let mut product = p * q;
+        let mut other_primes = Vec::with_capacity(primes.len() - 2);
+        for r in &primes[2..] {
+            trace!("Generiere CRT-Parameter für den weiteren Primfaktor {}", r);
+            other_primes.push(RsaOtherPrimeInfo {
+                r: r.clone(),
+                d: d.rem_euclid(&r.decrement()),
+                t: service.modulo_inverse(&product, r)?,
+            });
+            product *= r;
+        }
+
+        Ok(RsaCrtParameters {
+            p: p.clone(),
+            q: q.clone(),
+            d_p,
+            d_q,
+            q_inv,
+            other_primes,
+        })
+    }
+
+    /// Potenziert die Basis mit dem privaten Exponenten des Schlüssels modulo `n`.
+    /// Sind im Schlüssel die CRT-Parameter hinterlegt, wird je Primfaktor eine kleinere
+    /// Exponentiation durchgeführt und die Teilergebnisse werden mit dem Garner-Verfahren
+    /// (RFC 8017, Abschnitt 5.1.2) wieder zusammengesetzt.
+    ///
+    /// # Argumente
+    /// * `key` - Der private Schlüssel.
+    /// * `base` - Die zu potenzierende Basis.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// Das Ergebnis `base^d mod n`.
+    fn apply_private_exponent(
+        key: &RsaPrivateKey,
+        base: &BigInt,
+        service: &NumberTheoryService,
+    ) -> BigInt {
+        match &key.crt {
+            Some(crt) => {
+                let m_p = service.fast_exponentiation(base, &crt.d_p, &crt.p);
+                let m_q = service.fast_exponentiation(base, &crt.d_q, &crt.q);
+                // h = qInv * (m_p - m_q) mod p
+                let h = (&crt.q_inv * (m_p - &m_q)).rem_euclid(&crt.p);
+                let mut result = m_q + h * &crt.q;
+
+                // Weitere Primfaktoren: m = m + R * ((m_i - m) * t_i mod r_i) mit R = r_1 * ... * r_(i-1)
+                let mut product = &crt.p * &crt.q;
+                for other_prime in &crt.other_primes {
+                    let m_i = service.fast_exponentiation(base, &other_prime.d, &other_prime.r);
+                    let h = (&other_prime.t * (m_i - &result)).rem_euclid(&other_prime.r);
+                    result += &product * h;
+                    product *= &other_prime.r;
+                }
+                result
+            }
+            None => service.fast_exponentiation(base, &key.d, &key.n),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
+        Fast, Slow,
+    };
+    use crate::math_core::random_source::{
+        PrngRandomSource, RecordedRandomSource, RecordingRandomSource,
+    };
+
+    fn run_test_for_all_services(test: impl Fn(NumberTheoryWithPrngService)) {
+        test(NumberTheoryWithPrngService::new(Slow, 13)); // Langsame, eigene Implementierung
+        test(NumberTheoryWithPrngService::new(Fast, 13)); // Schnelle, externe Implementierung
+    }
+
+    fn generate_key_pair(key_size: u32, random_seed: u32) -> RsaKeyPair {
+        let config = RsaKeyGenConfig {
+            key_size,
+            miller_rabin_iterations: 30,
+            random_seed,
+            number_theory_service: NumberTheoryService::new(Fast),
+            prime_count: 2,
+        };
+        RsaScheme::generate_keypair(&config).unwrap()
+    }
+
+    #[test]
+    fn test_generated_crt_parameters_are_consistent() {
+        let key_pair = generate_key_pair(512, 23);
+        let private_key = key_pair.private_key;
+        let crt = private_key.crt.clone().unwrap();
+
+        assert_eq!(&crt.p * &crt.q, private_key.n);
+        assert_eq!(crt.d_p, private_key.d.rem_euclid(&crt.p.decrement()));
+        assert_eq!(crt.d_q, private_key.d.rem_euclid(&crt.q.decrement()));
+        assert!((&crt.q_inv * &crt.q).rem_euclid(&crt.p).is_one());
+    }
+
+    #[test]
+    fn test_crt_decryption_matches_plain_decryption() {
+        run_test_for_all_services(|service| {
+            let key_pair = generate_key_pair(512, 41);
+            let private_key = key_pair.private_key;
+            let plain_private_key = RsaPrivateKey {
+                crt: None,
+                ..private_key.clone()
+            };
+
+            let plaintext = BigInt::from(1234567890123456789u64);
+            let ciphertext = RsaScheme::encrypt(&key_pair.public_key, &plaintext, &service);
+
+            let crt_result = RsaScheme::decrypt(&private_key, &ciphertext, &service);
+            let plain_result = RsaScheme::decrypt(&plain_private_key, &ciphertext, &service);
+            assert_eq!(crt_result, plaintext);
+            assert_eq!(plain_result, plaintext);
+        });
+    }
+
+    #[test]
+    fn test_crt_sign_verify() {
+        run_test_for_all_services(|service| {
+            let key_pair = generate_key_pair(256, 7);
+            let message = BigInt::from(424242);
+
+            let signature = RsaScheme::sign(&key_pair.private_key, &message, &service);
+            assert!(RsaScheme::verify(
+                &key_pair.public_key,
+                &signature,
+                &message,
+                &service
+            ));
+            assert!(!RsaScheme::verify(
+                &key_pair.public_key,
+                &signature,
+                &BigInt::from(424243),
+                &service
+            ));
+        });
+    }
+
+    #[test]
+    fn test_multi_prime_key_generation() {
+        for prime_count in 3..=5 {
+            let config = RsaKeyGenConfig {
+                key_size: 640,
+                miller_rabin_iterations: 30,
+                random_seed: 19,
+                number_theory_service: NumberTheoryService::new(Fast),
+                prime_count,
+            };
+            let key_pair = RsaScheme::generate_keypair(&config).unwrap();
+            let private_key = key_pair.private_key;
+            let crt = private_key.crt.clone().unwrap();
+            assert_eq!(crt.other_primes.len(), prime_count as usize - 2);
+
+            let mut primes = vec![crt.p.clone(), crt.q.clone()];
+            primes.extend(crt.other_primes.iter().map(|other| other.r.clone()));
+            assert_eq!(primes.iter().product::<BigInt>(), private_key.n);
+
+            // e * d = 1 mod (r_i - 1) für alle Primfaktoren
+            for prime in &primes {
+                let product = &key_pair.public_key.e * &private_key.d;
+                assert!(product.rem_euclid(&prime.decrement()).is_one());
+            }
+        }
+    }
+
+    #[test]
+    fn test_key_generation_rejects_invalid_configs() {
+        let config = |key_size, prime_count| RsaKeyGenConfig {
+            key_size,
+            miller_rabin_iterations: 30,
+            random_seed: 19,
+            number_theory_service: NumberTheoryService::new(Fast),
+            prime_count,
+        };
+        // 10 Bit für fünf Primfaktoren wären 2-Bit-Primzahlen, die Suche würde nicht enden.
+        assert!(RsaScheme::generate_keypair(&config(10, 5)).is_err());
+        assert!(RsaScheme::generate_keypair(&config(5 * MIN_PRIME_WIDTH - 1, 5)).is_err());
+        assert!(RsaScheme::generate_keypair(&config(512, 1)).is_err());
+        assert!(RsaScheme::generate_keypair(&config(512, 6)).is_err());
+
+        assert!(RsaScheme::generate_keypair(&config(2 * MIN_PRIME_WIDTH, 2)).is_ok());
+    }
+
+    #[test]
+    fn test_multi_prime_crt_decryption_matches_plain_decryption() {
+        run_test_for_all_services(|service| {
+            let config = RsaKeyGenConfig {
+                key_size: 768,
+                miller_rabin_iterations: 30,
+                random_seed: 31,
+                number_theory_service: NumberTheoryService::new(Fast),
+                prime_count: 4,
+            };
+            let key_pair = RsaScheme::generate_keypair(&config).unwrap();
+            let private_key = key_pair.private_key;
+            let plain_private_key = RsaPrivateKey {
+                crt: None,
+                ..private_key.clone()
+            };
+
+            let plaintext = BigInt::from(98765432109876543210u128);
+            let ciphertext = RsaScheme::encrypt(&key_pair.public_key, &plaintext, &service);
+
+            assert_eq!(
+                RsaScheme::decrypt(&private_key, &ciphertext, &service),
+                plaintext
+            );
+            assert_eq!(
+                RsaScheme::decrypt(&plain_private_key, &ciphertext, &service),
+                plaintext
+            );
+        });
+    }
+
+    #[test]
+    fn test_key_generation_replays_recorded_randomness() {
+        let config = RsaKeyGenConfig {
+            key_size: 512,
+            miller_rabin_iterations: 30,
+            random_seed: 29,
+            number_theory_service: NumberTheoryService::new(Fast),
+            prime_count: 2,
+        };
+        let recording = RecordingRandomSource::new(PrngRandomSource::seeded(config.random_seed));
+        let recorded = RsaScheme::generate_keypair_with_source(&config, &recording)
+            .unwrap()
+            .private_key;
+        assert_eq!(
+            recorded.n,
+            RsaScheme::generate_keypair(&config).unwrap().private_key.n
+        );
+
+        let replay = RecordedRandomSource::new(recording.recorded());
+        let replayed = RsaScheme::generate_keypair_with_source(&config, &replay)
+            .unwrap()
+            .private_key;
+        assert_eq!(replay.remaining(), 0);
+        assert_eq!(replayed.n, recorded.n);
+        assert_eq!(replayed.d, recorded.d);
+    }
 }
@@ -0,0 +1,47 @@
+use crate::encryption::asymmetric_encryption_types::{
+    AsymmetricKey, PrivateKey, PublicKey, SignatureKey, VerificationKey,
+};
+use crate::encryption::core::schnorr::schnorr_group::SchnorrGroup;
+use crate::encryption::core::schnorr::schnorr_scheme::SchnorrScheme;
+use crate::encryption::encryption_types::Key;
+use bigdecimal::num_bigint::BigInt;
+
+/// Ein öffentlicher Schnorr-Schlüssel.
+/// Besteht aus der Gruppe und dem öffentlichen Element `y = g^x`.
+#[derive(Clone, Debug)]
+pub struct SchnorrPublicKey<G: SchnorrGroup> {
+    pub group: G,
+    pub y: G::Element,
+}
+
+impl<G: SchnorrGroup> Key<SchnorrScheme<G>> for SchnorrPublicKey<G> {}
+
+impl<G: SchnorrGroup> AsymmetricKey<SchnorrScheme<G>> for SchnorrPublicKey<G> {}
+
+impl<G: SchnorrGroup> PublicKey<SchnorrScheme<G>> for SchnorrPublicKey<G> {}
+
+impl<G: SchnorrGroup> VerificationKey<SchnorrScheme<G>> for SchnorrPublicKey<G> {}
+
+/// Ein privater Schnorr-Schlüssel.
+/// Besteht aus der Gruppe und dem Geheimwert `x` mit `0 < x < q`.
+#[derive(Clone, Debug)]
+pub struct SchnorrPrivateKey<G: SchnorrGroup> {
+    pub group: G,
+    pub x: BigInt,
+}
+
+impl<G: SchnorrGroup> Key<SchnorrScheme<G>> for SchnorrPrivateKey<G> {}
+
+impl<G: SchnorrGroup> AsymmetricKey<SchnorrScheme<G>> for SchnorrPrivateKey<G> {}
+
+impl<G: SchnorrGroup> PrivateKey<SchnorrScheme<G>> for SchnorrPrivateKey<G> {}
+
+impl<G: SchnorrGroup> SignatureKey<SchnorrScheme<G>> for SchnorrPrivateKey<G> {}
+
+/// Ein Schnorr-Schlüsselpaar. Wie bei DSA handelt es sich um ein reines Signatur- und
+/// Identifikationsverfahren, daher wird `AsymmetricKeyPair` nicht implementiert.
+#[derive(Clone, Debug)]
+pub struct SchnorrKeyPair<G: SchnorrGroup> {
+    pub public_key: SchnorrPublicKey<G>,
+    pub private_key: SchnorrPrivateKey<G>,
+}
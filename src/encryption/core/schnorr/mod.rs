@@ -0,0 +1,3 @@
+pub mod keys;
+pub mod schnorr_group;
+pub mod schnorr_scheme;
@@ -0,0 +1,154 @@
+use crate::encryption::core::dsa::domain_parameters::DsaDomainParameters;
+use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
+use crate::math_core::ecc::secure_finite_field_elliptic_curve::SecureFiniteFieldEllipticCurve;
+use crate::math_core::number_theory::number_theory_service::{
+    NumberTheoryService, NumberTheoryServiceTrait,
+};
+use anyhow::Result;
+use bigdecimal::num_bigint::BigInt;
+use std::fmt::Debug;
+
+/// Eine zyklische Gruppe primer Ordnung `q`, über der das Schnorr-Verfahren arbeitet.
+///
+/// Die Gruppe wird multiplikativ geschrieben: `exponentiate` entspricht `g^k` bzw. `k * G`
+/// und `combine` dem Gruppenprodukt bzw. der Punktaddition.
+pub trait SchnorrGroup: Clone + Debug {
+    /// Der Typ der Gruppenelemente.
+    type Element: Clone + Debug + PartialEq;
+
+    /// Die (prime) Ordnung `q` der Gruppe.
+    fn order(&self) -> &BigInt;
+
+    /// Der Erzeuger der Gruppe.
+    fn generator(&self) -> &Self::Element;
+
+    /// Potenziert ein Element mit einem Exponenten.
+    ///
+    /// # Argumente
+    /// * `base` - Das zu potenzierende Element.
+    /// * `exponent` - Der Exponent.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// Das Element `base^exponent`.
+    fn exponentiate(
+        &self,
+        base: &Self::Element,
+        exponent: &BigInt,
+        service: &NumberTheoryService,
+    ) -> Result<Self::Element>;
+
+    /// Potenziert ein Element mit einem geheimen Exponenten, z.B. dem privaten Schlüssel oder
+    /// einer Nonce. Standardmäßig wie `exponentiate`; Gruppen mit einem Verfahren, dessen
+    /// Ablauf nicht vom Exponenten abhängt, überschreiben die Methode.
+    ///
+    /// # Argumente
+    /// * `base` - Das zu potenzierende Element.
+    /// * `exponent` - Der geheime Exponent.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// Das Element `base^exponent`.
+    fn exponentiate_secret(
+        &self,
+        base: &Self::Element,
+        exponent: &BigInt,
+        service: &NumberTheoryService,
+    ) -> Result<Self::Element> {
+        self.exponentiate(base, exponent, service)
+    }
+
+    /// Verknüpft zwei Elemente der Gruppe.
+    ///
+    /// # Argumente
+    /// * `a` - Das erste Element.
+    /// * `b` - Das zweite Element.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// Das Element `a * b`.
+    fn combine(
+        &self,
+        a: &Self::Element,
+        b: &Self::Element,
+        service: &NumberTheoryService,
+    ) -> Result<Self::Element>;
+
+    /// Kodiert ein Element eindeutig als Zeichenkette, damit es gehasht werden kann.
+    fn encode(&self, element: &Self::Element) -> String;
+}
+
+/// Die Untergruppe der Ordnung `q` von `Z_p^*`, beschrieben durch DSA-Domain-Parameter.
+impl SchnorrGroup for DsaDomainParameters {
+    type Element = BigInt;
+
+    fn order(&self) -> &BigInt {
+        &self.q
+    }
+
+    fn generator(&self) -> &BigInt {
+        &self.g
+    }
+
+    fn exponentiate(
+        &self,
+        base: &BigInt,
+        exponent: &BigInt,
+        service: &NumberTheoryService,
+    ) -> Result<BigInt> {
+        Ok(service.fast_exponentiation(base, exponent, &self.p))
+    }
+
+    fn combine(&self, a: &BigInt, b: &BigInt, _service: &NumberTheoryService) -> Result<BigInt> {
+        Ok((a * b) % &self.p)
+    }
+
+    fn encode(&self, element: &BigInt) -> String {
+        element.to_str_radix(10)
+    }
+}
+
+/// Die vom Generator erzeugte Untergruppe der Ordnung `q` einer sicheren elliptischen Kurve.
+impl SchnorrGroup for SecureFiniteFieldEllipticCurve {
+    type Element = FiniteFieldEllipticCurvePoint;
+
+    fn order(&self) -> &BigInt {
+        &self.order_of_subgroup
+    }
+
+    fn generator(&self) -> &FiniteFieldEllipticCurvePoint {
+        &self.generator
+    }
+
+    fn exponentiate(
+        &self,
+        base: &FiniteFieldEllipticCurvePoint,
+        exponent: &BigInt,
+        service: &NumberTheoryService,
+    ) -> Result<FiniteFieldEllipticCurvePoint> {
+        base.multiply(exponent, self, service)
+    }
+
+    /// Verwendet die Montgomery-Leiter, deren Ablauf nicht von den Bits des Skalars abhängt.
+    fn exponentiate_secret(
+        &self,
+        base: &FiniteFieldEllipticCurvePoint,
+        exponent: &BigInt,
+        service: &NumberTheoryService,
+    ) -> Result<FiniteFieldEllipticCurvePoint> {
+        base.multiply_secret(exponent, self, service)
+    }
+
+    fn combine(
+        &self,
+        a: &FiniteFieldEllipticCurvePoint,
+        b: &FiniteFieldEllipticCurvePoint,
+        service: &NumberTheoryService,
+    ) -> Result<FiniteFieldEllipticCurvePoint> {
+        a.add(b, self, service)
+    }
+
+    fn encode(&self, element: &FiniteFieldEllipticCurvePoint) -> String {
+        element.to_string()
+    }
+}
@@ -0,0 +1,394 @@
+use crate::encryption::asymmetric_encryption_types::{
+    AsymmetricEncryptionScheme, Signer, Verifier,
+};
+use crate::encryption::core::schnorr::keys::{SchnorrKeyPair, SchnorrPrivateKey, SchnorrPublicKey};
+use crate::encryption::core::schnorr::schnorr_group::SchnorrGroup;
+use crate::encryption::encryption_types::EncryptionScheme;
+use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+use crate::math_core::traits::increment::Increment;
+use crate::shared::errors::SchnorrError;
+use crate::shared::hashing::sha256;
+use anyhow::{ensure, Context, Result};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::{One, Zero};
+use log::debug;
+use std::marker::PhantomData;
+
+/// Das Schnorr-Signaturverfahren über einer beliebigen Gruppe primer Ordnung.
+///
+/// Die Signatur entsteht per Fiat-Shamir-Heuristik aus dem interaktiven
+/// Identifikationsprotokoll: Die Challenge `e` wird nicht vom Verifizierer gewählt, sondern
+/// als Hashwert über das Commitment `R` und die Nachricht berechnet.
+pub struct SchnorrScheme<G: SchnorrGroup> {
+    _group: PhantomData<G>,
+}
+
+/// Eine Schnorr-Signatur, bestehend aus der Challenge `e = H(R || m) mod q` und der
+/// Antwort `s = k + x * e mod q`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchnorrSignature {
+    pub e: BigInt,
+    pub s: BigInt,
+}
+
+/// Das Commitment des Beweisers im Identifikationsprotokoll.
+///
+/// # Felder
+/// * `k` - Die geheime Zufallszahl, die nur dem Beweiser bekannt sein darf.
+/// * `r` - Das an den Verifizierer gesendete Element `R = g^k`.
+#[derive(Clone, Debug)]
+pub struct SchnorrCommitment<G: SchnorrGroup> {
+    pub k: BigInt,
+    pub r: G::Element,
+}
+
+impl<G: SchnorrGroup> EncryptionScheme for SchnorrScheme<G> {}
+
+impl<G: SchnorrGroup> AsymmetricEncryptionScheme for SchnorrScheme<G> {}
+
+impl<G: SchnorrGroup> SchnorrScheme<G> {
+    /// Generiert ein Schlüsselpaar für eine gegebene Gruppe.
+    /// Der Geheimwert `x` wird zufällig aus `[1, q - 1]` gewählt.
+    ///
+    /// # Argumente
+    /// * `group` - Die Gruppe primer Ordnung.
+    /// * `service` - Der Service für die Zahlentheorie und die Zufallszahlen.
+    ///
+    /// # Rückgabe
+    /// * `SchnorrKeyPair` - Das Schlüsselpaar.
+    ///
+    /// # Fehler
+    /// * `SchnorrError::InvalidGroupOrderError` - Falls die Ordnung `q` kleiner als 2 ist.
+    pub fn generate_keypair(
+        group: &G,
+        service: &NumberTheoryWithPrngService,
+    ) -> Result<SchnorrKeyPair<G>> {
+        ensure!(
+            group.order() > &BigInt::one(),
+            SchnorrError::InvalidGroupOrderError(group.order().to_string())
+        );
+        let x = service.take_random_number_in_range(&BigInt::one(), &group.order().decrement());
+        let y = group
+            .exponentiate_secret(group.generator(), &x, &service.number_theory_service)
+            .context("Failed to calculate public key y")?;
+
+        Ok(SchnorrKeyPair {
+            public_key: SchnorrPublicKey {
+                group: group.clone(),
+                y,
+            },
+            private_key: SchnorrPrivateKey {
+                group: group.clone(),
+                x,
+            },
+        })
+    }
+
+    /// Erster Schritt des Identifikationsprotokolls: Der Beweiser wählt eine Zufallszahl
+    /// `k` aus `[1, q - 1]` und schickt `R = g^k` an den Verifizierer.
+    ///
+    /// # Argumente
+    /// * `group` - Die Gruppe primer Ordnung.
+    /// * `service` - Der Service für die Zahlentheorie und die Zufallszahlen.
+    ///
+    /// # Rückgabe
+    /// * `SchnorrCommitment` - Das Commitment inklusive der geheimen Zufallszahl.
+    ///
+    /// # Fehler
+    /// * `SchnorrError::InvalidGroupOrderError` - Falls die Ordnung `q` kleiner als 2 ist.
+    pub fn commit(
+        group: &G,
+        service: &NumberTheoryWithPrngService,
+    ) -> Result<SchnorrCommitment<G>> {
+        ensure!(
+            group.order() > &BigInt::one(),
+            SchnorrError::InvalidGroupOrderError(group.order().to_string())
+        );
+        let k = service.take_random_number_in_range(&BigInt::one(), &group.order().decrement());
+        let r = group
+            .exponentiate_secret(group.generator(), &k, &service.number_theory_service)
+            .context("Failed to calculate commitment R")?;
+        Ok(SchnorrCommitment { k, r })
+    }
+
+    /// Zweiter Schritt des Identifikationsprotokolls: Der Verifizierer wählt eine zufällige
+    /// Challenge `e` aus `[0, q - 1]`.
+    ///
+    /// # Argumente
+    /// * `group` - Die Gruppe primer Ordnung.
+    /// * `service` - Der Service für die Zufallszahlen.
+    ///
+    /// # Rückgabe
+    /// Die Challenge `e`.
+    pub fn challenge(group: &G, service: &NumberTheoryWithPrngService) -> BigInt {
+        service.take_random_number_in_range(&BigInt::zero(), &group.order().decrement())
+    }
+
+    /// Dritter Schritt des Identifikationsprotokolls: Der Beweiser antwortet mit
+    /// `s = k + x * e mod q`.
+    ///
+    /// # Argumente
+    /// * `key` - Der private Schlüssel des Beweisers.
+    /// * `k` - Die geheime Zufallszahl aus dem Commitment.
+    /// * `e` - Die Challenge des Verifizierers.
+    ///
+    /// # Rückgabe
+    /// Die Antwort `s`.
+    pub fn respond(key: &SchnorrPrivateKey<G>, k: &BigInt, e: &BigInt) -> BigInt {
+        (k + &key.x * e) % key.group.order()
+    }
+
+    /// Letzter Schritt des Identifikationsprotokolls: Der Verifizierer prüft, ob
+    /// `g^s = R * y^e` gilt.
+    ///
+    /// # Argumente
+    /// * `key` - Der öffentliche Schlüssel des Beweisers.
+    /// * `r` - Das Commitment `R`.
+    /// * `e` - Die Challenge.
+    /// * `s` - Die Antwort des Beweisers.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// `true`, wenn sich der Beweiser erfolgreich identifiziert hat, ansonsten `false`.
+    pub fn verify_response(
+        key: &SchnorrPublicKey<G>,
+        r: &G::Element,
+        e: &BigInt,
+        s: &BigInt,
+        service: &NumberTheoryWithPrngService,
+    ) -> Result<bool> {
+        let group = &key.group;
+        let q = group.order();
+        if e < &BigInt::zero() || e >= q || s < &BigInt::zero() || s >= q {
+            return Ok(false);
+        }
+        let service = &service.number_theory_service;
+
+        let left = group.exponentiate(group.generator(), s, service)?;
+        let y_to_e = group.exponentiate(&key.y, e, service)?;
+        let right = group.combine(r, &y_to_e, service)?;
+        Ok(left == right)
+    }
+
+    /// Berechnet die Fiat-Shamir-Challenge `e = SHA256(R || m) mod q`.
+    /// Das Trennzeichen verhindert, dass Kodierung und Nachricht ineinander verschoben werden.
+    fn fiat_shamir_challenge(group: &G, r: &G::Element, message: &str) -> BigInt {
+        let data = format!("{}|{}", group.encode(r), message);
+        sha256(&data) % group.order()
+    }
+}
+
+impl<G: SchnorrGroup> Signer<SchnorrScheme<G>> for SchnorrScheme<G> {
+    type Input = str;
+    type Output = Result<SchnorrSignature>;
+    type Key = SchnorrPrivateKey<G>;
+
+    /// Signiert eine Nachricht mit dem privaten Schnorr-Schlüssel.
+    ///
+    /// # Argumente
+    /// * `key` - Der private Schlüssel.
+    /// * `message` - Die zu signierende Nachricht.
+    /// * `service` - Der Service für die Zahlentheorie und die Zufallszahlen.
+    ///
+    /// # Rückgabe
+    /// Die Signatur `(e, s)`.
+    fn sign(
+        key: &Self::Key,
+        message: &Self::Input,
+        service: &NumberTheoryWithPrngService,
+    ) -> Self::Output {
+        let group = &key.group;
+        let commitment = SchnorrScheme::commit(group, service)?;
+        let e = SchnorrScheme::fiat_shamir_challenge(group, &commitment.r, message);
+        let s = SchnorrScheme::respond(key, &commitment.k, &e);
+        debug!("Schnorr: Signatur mit e = {} und s = {}", e, s);
+        Ok(SchnorrSignature { e, s })
+    }
+}
+
+impl<G: SchnorrGroup> Verifier<SchnorrScheme<G>> for SchnorrScheme<G> {
+    type Signature = SchnorrSignature;
+    type Message = str;
+    type Output = Result<bool>;
+    type Key = SchnorrPublicKey<G>;
+
+    /// Überprüft eine Schnorr-Signatur, indem `R' = g^s * y^(q - e)` rekonstruiert und
+    /// `e = H(R' || m) mod q` geprüft wird.
+    ///
+    /// # Argumente
+    /// * `key` - Der öffentliche Schlüssel.
+    /// * `signature` - Die Signatur.
+    /// * `message` - Die Nachricht.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// `true`, wenn die Signatur gültig ist, ansonsten `false`.
+    fn verify(
+        key: &Self::Key,
+        signature: &Self::Signature,
+        message: &Self::Message,
+        service: &NumberTheoryWithPrngService,
+    ) -> Self::Output {
+        let group = &key.group;
+        let q = group.order();
+        let SchnorrSignature { e, s } = signature;
+        if e < &BigInt::zero() || e >= q || s < &BigInt::zero() || s >= q {
+            return Ok(false);
+        }
+        let service = &service.number_theory_service;
+
+        // y^(q - e) ist das Inverse von y^e, weil y in der Untergruppe der Ordnung q liegt.
+        let g_to_s = group.exponentiate(group.generator(), s, service)?;
+        let y_to_minus_e = group.exponentiate(&key.y, &(q - e), service)?;
+        let r = group.combine(&g_to_s, &y_to_minus_e, service)?;
+
+        Ok(&SchnorrScheme::fiat_shamir_challenge(group, &r, message) == e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::core::dsa::domain_parameters::DsaDomainParameters;
+    use crate::math_core::ecc::secure_finite_field_elliptic_curve::SecureFiniteFieldEllipticCurve;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
+        Fast, Slow,
+    };
+
+    fn run_test_for_all_services(test: impl Fn(NumberTheoryWithPrngService)) {
+        test(NumberTheoryWithPrngService::new(Slow, 13)); // Langsame, eigene Implementierung
+        test(NumberTheoryWithPrngService::new(Fast, 13)); // Schnelle, externe Implementierung
+    }
+
+    fn prime_field_group(service: &NumberTheoryWithPrngService) -> DsaDomainParameters {
+        DsaDomainParameters::generate(256, 64, 20, service).unwrap()
+    }
+
+    fn elliptic_curve_group(
+        service: &NumberTheoryWithPrngService,
+    ) -> SecureFiniteFieldEllipticCurve {
+        SecureFiniteFieldEllipticCurve::new(5, 32, 40, service).unwrap()
+    }
+
+    fn sign_verify_assert<G: SchnorrGroup>(group: &G, service: &NumberTheoryWithPrngService) {
+        let key_pair = SchnorrScheme::generate_keypair(group, service).unwrap();
+        let message = "Das ist eine Testnachricht für Schnorr!";
+
+        let signature = SchnorrScheme::sign(&key_pair.private_key, message, service).unwrap();
+        assert!(SchnorrScheme::verify(&key_pair.public_key, &signature, message, service).unwrap());
+        assert!(!SchnorrScheme::verify(
+            &key_pair.public_key,
+            &signature,
+            "Andere Nachricht",
+            service
+        )
+        .unwrap());
+
+        let manipulated = SchnorrSignature {
+            e: signature.e.clone(),
+            s: (&signature.s + 1) % group.order(),
+        };
+        assert!(
+            !SchnorrScheme::verify(&key_pair.public_key, &manipulated, message, service).unwrap()
+        );
+    }
+
+    fn identification_assert<G: SchnorrGroup>(group: &G, service: &NumberTheoryWithPrngService) {
+        let key_pair = SchnorrScheme::generate_keypair(group, service).unwrap();
+
+        let commitment = SchnorrScheme::commit(group, service).unwrap();
+        let e = SchnorrScheme::challenge(group, service);
+        let s = SchnorrScheme::respond(&key_pair.private_key, &commitment.k, &e);
+        assert!(SchnorrScheme::verify_response(
+            &key_pair.public_key,
+            &commitment.r,
+            &e,
+            &s,
+            service
+        )
+        .unwrap());
+
+        // Ein Betrüger ohne Kenntnis von x kann die Challenge nicht beantworten.
+        let impostor = SchnorrPrivateKey {
+            group: group.clone(),
+            x: (&key_pair.private_key.x + 1) % group.order(),
+        };
+        let e = BigInt::one();
+        let s = SchnorrScheme::respond(&impostor, &commitment.k, &e);
+        assert!(!SchnorrScheme::verify_response(
+            &key_pair.public_key,
+            &commitment.r,
+            &e,
+            &s,
+            service
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn test_sign_verify_prime_field() {
+        run_test_for_all_services(|service| {
+            sign_verify_assert(&prime_field_group(&service), &service);
+        });
+    }
+
+    #[test]
+    fn test_sign_verify_elliptic_curve() {
+        run_test_for_all_services(|service| {
+            sign_verify_assert(&elliptic_curve_group(&service), &service);
+        });
+    }
+
+    #[test]
+    fn test_secret_exponentiation_matches_public() {
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        let group = elliptic_curve_group(&service);
+        let service = &service.number_theory_service;
+        let q = group.order();
+        for exponent in [BigInt::one(), BigInt::from(2), q - 1, q.clone(), q + 5] {
+            assert_eq!(
+                group
+                    .exponentiate_secret(group.generator(), &exponent, service)
+                    .unwrap(),
+                group
+                    .exponentiate(group.generator(), &exponent, service)
+                    .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_identification_prime_field() {
+        run_test_for_all_services(|service| {
+            identification_assert(&prime_field_group(&service), &service);
+        });
+    }
+
+    #[test]
+    fn test_identification_elliptic_curve() {
+        run_test_for_all_services(|service| {
+            identification_assert(&elliptic_curve_group(&service), &service);
+        });
+    }
+
+    #[test]
+    fn test_verify_rejects_out_of_range_values() {
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        let group = prime_field_group(&service);
+        let key_pair = SchnorrScheme::generate_keypair(&group, &service).unwrap();
+        let signature = SchnorrSignature {
+            e: group.q.clone(),
+            s: BigInt::one(),
+        };
+        assert!(!SchnorrScheme::verify(&key_pair.public_key, &signature, "m", &service).unwrap());
+    }
+
+    #[test]
+    fn test_rejects_group_without_secrets() {
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        let mut group = prime_field_group(&service);
+        group.q = BigInt::one();
+        assert!(SchnorrScheme::generate_keypair(&group, &service).is_err());
+        assert!(SchnorrScheme::commit(&group, &service).is_err());
+    }
+}
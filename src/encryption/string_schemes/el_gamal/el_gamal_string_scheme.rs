@@ -0,0 +1,256 @@
+use crate::encryption::asymmetric_encryption_types::{
+    AsymmetricDecryptor, AsymmetricEncryptionScheme, AsymmetricEncryptor,
+};
+use crate::encryption::core::el_gamal::el_gamal_scheme::ElGamalScheme;
+use crate::encryption::encryption_types::{Decryptor, EncryptionScheme, Encryptor};
+use crate::encryption::string_schemes::decimal_unicode_schemes::from_decimal_block_scheme::FromDecimalBlockScheme;
+use crate::encryption::string_schemes::decimal_unicode_schemes::keys::DecimalUnicodeConversionSchemeKey;
+use crate::encryption::string_schemes::decimal_unicode_schemes::to_decimal_block_scheme::ToDecimalBlockScheme;
+use crate::encryption::string_schemes::el_gamal::keys::{
+    ElGamalStringPrivateKey, ElGamalStringPublicKey,
+};
+use crate::encryption::symmetric_encryption_types::{SymmetricDecryptor, SymmetricEncryptor};
+use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+use crate::math_core::traits::logarithm::Logarithm;
+use crate::shared::errors::ElGamalError;
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+
+/// Verschlüsselt beliebig lange Zeichenketten mit dem ElGamal-Kryptosystem.
+///
+/// Der Klartext wird unter einer Decimal-Unicode-Abbildung in Blöcke der Größe `log_radix(p)`
+/// aufgeteilt, sodass jeder Block kleiner als `p` ist. Jeder Block wird einzeln zu einem Paar
+/// `(a, b)` verschlüsselt. Der Chiffretext ist die Folge `a_1, b_1, a_2, b_2, ...`, die
+/// mit der Blockgröße `log_radix(p) + 1` wieder in eine Zeichenkette zur Basis `radix`
+/// abgebildet wird.
+pub struct ElGamalStringScheme {}
+
+impl EncryptionScheme for ElGamalStringScheme {}
+
+impl AsymmetricEncryptionScheme for ElGamalStringScheme {}
+
+impl Encryptor<ElGamalStringScheme> for ElGamalStringScheme {
+    type Input = str;
+    type Output = Result<String>;
+    type Key = ElGamalStringPublicKey;
+}
+
+impl AsymmetricEncryptor<ElGamalStringScheme> for ElGamalStringScheme {
+    /// Verschlüsselt eine beliebig lange Zeichenkette blockweise mit ElGamal.
+    ///
+    /// # Argumente
+    /// * `key` - Der zu verwendende Schlüssel.
+    /// * `plaintext` - Der zu verschlüsselnde Klartext.
+    /// * `service` - Der zu verwendende NumberTheoryService.
+    ///
+    /// # Rückgabe
+    /// * `String` - Die verschlüsselten Paare `(a, b)` als Zeichenkette.
+    ///
+    /// # Fehler
+    /// * `ElGamalError::ModulusTooSmallError` - Falls `p` kleiner als `radix` ist.
+    fn encrypt(
+        key: &Self::Key,
+        plaintext: &Self::Input,
+        service: &NumberTheoryWithPrngService,
+    ) -> Self::Output {
+        let radix = key.radix;
+        let p = &key.el_gamal_key.p;
+        let block_size = p.log(&radix.into());
+        ensure!(
+            block_size > 0,
+            ElGamalError::ModulusTooSmallError(p.to_string(), radix)
+        );
+
+        let pre_key = DecimalUnicodeConversionSchemeKey { radix, block_size };
+        let chunks = ToDecimalBlockScheme::encrypt(plaintext, &pre_key);
+
+        let encrypted_chunks: Vec<BigInt> = chunks
+            .iter()
+            .flat_map(|chunk| {
+                let (a, b) = ElGamalScheme::encrypt(&key.el_gamal_key, chunk, service);
+                [a, b]
+            })
+            .collect();
+
+        // Die Werte a und b sind kleiner als p und passen daher in Blöcke der Größe + 1.
+        let post_key = DecimalUnicodeConversionSchemeKey {
+            radix,
+            block_size: block_size + 1,
+        };
+        Ok(FromDecimalBlockScheme::encrypt(
+            &encrypted_chunks,
+            &post_key,
+        ))
+    }
+}
+
+impl Decryptor<ElGamalStringScheme> for ElGamalStringScheme {
+    type Input = str;
+    type Output = Result<String>;
+    type Key = ElGamalStringPrivateKey;
+}
+
+impl AsymmetricDecryptor<ElGamalStringScheme> for ElGamalStringScheme {
+    /// Entschlüsselt eine mit `encrypt` erzeugte Zeichenkette.
+    ///
+    /// # Argumente
+    /// * `key` - Der zu verwendende Schlüssel.
+    /// * `ciphertext` - Die verschlüsselten Paare `(a, b)` als Zeichenkette.
+    /// * `service` - Der zu verwendende NumberTheoryService.
+    ///
+    /// # Rückgabe
+    /// * `String` - Die entschlüsselte Nachricht.
+    ///
+    /// # Fehler
+    /// * `ElGamalError::ModulusTooSmallError` - Falls `p` kleiner als `radix` ist.
+    /// * `ElGamalError::InvalidCiphertextLengthError` - Falls die Anzahl der Blöcke ungerade ist.
+    fn decrypt(
+        key: &Self::Key,
+        ciphertext: &Self::Input,
+        service: &NumberTheoryWithPrngService,
+    ) -> Self::Output {
+        let radix = key.radix;
+        let p = &key.el_gamal_key.p;
+        let block_size = p.log(&radix.into());
+        ensure!(
+            block_size > 0,
+            ElGamalError::ModulusTooSmallError(p.to_string(), radix)
+        );
+
+        let unicode_conversion_key = DecimalUnicodeConversionSchemeKey {
+            radix,
+            block_size: block_size + 1,
+        };
+        let chunks = FromDecimalBlockScheme::decrypt(ciphertext, &unicode_conversion_key);
+        ensure!(
+            chunks.len() % 2 == 0,
+            ElGamalError::InvalidCiphertextLengthError(chunks.len())
+        );
+
+        let decrypted_chunks = chunks
+            .chunks(2)
+            .map(|pair| {
+                ElGamalScheme::decrypt(
+                    &key.el_gamal_key,
+                    &(pair[0].clone(), pair[1].clone()),
+                    service,
+                )
+            })
+            .collect();
+
+        Ok(ToDecimalBlockScheme::decrypt(
+            &decrypted_chunks,
+            &unicode_conversion_key,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::asymmetric_encryption_types::KeyGenerator;
+    use crate::encryption::core::el_gamal::el_gamal_scheme::ElGamalKeyGenConfig;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
+        Fast, Slow,
+    };
+
+    fn run_test_for_all_services(test: impl Fn(NumberTheoryWithPrngService)) {
+        test(NumberTheoryWithPrngService::new(Slow, 13)); // Langsame, eigene Implementierung
+        test(NumberTheoryWithPrngService::new(Fast, 13)); // Schnelle, externe Implementierung
+    }
+
+    /// Generiert ein ElGamal-Schlüsselpaar und ergänzt beide Schlüssel um die Basis `radix`.
+    fn generate_key_pair(
+        modulus_width: u32,
+        random_seed: u32,
+        radix: u32,
+    ) -> (ElGamalStringPublicKey, ElGamalStringPrivateKey) {
+        let config = ElGamalKeyGenConfig {
+            modulus_width,
+            miller_rabin_iterations: 30,
+            random_seed,
+            number_theory_service: NumberTheoryService::new(Fast),
+        };
+        let key_pair = ElGamalScheme::generate_keypair(&config).unwrap();
+        (
+            ElGamalStringPublicKey {
+                el_gamal_key: key_pair.public_key,
+                radix,
+            },
+            ElGamalStringPrivateKey {
+                el_gamal_key: key_pair.private_key,
+                radix,
+            },
+        )
+    }
+
+    /// Diese Methode führt die Verschlüsselung und Entschlüsselung einer Nachricht durch und
+    /// prüft, ob die ursprüngliche Nachricht wiederhergestellt werden kann.
+    fn encryption_decryption_assert(
+        public_key: &ElGamalStringPublicKey,
+        private_key: &ElGamalStringPrivateKey,
+        message: &str,
+        service: &NumberTheoryWithPrngService,
+    ) {
+        let ciphertext = ElGamalStringScheme::encrypt(public_key, message, service).unwrap();
+        let decrypted = ElGamalStringScheme::decrypt(private_key, &ciphertext, service).unwrap();
+        assert_eq!(message, decrypted);
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_happy_flow() {
+        run_test_for_all_services(|service| {
+            let (public_key, private_key) = generate_key_pair(256, 23, 55296);
+            let message = "Das ist eine ganz interessante Testnachricht mit Ümläuten und 苉!";
+            encryption_decryption_assert(&public_key, &private_key, message, &service);
+        });
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_small_modulus_and_empty_string() {
+        run_test_for_all_services(|service| {
+            let (public_key, private_key) = generate_key_pair(40, 24, 256);
+            encryption_decryption_assert(&public_key, &private_key, "Kurzer Text", &service);
+            encryption_decryption_assert(&public_key, &private_key, "", &service);
+        });
+    }
+
+    #[test]
+    fn test_ciphertext_layout_consists_of_pairs() {
+        let (public_key, _) = generate_key_pair(64, 25, 256);
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        let p = &public_key.el_gamal_key.p;
+        let block_size = p.log(&256.into());
+
+        let message = "Zwölf Zeichen";
+        let ciphertext = ElGamalStringScheme::encrypt(&public_key, message, &service).unwrap();
+
+        let plaintext_blocks = message.chars().count().div_ceil(block_size);
+        assert_eq!(
+            ciphertext.chars().count(),
+            2 * plaintext_blocks * (block_size + 1)
+        );
+    }
+
+    #[test]
+    fn test_decrypt_rejects_odd_block_count() {
+        let (public_key, private_key) = generate_key_pair(64, 26, 256);
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        let ciphertext = ElGamalStringScheme::encrypt(&public_key, "Text", &service).unwrap();
+
+        let block_size = public_key.el_gamal_key.p.log(&256.into()) + 1;
+        let truncated: String = ciphertext
+            .chars()
+            .take(ciphertext.chars().count() - block_size)
+            .collect();
+        assert!(ElGamalStringScheme::decrypt(&private_key, &truncated, &service).is_err());
+    }
+
+    #[test]
+    fn test_encrypt_rejects_too_small_modulus() {
+        let (public_key, _) = generate_key_pair(8, 27, 55296);
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        assert!(ElGamalStringScheme::encrypt(&public_key, "Text", &service).is_err());
+    }
+}
@@ -0,0 +1,34 @@
+use crate::encryption::asymmetric_encryption_types::{
+    AsymmetricDecryptionKey, AsymmetricEncryptionKey, AsymmetricKey, PrivateKey, PublicKey,
+};
+use crate::encryption::core::el_gamal::keys::{ElGamalPrivateKey, ElGamalPublicKey};
+use crate::encryption::encryption_types::Key;
+use crate::encryption::string_schemes::el_gamal::el_gamal_string_scheme::ElGamalStringScheme;
+
+#[derive(Clone, Debug)]
+pub struct ElGamalStringPublicKey {
+    pub el_gamal_key: ElGamalPublicKey,
+    pub radix: u32,
+}
+
+impl Key<ElGamalStringScheme> for ElGamalStringPublicKey {}
+
+impl AsymmetricKey<ElGamalStringScheme> for ElGamalStringPublicKey {}
+
+impl PublicKey<ElGamalStringScheme> for ElGamalStringPublicKey {}
+
+impl AsymmetricEncryptionKey<ElGamalStringScheme> for ElGamalStringPublicKey {}
+
+#[derive(Clone, Debug)]
+pub struct ElGamalStringPrivateKey {
+    pub el_gamal_key: ElGamalPrivateKey,
+    pub radix: u32,
+}
+
+impl Key<ElGamalStringScheme> for ElGamalStringPrivateKey {}
+
+impl AsymmetricKey<ElGamalStringScheme> for ElGamalStringPrivateKey {}
+
+impl PrivateKey<ElGamalStringScheme> for ElGamalStringPrivateKey {}
+
+impl AsymmetricDecryptionKey<ElGamalStringScheme> for ElGamalStringPrivateKey {}
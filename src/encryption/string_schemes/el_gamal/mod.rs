@@ -0,0 +1,2 @@
+pub mod el_gamal_string_scheme;
+pub mod keys;
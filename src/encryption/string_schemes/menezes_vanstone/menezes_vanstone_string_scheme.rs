@@ -13,6 +13,7 @@ use crate::encryption::string_schemes::menezes_vanstone::keys::{
     MenezesVanstoneStringKeyPair, MenezesVanstoneStringPrivateKey, MenezesVanstoneStringPublicKey,
 };
 use crate::encryption::symmetric_encryption_types::{SymmetricDecryptor, SymmetricEncryptor};
+use crate::math_core::ecc::elliptic_curve::EllipticCurve;
 use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
 use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
 use crate::math_core::traits::logarithm::Logarithm;
@@ -34,12 +35,19 @@ pub struct MvStringCiphertext {
     pub points: Vec<FiniteFieldEllipticCurvePoint>,
 }
 
-impl From<MvCipherTextBean> for MvStringCiphertext {
-    fn from(ciphertext: MvCipherTextBean) -> Self {
-        MvStringCiphertext {
+impl MvStringCiphertext {
+    /// Mapped die Bean in das Domain-Modell. Punkte in SEC1-Kodierung werden dabei auf der
+    /// gegebenen Kurve dekodiert.
+    pub fn from_bean<C: EllipticCurve>(ciphertext: MvCipherTextBean, curve: &C) -> Result<Self> {
+        let points = ciphertext
+            .points
+            .iter()
+            .map(|point| point.to_point(curve))
+            .collect::<Result<_>>()?;
+        Ok(MvStringCiphertext {
             ciphertext: ciphertext.encrypted_message,
-            points: ciphertext.points.into_iter().map(Into::into).collect(),
-        }
+            points,
+        })
     }
 }
 
@@ -1,3 +1,4 @@
 pub mod decimal_unicode_schemes;
+pub mod el_gamal;
 pub mod menezes_vanstone;
 pub mod rsa;
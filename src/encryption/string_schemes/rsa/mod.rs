@@ -1,2 +1,3 @@
 pub mod keys;
+pub mod rsa_oaep_with_string_scheme;
 pub mod rsa_with_string_scheme;
@@ -0,0 +1,166 @@
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+
+use crate::encryption::asymmetric_encryption_types::{AsymmetricDecryptor, AsymmetricEncryptor};
+use crate::encryption::core::rsa::data_conversion::octet_length;
+use crate::encryption::core::rsa::rsa_oaep_scheme::RsaOaepScheme;
+use crate::encryption::encryption_types::{Decryptor, Encryptor};
+use crate::encryption::string_schemes::decimal_unicode_schemes::from_decimal_block_scheme::FromDecimalBlockScheme;
+use crate::encryption::string_schemes::decimal_unicode_schemes::keys::DecimalUnicodeConversionSchemeKey;
+use crate::encryption::string_schemes::rsa::keys::{
+    RsaWithStringPrivateKey, RsaWithStringPublicKey,
+};
+use crate::encryption::string_schemes::rsa::rsa_with_string_scheme::RsaWithStringScheme;
+use crate::encryption::symmetric_encryption_types::{SymmetricDecryptor, SymmetricEncryptor};
+use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+use crate::math_core::traits::logarithm::Logarithm;
+use crate::shared::errors::RsaError;
+
+/// Verschlüsselt Zeichenketten mit RSA-OAEP.
+/// Die UTF-8-Bytes der Nachricht werden in Blöcke der maximal erlaubten OAEP-Nachrichtenlänge
+/// aufgeteilt, einzeln mit `RsaOaepScheme` verschlüsselt und die Chiffretexte anschließend wie
+/// beim `RsaWithStringScheme` unter einer Decimal-Unicode-Abbildung zu einem String zusammengefügt.
+pub struct RsaOaepWithStringScheme {}
+
+impl Encryptor<RsaWithStringScheme> for RsaOaepWithStringScheme {
+    type Input = str;
+    type Output = Result<String>;
+    type Key = RsaWithStringPublicKey;
+}
+
+impl AsymmetricEncryptor<RsaWithStringScheme> for RsaOaepWithStringScheme {
+    /// Verschlüsselt eine beliebig lange Zeichenkette mit RSA-OAEP.
+    ///
+    /// # Argumente
+    /// * `key` - Der zu verwendende Schlüssel.
+    /// * `plaintext` - Der zu verschlüsselnde Klartext.
+    /// * `service` - Der zu verwendende NumberTheoryService.
+    ///
+    /// # Rückgabe
+    /// * `String` - Die verschlüsselte Nachricht.
+    ///
+    /// # Fehler
+    /// * `RsaError::ModulusTooShortError` - Falls der Modulus für OAEP zu klein ist.
+    fn encrypt(
+        key: &Self::Key,
+        plaintext: &Self::Input,
+        service: &NumberTheoryWithPrngService,
+    ) -> Self::Output {
+        let rsa_key = &key.rsa_public_key;
+        let max_length = RsaOaepScheme::max_message_length(&rsa_key.n).unwrap_or(0);
+        ensure!(
+            max_length > 0,
+            RsaError::ModulusTooShortError(octet_length(&rsa_key.n))
+        );
+
+        let encrypted_chunks = plaintext
+            .as_bytes()
+            .chunks(max_length)
+            .map(|chunk| RsaOaepScheme::encrypt(rsa_key, chunk, service))
+            .collect::<Result<Vec<BigInt>>>()?;
+
+        let radix = key.radix;
+        let conversion_key = DecimalUnicodeConversionSchemeKey {
+            radix,
+            block_size: rsa_key.n.log(&radix.into()) + 1,
+        };
+        Ok(FromDecimalBlockScheme::encrypt(
+            &encrypted_chunks,
+            &conversion_key,
+        ))
+    }
+}
+
+impl Decryptor<RsaWithStringScheme> for RsaOaepWithStringScheme {
+    type Input = str;
+    type Output = Result<String>;
+    type Key = RsaWithStringPrivateKey;
+}
+
+impl AsymmetricDecryptor<RsaWithStringScheme> for RsaOaepWithStringScheme {
+    /// Entschlüsselt eine mit `encrypt` verschlüsselte Zeichenkette.
+    ///
+    /// # Argumente
+    /// * `key` - Der zu verwendende Schlüssel.
+    /// * `ciphertext` - Der zu entschlüsselnde Chiffretext.
+    /// * `service` - Der zu verwendende NumberTheoryService.
+    ///
+    /// # Rückgabe
+    /// * `String` - Die entschlüsselte Nachricht.
+    ///
+    /// # Fehler
+    /// * `RsaError::DecryptionError` - Falls einer der Blöcke ungültig ist.
+    fn decrypt(
+        key: &Self::Key,
+        ciphertext: &Self::Input,
+        service: &NumberTheoryWithPrngService,
+    ) -> Self::Output {
+        let rsa_key = &key.rsa_private_key;
+        let radix = key.radix;
+        let conversion_key = DecimalUnicodeConversionSchemeKey {
+            radix,
+            block_size: rsa_key.n.log(&radix.into()) + 1,
+        };
+
+        let chunks = FromDecimalBlockScheme::decrypt(ciphertext, &conversion_key);
+        let mut plaintext_bytes = Vec::new();
+        for chunk in chunks.iter() {
+            plaintext_bytes.extend(RsaOaepScheme::decrypt(rsa_key, chunk, service)?);
+        }
+
+        String::from_utf8(plaintext_bytes).map_err(|_| RsaError::DecryptionError.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::asymmetric_encryption_types::KeyGenerator;
+    use crate::encryption::core::rsa::rsa_scheme::{RsaKeyGenConfig, RsaScheme};
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+
+    fn generate_keys(key_size: u32) -> (RsaWithStringPublicKey, RsaWithStringPrivateKey) {
+        let config = RsaKeyGenConfig {
+            key_size,
+            miller_rabin_iterations: 30,
+            random_seed: 97,
+            number_theory_service: NumberTheoryService::new(Fast),
+            prime_count: 2,
+        };
+        let key_pair = RsaScheme::generate_keypair(&config).unwrap();
+        let radix = 55296;
+        (
+            RsaWithStringPublicKey {
+                rsa_public_key: key_pair.public_key,
+                radix,
+            },
+            RsaWithStringPrivateKey {
+                rsa_private_key: key_pair.private_key,
+                radix,
+            },
+        )
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_multiple_blocks() {
+        let (public_key, private_key) = generate_keys(1024);
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        // Länger als ein OAEP-Block, damit mehrere Blöcke entstehen.
+        let message = "Das ist eine ganz interessante Testnachricht für OAEP! ".repeat(5);
+
+        let ciphertext = RsaOaepWithStringScheme::encrypt(&public_key, &message, &service).unwrap();
+        let plaintext =
+            RsaOaepWithStringScheme::decrypt(&private_key, &ciphertext, &service).unwrap();
+        assert_eq!(message, plaintext);
+    }
+
+    #[test]
+    fn test_encrypt_with_too_small_modulus_fails() {
+        let (public_key, _) = generate_keys(256);
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+
+        let result = RsaOaepWithStringScheme::encrypt(&public_key, "Hallo", &service);
+        assert!(result.is_err());
+    }
+}
@@ -15,6 +15,7 @@ use crate::encryption::symmetric_encryption_types::{SymmetricDecryptor, Symmetri
 use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
 use crate::math_core::traits::logarithm::Logarithm;
 use crate::shared::hashing::sha256;
+use anyhow::Result;
 
 pub struct RsaWithStringScheme {}
 
@@ -199,8 +200,11 @@ impl<'a> Verifier<RsaWithStringScheme> for RsaWithStringScheme {
 impl RsaWithStringScheme {
     // TODO KeyGenConfig anpassen? Das hier passt nicht mehr ins Muster
 
-    fn generate_keypair(config: &impl KeyGenWithPrimeConfig, radix: u32) -> RsaWithStringKeyPair {
-        let rsa_key_pair = RsaScheme::generate_keypair(config);
+    fn generate_keypair(
+        config: &impl KeyGenWithPrimeConfig,
+        radix: u32,
+    ) -> Result<RsaWithStringKeyPair> {
+        let rsa_key_pair = RsaScheme::generate_keypair(config)?;
 
         let public_key = RsaWithStringPublicKey {
             rsa_public_key: rsa_key_pair.public_key,
@@ -211,10 +215,10 @@ impl RsaWithStringScheme {
             radix,
         };
 
-        RsaWithStringKeyPair {
+        Ok(RsaWithStringKeyPair {
             public_key,
             private_key,
-        }
+        })
     }
 }
 
@@ -243,6 +247,7 @@ mod tests {
                 miller_rabin_iterations: 30,
                 random_seed: 73,
                 number_theory_service: NumberTheoryService::new(Fast),
+                prime_count: 2,
             };
             encryption_decryption_assert(config, message, service);
         });
@@ -258,6 +263,7 @@ mod tests {
                 miller_rabin_iterations: 30,
                 random_seed: 3,
                 number_theory_service: NumberTheoryService::new(Fast),
+                prime_count: 2,
             };
             encryption_decryption_assert(config, message, service);
         });
@@ -273,6 +279,7 @@ mod tests {
                 miller_rabin_iterations: 30,
                 random_seed: 874,
                 number_theory_service: NumberTheoryService::new(Fast),
+                prime_count: 2,
             };
             encryption_decryption_assert(config, message, service);
         });
@@ -286,7 +293,7 @@ mod tests {
         service: NumberTheoryWithPrngService,
     ) {
         let radix = 55296;
-        let key_pair = RsaWithStringScheme::generate_keypair(&config, radix);
+        let key_pair = RsaWithStringScheme::generate_keypair(&config, radix).unwrap();
         let (public_key, private_key) = (&key_pair.public_key, &key_pair.private_key);
 
         let encrypted_message = RsaWithStringScheme::encrypt(public_key, message, &service);
@@ -306,6 +313,7 @@ mod tests {
                 miller_rabin_iterations: 30,
                 random_seed: 653,
                 number_theory_service: NumberTheoryService::new(Fast),
+                prime_count: 2,
             };
             sign_verify_assert(config, 55296, message, service, true);
         });
@@ -322,6 +330,7 @@ mod tests {
                 miller_rabin_iterations: 30,
                 random_seed: 55,
                 number_theory_service: NumberTheoryService::new(Fast),
+                prime_count: 2,
             };
             sign_verify_assert(config, 55296, message, service, true);
         });
@@ -337,6 +346,7 @@ mod tests {
                 miller_rabin_iterations: 30,
                 random_seed: 40,
                 number_theory_service: NumberTheoryService::new(Fast),
+                prime_count: 2,
             };
             sign_verify_assert(config, 55296, message, service, true);
         });
@@ -353,9 +363,10 @@ mod tests {
                 miller_rabin_iterations: 30,
                 random_seed: 17,
                 number_theory_service: NumberTheoryService::new(Fast),
+                prime_count: 2,
             };
             let radix = 55296;
-            let key_pair = RsaWithStringScheme::generate_keypair(&config, radix);
+            let key_pair = RsaWithStringScheme::generate_keypair(&config, radix).unwrap();
             let (public_key, private_key) = (&key_pair.public_key, &key_pair.private_key);
 
             let _radix = 55296;
@@ -375,7 +386,7 @@ mod tests {
         service: NumberTheoryWithPrngService,
         expected: bool,
     ) {
-        let key_pair = RsaWithStringScheme::generate_keypair(&config, radix);
+        let key_pair = RsaWithStringScheme::generate_keypair(&config, radix).unwrap();
         let (public_key, private_key) = (&key_pair.public_key, &key_pair.private_key);
 
         let signature = RsaWithStringScheme::sign(private_key, message, &service);
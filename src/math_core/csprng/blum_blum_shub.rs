@@ -0,0 +1,300 @@
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::{BigInt, Sign};
+use bigdecimal::{One, Zero};
+
+use crate::math_core::csprng::chacha20_rng::ChaCha20Rng;
+use crate::math_core::csprng::{os_entropy, RandomByteGenerator};
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+use crate::math_core::number_theory::number_theory_service::{
+    NumberTheoryService, NumberTheoryServiceTrait,
+};
+use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+use crate::math_core::pseudo_random_number_generator::{PrngBackend, PseudoRandomNumberGenerator};
+use crate::math_core::random_source::PrngRandomSource;
+use crate::shared::errors::BlumBlumShubError;
+
+/// Die minimale Bit-Breite des Modulus für `generate`. Erst ab fünf Bit gibt es mehrere
+/// Blum-Primzahlen derselben Breite.
+pub const MIN_MODULUS_WIDTH: u32 = 16;
+/// Die Bit-Breite des Modulus bei Initialisierung mit Entropie des Betriebssystems oder Seed.
+const DEFAULT_MODULUS_WIDTH: u32 = 1024;
+/// Die Anzahl der Miller-Rabin-Iterationen für die Primzahlen bei Initialisierung mit Entropie
+/// des Betriebssystems oder Seed.
+const DEFAULT_MILLER_RABIN_ITERATIONS: u32 = 40;
+
+///
+/// Der Blum-Blum-Shub-Generator: Zum Modulus `n = p · q` aus zwei Blum-Primzahlen wird der
+/// Zustand fortlaufend quadriert, `x_{i+1} = x_i^2 mod n`, und jeweils das niederwertigste Bit
+/// ausgegeben. Die Vorhersage des nächsten Bits ist so schwer wie die Faktorisierung von `n`,
+/// der Generator ist dafür deutlich langsamer als ChaCha20 oder HMAC_DRBG.
+///
+/// Zu Modulus und Seed ist die Folge reproduzierbar. Damit dient sie auch als Schlüsselstrom
+/// der Stromchiffre `BlumBlumShubScheme`.
+///
+pub struct BlumBlumShub {
+    /// Der Modulus `n = p · q`
+    modulus: BigInt,
+    /// Der aktuelle Zustand `x_i`
+    state: BigInt,
+    number_theory_service: NumberTheoryService,
+}
+
+impl BlumBlumShub {
+    /// Erstellt den Generator zum Modulus und Seed. Der Startwert ist `x_0 = seed^2 mod n`.
+    /// Die Faktorisierung des Modulus wird nicht geprüft, siehe `from_primes`.
+    ///
+    /// # Argumente
+    /// * `modulus` - Der Modulus `n = p · q` aus zwei Blum-Primzahlen.
+    /// * `seed` - Der Seed, teilerfremd zu `n`.
+    /// * `number_theory_service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// * `BlumBlumShub` - Der Generator.
+    ///
+    /// # Fehler
+    /// * `BlumBlumShubError::InvalidSeedError` - Wenn der Seed nicht teilerfremd zu `n` ist
+    ///   oder `x_0` gleich 0 oder 1 ist und die Folge damit konstant wäre.
+    pub fn new(
+        modulus: BigInt,
+        seed: &BigInt,
+        number_theory_service: NumberTheoryService,
+    ) -> Result<Self> {
+        let state = number_theory_service.fast_exponentiation(seed, &BigInt::from(2), &modulus);
+        let is_coprime = number_theory_service
+            .extended_euclid(seed, &modulus)
+            .ggt
+            .is_one();
+        ensure!(
+            is_coprime && !state.is_zero() && !state.is_one(),
+            BlumBlumShubError::InvalidSeedError(seed.to_string(), modulus.to_string())
+        );
+        Ok(BlumBlumShub {
+            modulus,
+            state,
+            number_theory_service,
+        })
+    }
+
+    /// Erstellt den Generator zu den Faktoren des Modulus und dem Seed.
+    ///
+    /// # Argumente
+    /// * `p` - Die erste Blum-Primzahl.
+    /// * `q` - Die zweite Blum-Primzahl, verschieden von `p`.
+    /// * `seed` - Der Seed, teilerfremd zu `p · q`.
+    /// * `number_theory_service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// * `BlumBlumShub` - Der Generator.
+    ///
+    /// # Fehler
+    /// * `BlumBlumShubError::NonBlumPrimesError` - Wenn `p` oder `q` nicht ≡ 3 mod 4 ist oder
+    ///   beide gleich sind. Ob die Faktoren prim sind, wird nicht geprüft.
+    /// * `BlumBlumShubError::InvalidSeedError` - Siehe `new`.
+    pub fn from_primes(
+        p: &BigInt,
+        q: &BigInt,
+        seed: &BigInt,
+        number_theory_service: NumberTheoryService,
+    ) -> Result<Self> {
+        let three = BigInt::from(3);
+        ensure!(
+            p != q && p % 4 == three && q % 4 == three,
+            BlumBlumShubError::NonBlumPrimesError(p.to_string(), q.to_string())
+        );
+        Self::new(p * q, seed, number_theory_service)
+    }
+
+    /// Generiert einen Generator mit zufälligem Modulus und Seed. Die beiden Blum-Primzahlen
+    /// und der Seed stammen aus der Zufallsfolge des Service.
+    ///
+    /// # Argumente
+    /// * `modulus_width` - Die Bit-Breite des Modulus, jede Primzahl hat die halbe Breite.
+    /// * `miller_rabin_iterations` - Die Anzahl der Iterationen für den Miller-Rabin-Test.
+    /// * `service` - Der Service mit der Zufallsfolge.
+    ///
+    /// # Rückgabe
+    /// * `BlumBlumShub` - Der Generator.
+    ///
+    /// # Fehler
+    /// * `BlumBlumShubError::InvalidModulusWidthError` - Wenn die Breite kleiner als
+    ///   `MIN_MODULUS_WIDTH` ist.
+    pub fn generate(
+        modulus_width: u32,
+        miller_rabin_iterations: u32,
+        service: &NumberTheoryWithPrngService,
+    ) -> Result<Self> {
+        ensure!(
+            modulus_width >= MIN_MODULUS_WIDTH,
+            BlumBlumShubError::InvalidModulusWidthError(modulus_width, MIN_MODULUS_WIDTH)
+        );
+        let prime_width = modulus_width / 2;
+        let prime_generator = service.prime_generator();
+        let p = prime_generator.generate_blum_prime(prime_width, miller_rabin_iterations, service);
+        let q = loop {
+            let q =
+                prime_generator.generate_blum_prime(prime_width, miller_rabin_iterations, service);
+            if q != p {
+                break q;
+            }
+        };
+        let modulus = &p * &q;
+        let upper = &modulus - BigInt::from(2);
+        loop {
+            let seed = service.take_random_number_in_range(&BigInt::from(2), &upper);
+            if let Ok(generator) = Self::new(modulus.clone(), &seed, service.number_theory_service)
+            {
+                return Ok(generator);
+            }
+        }
+    }
+
+    /// Gibt den Modulus `n` zurück.
+    pub fn modulus(&self) -> &BigInt {
+        &self.modulus
+    }
+
+    /// Quadriert den Zustand und gibt das niederwertigste Bit des neuen Zustands zurück.
+    pub fn next_bit(&mut self) -> bool {
+        self.state = self.number_theory_service.fast_exponentiation(
+            &self.state,
+            &BigInt::from(2),
+            &self.modulus,
+        );
+        self.state.bit(0)
+    }
+
+    /// Verknüpft die Daten per XOR mit dem Schlüsselstrom. Da XOR selbstinvers ist, ver- und
+    /// entschlüsselt dieselbe Operation.
+    ///
+    /// # Argumente
+    /// * `data` - Die Daten, die an Ort und Stelle überschrieben werden.
+    pub fn apply_keystream(&mut self, data: &mut [u8]) {
+        for byte in data.iter_mut() {
+            *byte ^= self.next_byte();
+        }
+    }
+
+    /// Setzt acht Bits zu einem Byte zusammen, das erste Bit ist das höchstwertige.
+    fn next_byte(&mut self) -> u8 {
+        (0..8).fold(0u8, |byte, _| (byte << 1) | self.next_bit() as u8)
+    }
+}
+
+impl RandomByteGenerator for BlumBlumShub {
+    fn fill_bytes(&mut self, destination: &mut [u8]) {
+        for byte in destination.iter_mut() {
+            *byte = self.next_byte();
+        }
+    }
+
+    /// Die Primzahlen stammen aus einem ChaCha20-Generator, der Seed direkt aus der Entropie
+    /// des Betriebssystems.
+    fn from_os_entropy() -> Self {
+        let service = NumberTheoryWithPrngService::with_backend(Fast, PrngBackend::ChaCha20);
+        // Das unwrap() wird niemals fehlschlagen, weil die Standardbreite gültig ist.
+        let generator = Self::generate(
+            DEFAULT_MODULUS_WIDTH,
+            DEFAULT_MILLER_RABIN_ITERATIONS,
+            &service,
+        )
+        .unwrap();
+        loop {
+            let entropy = os_entropy(DEFAULT_MODULUS_WIDTH as usize / 8);
+            let seed = BigInt::from_bytes_be(Sign::Plus, &entropy) % &generator.modulus;
+            if let Ok(seeded) = Self::new(
+                generator.modulus.clone(),
+                &seed,
+                generator.number_theory_service,
+            ) {
+                return seeded;
+            }
+        }
+    }
+
+    /// Modulus und Startwert stammen aus einem ChaCha20-Generator zum Seed.
+    fn from_seed(seed: u32) -> Self {
+        let prng =
+            PseudoRandomNumberGenerator::with_generator(Box::new(ChaCha20Rng::from_seed(seed)));
+        let service =
+            NumberTheoryWithPrngService::with_random_source(Fast, PrngRandomSource::new(prng));
+        // Das unwrap() wird niemals fehlschlagen, weil die Standardbreite gültig ist.
+        Self::generate(
+            DEFAULT_MODULUS_WIDTH,
+            DEFAULT_MILLER_RABIN_ITERATIONS,
+            &service,
+        )
+        .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn small_generator(seed: u32) -> Result<BlumBlumShub> {
+        BlumBlumShub::from_primes(
+            &BigInt::from(11),
+            &BigInt::from(23),
+            &BigInt::from(seed),
+            NumberTheoryService::new(Fast),
+        )
+    }
+
+    #[test]
+    fn test_known_sequence() {
+        // n = 253, x_0 = 3^2 = 9, danach 81, 236, 36, 31, 202.
+        let mut generator = small_generator(3).unwrap();
+        let bits: Vec<bool> = (0..5).map(|_| generator.next_bit()).collect();
+        assert_eq!(bits, vec![true, false, false, true, false]);
+        assert_eq!(generator.state, BigInt::from(202));
+    }
+
+    #[test]
+    fn test_rejects_invalid_parameters() {
+        let service = NumberTheoryService::new(Fast);
+        let (eleven, thirteen) = (BigInt::from(11), BigInt::from(13));
+        // 13 ≡ 1 mod 4 ist keine Blum-Primzahl.
+        assert!(BlumBlumShub::from_primes(&eleven, &thirteen, &3.into(), service).is_err());
+        assert!(BlumBlumShub::from_primes(&eleven, &eleven, &3.into(), service).is_err());
+        // Nicht teilerfremd zu 253 = 11 · 23 bzw. konstante Folge ab x_0 = 1.
+        assert!(small_generator(22).is_err());
+        assert!(small_generator(1).is_err());
+        assert!(small_generator(0).is_err());
+    }
+
+    #[test]
+    fn test_keystream_is_self_inverse() {
+        let service = NumberTheoryWithPrngService::new(Fast, 29);
+        let generator = BlumBlumShub::generate(256, 20, &service).unwrap();
+        let seed = BigInt::from(123_456_789);
+        let message = b"Blum Blum Shub als Stromchiffre".to_vec();
+
+        let mut data = message.clone();
+        BlumBlumShub::new(
+            generator.modulus().clone(),
+            &seed,
+            service.number_theory_service,
+        )
+        .unwrap()
+        .apply_keystream(&mut data);
+        assert_ne!(data, message);
+
+        BlumBlumShub::new(
+            generator.modulus().clone(),
+            &seed,
+            service.number_theory_service,
+        )
+        .unwrap()
+        .apply_keystream(&mut data);
+        assert_eq!(data, message);
+    }
+
+    #[test]
+    fn test_generate_uses_blum_primes() {
+        let service = NumberTheoryWithPrngService::new(Fast, 7);
+        let generator = BlumBlumShub::generate(128, 20, &service).unwrap();
+        assert!(generator.modulus().bits() >= 127);
+        assert_eq!(generator.modulus() % 4, BigInt::one());
+        assert!(BlumBlumShub::generate(MIN_MODULUS_WIDTH - 1, 20, &service).is_err());
+    }
+}
@@ -0,0 +1,185 @@
+use crate::math_core::csprng::{os_entropy, RandomByteGenerator};
+use crate::shared::hashing::sha256_bytes;
+
+/// Die Konstante "expand 32-byte k" in den ersten vier Wörtern des Zustands.
+const CONSTANTS: [u32; 4] = [0x61707865, 0x3320646e, 0x79622d32, 0x6b206574];
+/// Die Länge eines Blocks in Bytes.
+const BLOCK_LENGTH: usize = 64;
+
+///
+/// Zufallszahlengenerator auf Basis der Stromchiffre ChaCha20 (RFC 8439). Die Ausgabe ist der
+/// Schlüsselstrom zum Seed als Schlüssel. Wie beim ursprünglichen ChaCha wird ein 64-Bit-Zähler
+/// in den Wörtern 12 und 13 verwendet, die Nonce in den Wörtern 14 und 15 ist null.
+///
+pub struct ChaCha20Rng {
+    /// Der Schlüssel als acht Little-Endian-Wörter
+    key: [u32; 8],
+    /// Der Zähler des nächsten Blocks
+    counter: u64,
+    /// Der aktuelle Block des Schlüsselstroms
+    buffer: [u8; BLOCK_LENGTH],
+    /// Die Position des nächsten unverbrauchten Bytes im Puffer
+    position: usize,
+}
+
+impl ChaCha20Rng {
+    /// Erstellt den Generator mit dem Seed als Schlüssel.
+    ///
+    /// # Argumente
+    /// * `seed` - Der 256-Bit-Schlüssel.
+    ///
+    /// # Rückgabe
+    /// * `ChaCha20Rng` - Der Generator.
+    pub fn new(seed: [u8; 32]) -> Self {
+        let mut key = [0u32; 8];
+        for (word, bytes) in key.iter_mut().zip(seed.chunks_exact(4)) {
+            *word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+        ChaCha20Rng {
+            key,
+            counter: 0,
+            buffer: [0; BLOCK_LENGTH],
+            position: BLOCK_LENGTH,
+        }
+    }
+
+    /// Berechnet einen Block der ChaCha20-Blockfunktion.
+    ///
+    /// # Argumente
+    /// * `key` - Der Schlüssel.
+    /// * `input` - Die Wörter 12 bis 15, also Zähler und Nonce.
+    ///
+    /// # Rückgabe
+    /// * Die 64 Bytes des Schlüsselstroms.
+    fn block(key: &[u32; 8], input: [u32; 4]) -> [u8; BLOCK_LENGTH] {
+        let mut state = [0u32; 16];
+        state[..4].copy_from_slice(&CONSTANTS);
+        state[4..12].copy_from_slice(key);
+        state[12..].copy_from_slice(&input);
+
+        let mut working = state;
+        for _ in 0..10 {
+            // Spaltenrunde
+            Self::quarter_round(&mut working, 0, 4, 8, 12);
+            Self::quarter_round(&mut working, 1, 5, 9, 13);
+            Self::quarter_round(&mut working, 2, 6, 10, 14);
+            Self::quarter_round(&mut working, 3, 7, 11, 15);
+            // Diagonalrunde
+            Self::quarter_round(&mut working, 0, 5, 10, 15);
+            Self::quarter_round(&mut working, 1, 6, 11, 12);
+            Self::quarter_round(&mut working, 2, 7, 8, 13);
+            Self::quarter_round(&mut working, 3, 4, 9, 14);
+        }
+
+        let mut output = [0u8; BLOCK_LENGTH];
+        for (i, chunk) in output.chunks_exact_mut(4).enumerate() {
+            chunk.copy_from_slice(&working[i].wrapping_add(state[i]).to_le_bytes());
+        }
+        output
+    }
+
+    fn quarter_round(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+        state[a] = state[a].wrapping_add(state[b]);
+        state[d] = (state[d] ^ state[a]).rotate_left(16);
+        state[c] = state[c].wrapping_add(state[d]);
+        state[b] = (state[b] ^ state[c]).rotate_left(12);
+        state[a] = state[a].wrapping_add(state[b]);
+        state[d] = (state[d] ^ state[a]).rotate_left(8);
+        state[c] = state[c].wrapping_add(state[d]);
+        state[b] = (state[b] ^ state[c]).rotate_left(7);
+    }
+}
+
+impl RandomByteGenerator for ChaCha20Rng {
+    fn fill_bytes(&mut self, destination: &mut [u8]) {
+        let mut written = 0;
+        while written < destination.len() {
+            if self.position == BLOCK_LENGTH {
+                let input = [self.counter as u32, (self.counter >> 32) as u32, 0, 0];
+                self.buffer = Self::block(&self.key, input);
+                self.counter += 1;
+                self.position = 0;
+            }
+            let count = (destination.len() - written).min(BLOCK_LENGTH - self.position);
+            destination[written..written + count]
+                .copy_from_slice(&self.buffer[self.position..self.position + count]);
+            self.position += count;
+            written += count;
+        }
+    }
+
+    fn from_os_entropy() -> Self {
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(&os_entropy(32));
+        ChaCha20Rng::new(seed)
+    }
+
+    /// Der Schlüssel ist der SHA-256-Hash des Seeds.
+    fn from_seed(seed: u32) -> Self {
+        let mut key = [0u8; 32];
+        key.copy_from_slice(&sha256_bytes(&seed.to_be_bytes()));
+        ChaCha20Rng::new(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::hex::to_hex;
+
+    #[test]
+    fn test_block_function_rfc8439() {
+        // RFC 8439, Abschnitt 2.3.2
+        let mut seed = [0u8; 32];
+        for (i, byte) in seed.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+        let key = ChaCha20Rng::new(seed).key;
+        let block = ChaCha20Rng::block(&key, [1, 0x09000000, 0x4a000000, 0]);
+        assert_eq!(
+            to_hex(&block),
+            "10f1e7e4d13b5915500fdd1fa32071c4c7d1f4c733c068030422aa9ac3d46c4e\
+             d2826446079faa0914c2d705d98b02a2b5129cd1de164eb9cbd083e8a2503c4e"
+        );
+    }
+
+    #[test]
+    fn test_keystream_for_zero_key() {
+        // RFC 8439, Anhang A.1, Testvektor 1: Schlüssel, Zähler und Nonce sind null.
+        let mut rng = ChaCha20Rng::new([0; 32]);
+        let mut output = [0u8; 64];
+        rng.fill_bytes(&mut output);
+        assert_eq!(
+            to_hex(&output),
+            "76b8e0ada0f13d90405d6ae55386bd28bdd219b8a08ded1aa836efcc8b770dc7\
+             da41597c5157488d7724e03fb8d84a376a43b8f41518a11cc387b669b2ee6586"
+        );
+    }
+
+    #[test]
+    fn test_stream_is_independent_of_chunking() {
+        let mut whole = ChaCha20Rng::new([7; 32]);
+        let mut expected = [0u8; 200];
+        whole.fill_bytes(&mut expected);
+
+        let mut pieces = ChaCha20Rng::new([7; 32]);
+        let mut actual = [0u8; 200];
+        let (first, rest) = actual.split_at_mut(10);
+        pieces.fill_bytes(first);
+        let (second, third) = rest.split_at_mut(100);
+        pieces.fill_bytes(second);
+        pieces.fill_bytes(third);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_from_seed_is_reproducible() {
+        let output = |seed: u32| {
+            let mut bytes = [0u8; 100];
+            ChaCha20Rng::from_seed(seed).fill_bytes(&mut bytes);
+            bytes
+        };
+        assert_eq!(output(42), output(42));
+        assert_ne!(output(42), output(43));
+    }
+}
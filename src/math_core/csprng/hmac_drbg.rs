@@ -0,0 +1,182 @@
+use crate::math_core::csprng::{os_entropy, RandomByteGenerator};
+use crate::shared::hashing::{hmac_sha256, SHA256_LENGTH};
+
+/// Nach so vielen Anfragen muss laut NIST SP 800-90A (Tabelle 2) neu geseedet werden.
+const RESEED_INTERVAL: u64 = 1 << 48;
+/// Die maximale Anzahl an Bytes pro Anfrage (2^19 Bit).
+const MAX_BYTES_PER_REQUEST: usize = 1 << 16;
+/// Die Länge der Entropie beim Seeden aus dem Betriebssystem. Für 256 Bit Sicherheit werden
+/// zusätzlich zur Entropie noch 128 Bit Nonce verwendet.
+const ENTROPY_LENGTH: usize = 32;
+const NONCE_LENGTH: usize = 16;
+
+///
+/// Deterministischer Zufallsbitgenerator HMAC_DRBG nach NIST SP 800-90A (Abschnitt 10.1.2)
+/// mit HMAC-SHA256. Der Zustand besteht aus dem Schlüssel K und dem Wert V, die nach jeder
+/// Anfrage weitergeschaltet werden.
+///
+pub struct HmacDrbg {
+    /// Der Schlüssel K
+    key: Vec<u8>,
+    /// Der Wert V
+    value: Vec<u8>,
+    /// Die Anzahl der Anfragen seit dem letzten Seeden
+    reseed_counter: u64,
+}
+
+impl HmacDrbg {
+    /// Instanziiert den Generator (HMAC_DRBG_Instantiate).
+    ///
+    /// # Argumente
+    /// * `entropy` - Die Entropie.
+    /// * `nonce` - Eine Nonce, z.B. ein Zeitstempel oder weitere Entropie.
+    /// * `personalization` - Ein optionaler Personalisierungsstring.
+    ///
+    /// # Rückgabe
+    /// * `HmacDrbg` - Der Generator.
+    pub fn new(entropy: &[u8], nonce: &[u8], personalization: &[u8]) -> Self {
+        let mut drbg = HmacDrbg {
+            key: vec![0x00; SHA256_LENGTH],
+            value: vec![0x01; SHA256_LENGTH],
+            reseed_counter: 1,
+        };
+        drbg.update(&[entropy, nonce, personalization].concat());
+        drbg
+    }
+
+    /// Seedet den Generator mit neuer Entropie (HMAC_DRBG_Reseed).
+    ///
+    /// # Argumente
+    /// * `entropy` - Die neue Entropie.
+    /// * `additional_input` - Optionale zusätzliche Eingabe.
+    pub fn reseed(&mut self, entropy: &[u8], additional_input: &[u8]) {
+        self.update(&[entropy, additional_input].concat());
+        self.reseed_counter = 1;
+    }
+
+    /// Erzeugt Zufallsbytes (HMAC_DRBG_Generate).
+    ///
+    /// # Argumente
+    /// * `length` - Die Anzahl der Bytes, höchstens 2^16.
+    /// * `additional_input` - Optionale zusätzliche Eingabe.
+    ///
+    /// # Rückgabe
+    /// * `Vec<u8>` - Die Zufallsbytes.
+    pub fn generate(&mut self, length: usize, additional_input: &[u8]) -> Vec<u8> {
+        assert!(
+            length <= MAX_BYTES_PER_REQUEST,
+            "Es können höchstens {} Bytes pro Anfrage erzeugt werden",
+            MAX_BYTES_PER_REQUEST
+        );
+        if !additional_input.is_empty() {
+            self.update(additional_input);
+        }
+        let mut output = Vec::with_capacity(length + SHA256_LENGTH);
+        while output.len() < length {
+            self.value = hmac_sha256(&self.key, &self.value);
+            output.extend(&self.value);
+        }
+        output.truncate(length);
+        self.update(additional_input);
+        self.reseed_counter += 1;
+        output
+    }
+
+    /// Die Zustandsfortschaltung HMAC_DRBG_Update.
+    fn update(&mut self, provided_data: &[u8]) {
+        for separator in [0x00, 0x01] {
+            self.key = hmac_sha256(
+                &self.key,
+                &[&self.value[..], &[separator], provided_data].concat(),
+            );
+            self.value = hmac_sha256(&self.key, &self.value);
+            if provided_data.is_empty() {
+                break;
+            }
+        }
+    }
+}
+
+impl RandomByteGenerator for HmacDrbg {
+    fn fill_bytes(&mut self, destination: &mut [u8]) {
+        for chunk in destination.chunks_mut(MAX_BYTES_PER_REQUEST) {
+            if self.reseed_counter > RESEED_INTERVAL {
+                self.reseed(&os_entropy(ENTROPY_LENGTH), &[]);
+            }
+            chunk.copy_from_slice(&self.generate(chunk.len(), &[]));
+        }
+    }
+
+    fn from_os_entropy() -> Self {
+        HmacDrbg::new(
+            &os_entropy(ENTROPY_LENGTH),
+            &os_entropy(NONCE_LENGTH),
+            b"encryption_tool",
+        )
+    }
+
+    /// Der Seed dient als Entropie, die Nonce bleibt leer.
+    fn from_seed(seed: u32) -> Self {
+        HmacDrbg::new(&seed.to_be_bytes(), &[], b"encryption_tool")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::hex::{from_hex, to_hex};
+
+    /// Testvektor aus den NIST CAVP-Testdaten (HMAC_DRBG, SHA-256, ohne Reseed und ohne
+    /// zusätzliche Eingaben, COUNT = 0). Geprüft wird die Ausgabe des zweiten Aufrufs.
+    #[test]
+    fn test_nist_vector() {
+        let entropy =
+            from_hex("ca851911349384bffe89de1cbdc46e6831e44d34a4fb935ee285dd14b71a7488").unwrap();
+        let nonce = from_hex("659ba96c601dc69fc902940805ec0ca8").unwrap();
+        let mut drbg = HmacDrbg::new(&entropy, &nonce, &[]);
+
+        drbg.generate(128, &[]);
+        assert_eq!(
+            to_hex(&drbg.generate(128, &[])),
+            "e528e9abf2dece54d47c7e75e5fe302149f817ea9fb4bee6f4199697d04d5b89\
+             d54fbb978a15b5c443c9ec21036d2460b6f73ebad0dc2aba6e624abf07745bc1\
+             07694bb7547bb0995f70de25d6b29e2d3011bb19d27676c07162c8b5ccde0668\
+             961df86803482cb37ed6d5c0bb8d50cf1f50d476aa0458bdaba806f48be9dcb8"
+        );
+    }
+
+    #[test]
+    fn test_reseed_and_additional_input_change_output() {
+        let new = || HmacDrbg::new(b"entropy", b"nonce", &[]);
+        let plain = new().generate(32, &[]);
+
+        let mut reseeded = new();
+        reseeded.reseed(b"more entropy", &[]);
+        assert_ne!(reseeded.generate(32, &[]), plain);
+
+        assert_ne!(new().generate(32, b"additional"), plain);
+        assert_eq!(new().generate(32, &[]), plain);
+    }
+
+    #[test]
+    fn test_fill_bytes_from_os_entropy() {
+        let mut first = HmacDrbg::from_os_entropy();
+        let mut second = HmacDrbg::from_os_entropy();
+        let mut a = [0u8; 100];
+        let mut b = [0u8; 100];
+        first.fill_bytes(&mut a);
+        second.fill_bytes(&mut b);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_from_seed_is_reproducible() {
+        let output = |seed: u32| {
+            let mut bytes = [0u8; 100];
+            HmacDrbg::from_seed(seed).fill_bytes(&mut bytes);
+            bytes
+        };
+        assert_eq!(output(42), output(42));
+        assert_ne!(output(42), output(43));
+    }
+}
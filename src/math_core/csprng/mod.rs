@@ -0,0 +1,32 @@
+pub mod blum_blum_shub;
+pub mod chacha20_rng;
+pub mod hmac_drbg;
+
+///
+/// Gemeinsame Schnittstelle der kryptografisch sicheren Zufallsbitgeneratoren. Anders als die
+/// Weyl-Folge des `PseudoRandomNumberGenerator` lässt sich die Ausgabe ohne Kenntnis des
+/// internen Zustands nicht vorhersagen.
+///
+pub trait RandomByteGenerator: Send {
+    /// Füllt den Puffer vollständig mit Zufallsbytes.
+    fn fill_bytes(&mut self, destination: &mut [u8]);
+
+    /// Erstellt einen Generator, der mit Entropie des Betriebssystems initialisiert ist.
+    fn from_os_entropy() -> Self
+    where
+        Self: Sized;
+
+    /// Erstellt einen reproduzierbaren Generator zum Seed, z.B. für die statistischen Tests.
+    /// Ein Seed mit 32 Bit ist für echte Schlüssel zu kurz.
+    fn from_seed(seed: u32) -> Self
+    where
+        Self: Sized;
+}
+
+/// Liest `length` Bytes Entropie aus der Zufallsquelle des Betriebssystems.
+fn os_entropy(length: usize) -> Vec<u8> {
+    use rand::RngCore;
+    let mut entropy = vec![0u8; length];
+    rand::rngs::OsRng.fill_bytes(&mut entropy);
+    entropy
+}
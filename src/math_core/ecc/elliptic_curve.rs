@@ -0,0 +1,47 @@
+use std::fmt::{Debug, Display};
+
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::num_traits::Euclid;
+use bigdecimal::Zero;
+
+use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
+
+///
+/// Gemeinsame Schnittstelle für elliptische Kurven in kurzer Weierstraß-Form
+/// y^2 = x^3 + a · x + b über dem endlichen Körper Z_p, auf denen eine zyklische Untergruppe
+/// mit Generator festgelegt ist.
+///
+/// Die Punktarithmetik in `FiniteFieldEllipticCurvePoint` und die darauf aufbauenden Verfahren
+/// arbeiten ausschließlich über diese Schnittstelle.
+///
+pub trait EllipticCurve: Clone + Debug + Display + PartialEq {
+    /// Der Koeffizient a der Kurve.
+    fn a(&self) -> BigInt;
+
+    /// Der Koeffizient b der Kurve.
+    fn b(&self) -> BigInt;
+
+    /// Der Modulus p des Körpers, über dem die Kurve definiert ist.
+    fn prime(&self) -> &BigInt;
+
+    /// Die Ordnung der zyklischen Untergruppe, die vom Generator erzeugt wird.
+    fn order_of_subgroup(&self) -> &BigInt;
+
+    /// Der Generator der zyklischen Untergruppe.
+    fn generator(&self) -> &FiniteFieldEllipticCurvePoint;
+
+    /// Überprüft, ob ein Punkt auf der elliptischen Kurve liegt. Der Punkt im Unendlichen liegt
+    /// auf jeder Kurve.
+    fn has_point(&self, point: &FiniteFieldEllipticCurvePoint) -> bool {
+        if point.is_infinite {
+            return true;
+        }
+        let x_cubed = point.x.pow(3);
+        let y_squared = point.y.pow(2);
+
+        // y^2 = x^3 + ax + b (mod p) ist äquivalent zu (x^3 + ax + b - y^2) % p == 0
+        (x_cubed + self.a() * &point.x + self.b() - y_squared)
+            .rem_euclid(self.prime())
+            .is_zero()
+    }
+}
@@ -6,7 +6,8 @@ use bigdecimal::{One, Zero};
 use std::fmt::Display;
 use std::ops::Add;
 
-use crate::math_core::ecc::secure_finite_field_elliptic_curve::SecureFiniteFieldEllipticCurve;
+use crate::math_core::ecc::elliptic_curve::EllipticCurve;
+use crate::math_core::ecc::jacobian_point::JacobianPoint;
 use crate::math_core::number_theory::number_theory_service::{
     NumberTheoryService, NumberTheoryServiceTrait,
 };
@@ -63,20 +64,20 @@ impl FiniteFieldEllipticCurvePoint {
 
     /// Addiert zwei Punkte auf einer elliptischen Kurve.
     /// Die Punkte müssen auf der gleichen elliptischen Kurve liegen.
-    pub fn add(
+    pub fn add<C: EllipticCurve>(
         &self,
         other: &Self,
-        curve: &SecureFiniteFieldEllipticCurve,
+        curve: &C,
         service: &NumberTheoryService,
     ) -> Result<Self> {
         // Liegen die Punkte nicht auf der gleichen Kurve, ist das Ergebnis undefiniert.
         ensure!(
             curve.has_point(self),
-            PointNotOnCurveError(other.clone(), curve.clone())
+            PointNotOnCurveError(self.clone(), curve.to_string())
         );
         ensure!(
             curve.has_point(other),
-            PointNotOnCurveError(other.clone(), curve.clone())
+            PointNotOnCurveError(other.clone(), curve.to_string())
         );
 
         // Liegt einer der beiden Punkte im Unendlichen, so ist das Ergebnis der je andere Punkt.
@@ -88,7 +89,7 @@ impl FiniteFieldEllipticCurvePoint {
         }
 
         // Negieren sich zwei Punkte, so erhält man ebenfalls den Punkt im Unendlichen.
-        if self.x == other.x && (&self.y).add(&other.y).rem_euclid(&curve.prime).is_zero() {
+        if self.x == other.x && (&self.y).add(&other.y).rem_euclid(curve.prime()).is_zero() {
             return Ok(FiniteFieldEllipticCurvePoint::infinite());
         }
 
@@ -97,7 +98,7 @@ impl FiniteFieldEllipticCurvePoint {
             return Ok(self.double(curve, service));
         }
 
-        let prime = &curve.prime;
+        let prime = curve.prime();
 
         // Zähler der Steigung berechnen
         let slope_numer = &other.y - &self.y;
@@ -118,11 +119,7 @@ impl FiniteFieldEllipticCurvePoint {
     }
 
     /// Verdoppelt einen Punkt auf einer elliptischen Kurve.
-    pub fn double(
-        &self,
-        curve: &SecureFiniteFieldEllipticCurve,
-        service: &NumberTheoryService,
-    ) -> Self {
+    pub fn double<C: EllipticCurve>(&self, curve: &C, service: &NumberTheoryService) -> Self {
         if self.is_infinite {
             return self.clone();
         }
@@ -131,9 +128,9 @@ impl FiniteFieldEllipticCurvePoint {
         if self.y.is_zero() {
             return FiniteFieldEllipticCurvePoint::infinite();
         }
-        let p = &curve.prime;
-        // Zähler der Steigung berechnen
-        let slope_numer = 3 * (&self.x).pow(2) + &curve.a;
+        let p = curve.prime();
+        // Zähler der Steigung berechnen, der Koeffizient b fällt bei der Ableitung weg
+        let slope_numer = 3 * (&self.x).pow(2) + curve.a();
         // Nenner der Steigung berechnen
         let slope_denom = 2 * &self.y;
         let slope_denom = service.modulo_inverse(&slope_denom, p).unwrap();
@@ -147,13 +144,14 @@ impl FiniteFieldEllipticCurvePoint {
     }
 
     /// Multipliziert einen Punkt mit einem Skalar.
-    /// Dabei wird die optimierte Berechnung in Form des Double-and-add Algorithmus verwendet.
+    /// Dabei wird die optimierte Berechnung in Form des Double-and-add Algorithmus in
+    /// Jacobi-Koordinaten verwendet.
     /// Bei Multiplikation mit 0 wird der Punkt im Ursprung mit Bezug auf die ursprüngliche Kurve
     /// zurückgegeben.
-    pub fn multiply(
+    pub fn multiply<C: EllipticCurve>(
         &self,
         scalar: &BigInt,
-        curve: &SecureFiniteFieldEllipticCurve,
+        curve: &C,
         service: &NumberTheoryService,
     ) -> Result<Self> {
         // Bei einer 1 passiert nichts
@@ -166,23 +164,81 @@ impl FiniteFieldEllipticCurvePoint {
         }
         // Ist der Punkt der Generator und der Skalar die Ordnung des Generators, wird der Punkt
         // im Unendlichen zurückgegeben.
-        if self == &curve.generator && scalar == &curve.order_of_subgroup {
+        if self == curve.generator() && scalar == curve.order_of_subgroup() {
             return Ok(FiniteFieldEllipticCurvePoint::infinite());
         }
 
-        let mut result = FiniteFieldEllipticCurvePoint::infinite();
-        let mut addend = self.clone();
+        // Liegt der Punkt nicht auf der Kurve, ist das Ergebnis undefiniert.
+        ensure!(
+            curve.has_point(self),
+            PointNotOnCurveError(self.clone(), curve.to_string())
+        );
+
+        // Die Zwischenergebnisse werden in Jacobi-Koordinaten berechnet, sodass statt einer
+        // Inversion pro Schritt nur eine einzige am Ende anfällt.
+        let mut result = JacobianPoint::infinite();
+        let mut addend = JacobianPoint::from_affine(self);
         let mut n = scalar.clone();
         while n > BigInt::zero() {
             if n.is_odd() {
-                result = result
-                    .add(&addend, &curve, service)
-                    .context("Error while adding point in multiply operation")?;
+                result = result.add(&addend, curve);
             }
-            addend = addend.double(curve, service);
+            addend = addend.double(curve);
             n = n >> 1;
         }
-        Ok(result)
+        result
+            .to_affine(curve, service)
+            .context("Error while converting result of multiply operation")
+    }
+
+    /// Multipliziert einen Punkt mit einem geheimen Skalar über die Montgomery-Leiter.
+    /// Anders als bei `multiply` hängt die Folge der Operationen nicht vom Skalar ab: Für jedes
+    /// Bit wird genau eine Addition und eine Verdopplung ausgeführt, die Auswahl der Operanden
+    /// erfolgt über einen verzweigungsfreien Tausch. Damit der Skalar stets dieselbe Bitlänge
+    /// hat, wird er modulo q reduziert und um q bzw. 2q erhöht. Das ändert das Ergebnis nur für
+    /// Punkte, die nicht in der Untergruppe der Ordnung q liegen.
+    ///
+    /// Die Arithmetik mit `BigInt` ist selbst nicht zeitkonstant, die Laufzeit hängt aber nicht
+    /// mehr von der Anzahl der gesetzten Bits des Skalars ab.
+    ///
+    /// # Argumente
+    /// * `scalar` - Der geheime Skalar, z.B. ein privater Schlüssel oder eine Nonce.
+    /// * `curve` - Die Kurve.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// * `FiniteFieldEllipticCurvePoint` - Das Produkt `scalar * self`.
+    pub fn multiply_secret<C: EllipticCurve>(
+        &self,
+        scalar: &BigInt,
+        curve: &C,
+        service: &NumberTheoryService,
+    ) -> Result<Self> {
+        ensure!(
+            curve.has_point(self),
+            PointNotOnCurveError(self.clone(), curve.to_string())
+        );
+        let q = curve.order_of_subgroup();
+        let bits = q.bits();
+
+        // k + q bzw. k + 2q hat immer genau bits + 1 Bits, das oberste Bit ist gesetzt.
+        let mut k = scalar.rem_euclid(q) + q;
+        if k.bits() <= bits {
+            k += q;
+        }
+
+        // Invariante: r1 = r0 + self
+        let mut r0 = JacobianPoint::from_affine(self);
+        let mut r1 = r0.double(curve);
+        for i in (0..bits).rev() {
+            let bit = k.bit(i);
+            JacobianPoint::conditional_swap(&mut r0, &mut r1, bit);
+            r1 = r0.add(&r1, curve);
+            r0 = r0.double(curve);
+            JacobianPoint::conditional_swap(&mut r0, &mut r1, bit);
+        }
+        r0.to_affine(curve, service)
+            .context("Error while converting result of multiply operation")
     }
 
     ///
@@ -204,8 +260,11 @@ impl FiniteFieldEllipticCurvePoint {
 mod tests {
 
     use super::*;
+    use crate::math_core::ecc::named_curves::NamedCurve;
+    use crate::math_core::ecc::secure_finite_field_elliptic_curve::SecureFiniteFieldEllipticCurve;
     use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
     use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+    use std::time::Instant;
 
     fn get_curve() -> SecureFiniteFieldEllipticCurve {
         let service = NumberTheoryWithPrngService::new(Fast, 17);
@@ -351,4 +410,115 @@ mod tests {
             .unwrap();
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_multiply_secret_matches_multiply() {
+        let service = NumberTheoryWithPrngService::new(Fast, 17);
+        let number_theory_service = &service.number_theory_service;
+        let secure_curve = get_curve();
+        let named_curve = NamedCurve::P256.curve();
+
+        let q = secure_curve.order_of_subgroup();
+        let mut scalars: Vec<BigInt> = vec![
+            BigInt::zero(),
+            BigInt::one(),
+            BigInt::from(2),
+            q - 1,
+            q.clone(),
+            q + 5,
+        ];
+        for _ in 0..10 {
+            scalars.push(service.take_random_number_in_range(&BigInt::one(), q));
+        }
+        for k in &scalars {
+            let expected = secure_curve
+                .generator
+                .multiply(&k.rem_euclid(q), &secure_curve, number_theory_service)
+                .unwrap();
+            let actual = secure_curve
+                .generator
+                .multiply_secret(k, &secure_curve, number_theory_service)
+                .unwrap();
+            assert_eq!(actual, expected, "k = {}", k);
+        }
+
+        for _ in 0..5 {
+            let k =
+                service.take_random_number_in_range(&BigInt::one(), &named_curve.order_of_subgroup);
+            let expected = named_curve
+                .generator
+                .multiply(&k, &named_curve, number_theory_service)
+                .unwrap();
+            let actual = named_curve
+                .generator
+                .multiply_secret(&k, &named_curve, number_theory_service)
+                .unwrap();
+            assert_eq!(actual, expected, "k = {}", k);
+        }
+    }
+
+    /// Mittelwert und Standardabweichung von Messwerten.
+    fn mean_and_deviation(samples: &[f64]) -> (f64, f64) {
+        let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+        let variance =
+            samples.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / samples.len() as f64;
+        (mean, variance.sqrt())
+    }
+
+    /// Zeitmessung für Skalare mit wenigen bzw. vielen gesetzten Bits bei gleicher Bitlänge.
+    /// Beim Double-and-Add hängt die Laufzeit von der Anzahl der Additionen und damit vom
+    /// Hamming-Gewicht ab, bei der Montgomery-Leiter nicht.
+    ///
+    /// Aufruf: `cargo test --release timing_variance -- --ignored --nocapture`
+    #[test]
+    #[ignore] // Zeitmessung, nur manuell und im Release-Build aussagekräftig.
+    fn test_timing_variance_of_scalar_multiplication() {
+        let service = &NumberTheoryService::new(Fast);
+        let curve = NamedCurve::P256.curve();
+        let generator = &curve.generator;
+        let runs = 200;
+
+        let sparse = (BigInt::one() << 255) + 1;
+        let dense = (BigInt::one() << 255) + ((BigInt::one() << 254) - 1);
+
+        let mut relative_differences = Vec::new();
+        for (name, secret) in [("Double-and-Add", false), ("Montgomery-Leiter", true)] {
+            let multiply = |k: &BigInt| {
+                if secret {
+                    generator.multiply_secret(k, &curve, service).unwrap()
+                } else {
+                    generator.multiply(k, &curve, service).unwrap()
+                }
+            };
+            let time = |k: &BigInt| {
+                let start = Instant::now();
+                std::hint::black_box(multiply(k));
+                start.elapsed().as_secs_f64() * 1e6
+            };
+            // Aufwärmen, danach abwechselnd messen, damit sich Schwankungen der Taktfrequenz
+            // gleichmäßig auf beide Reihen verteilen.
+            time(&sparse);
+            time(&dense);
+            let (mut sparse_samples, mut dense_samples) = (Vec::new(), Vec::new());
+            for _ in 0..runs {
+                sparse_samples.push(time(&sparse));
+                dense_samples.push(time(&dense));
+            }
+            let (sparse_mean, sparse_deviation) = mean_and_deviation(&sparse_samples);
+            let (dense_mean, dense_deviation) = mean_and_deviation(&dense_samples);
+            let relative_difference = (dense_mean - sparse_mean).abs() / sparse_mean;
+            println!(
+                "{:<18} wenige Bits: {:>9.1} ± {:>7.1} µs, viele Bits: {:>9.1} ± {:>7.1} µs, \
+                 Differenz: {:>5.1} %",
+                name,
+                sparse_mean,
+                sparse_deviation,
+                dense_mean,
+                dense_deviation,
+                relative_difference * 100.0
+            );
+            relative_differences.push(relative_difference);
+        }
+        assert!(relative_differences[1] < relative_differences[0]);
+    }
 }
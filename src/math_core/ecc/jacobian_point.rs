@@ -0,0 +1,254 @@
+use anyhow::{Context, Result};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::num_traits::Euclid;
+use bigdecimal::{One, Zero};
+
+use crate::math_core::ecc::elliptic_curve::EllipticCurve;
+use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
+use crate::math_core::number_theory::number_theory_service::{
+    NumberTheoryService, NumberTheoryServiceTrait,
+};
+
+///
+/// Ein Punkt in Jacobi-Koordinaten (X : Y : Z), der dem affinen Punkt (X / Z^2, Y / Z^3)
+/// entspricht. Der Punkt im Unendlichen hat Z = 0.
+///
+/// Addition und Verdopplung kommen in dieser Darstellung ohne modulare Inversion aus. Sie wird
+/// nur intern für die Skalarmultiplikation verwendet, erst das Ergebnis wird mit einer einzigen
+/// Inversion zurück in affine Koordinaten überführt.
+///
+#[derive(Clone, Debug)]
+pub(crate) struct JacobianPoint {
+    x: BigInt,
+    y: BigInt,
+    z: BigInt,
+}
+
+impl JacobianPoint {
+    /// Der Punkt im Unendlichen (1 : 1 : 0).
+    pub(crate) fn infinite() -> Self {
+        JacobianPoint {
+            x: BigInt::one(),
+            y: BigInt::one(),
+            z: BigInt::zero(),
+        }
+    }
+
+    pub(crate) fn is_infinite(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    /// Überführt einen affinen Punkt mit Z = 1 in Jacobi-Koordinaten.
+    pub(crate) fn from_affine(point: &FiniteFieldEllipticCurvePoint) -> Self {
+        if point.is_infinite {
+            return Self::infinite();
+        }
+        JacobianPoint {
+            x: point.x.clone(),
+            y: point.y.clone(),
+            z: BigInt::one(),
+        }
+    }
+
+    /// Überführt den Punkt zurück in affine Koordinaten. Dafür wird genau eine Inversion von Z
+    /// benötigt.
+    pub(crate) fn to_affine<C: EllipticCurve>(
+        &self,
+        curve: &C,
+        service: &NumberTheoryService,
+    ) -> Result<FiniteFieldEllipticCurvePoint> {
+        if self.is_infinite() {
+            return Ok(FiniteFieldEllipticCurvePoint::infinite());
+        }
+        let p = curve.prime();
+        let z_inverse = service
+            .modulo_inverse(&self.z, p)
+            .context("Failed to invert z coordinate")?;
+        let z_inverse_squared = (&z_inverse * &z_inverse).rem_euclid(p);
+        let x = (&self.x * &z_inverse_squared).rem_euclid(p);
+        let y = (&self.y * z_inverse_squared * z_inverse).rem_euclid(p);
+        Ok(FiniteFieldEllipticCurvePoint::new(x, y))
+    }
+
+    /// Vertauscht die beiden Punkte, falls `swap` gesetzt ist. Die Auswahl erfolgt arithmetisch
+    /// über den Faktor 0 oder 1 statt über eine Verzweigung, sodass in beiden Fällen dieselben
+    /// Operationen ausgeführt werden.
+    pub(crate) fn conditional_swap(a: &mut Self, b: &mut Self, swap: bool) {
+        let factor = BigInt::from(swap as u8);
+        for (first, second) in [
+            (&mut a.x, &mut b.x),
+            (&mut a.y, &mut b.y),
+            (&mut a.z, &mut b.z),
+        ] {
+            let delta = (&*second - &*first) * &factor;
+            *first += &delta;
+            *second -= delta;
+        }
+    }
+
+    /// Verdoppelt den Punkt ("dbl-2007-bl" für beliebiges a):
+    /// S = 4 X Y^2, M = 3 X^2 + a Z^4, X' = M^2 - 2S, Y' = M (S - X') - 8 Y^4, Z' = 2 Y Z.
+    pub(crate) fn double<C: EllipticCurve>(&self, curve: &C) -> Self {
+        if self.is_infinite() || self.y.is_zero() {
+            return Self::infinite();
+        }
+        let p = curve.prime();
+        let y_squared = (&self.y * &self.y).rem_euclid(p);
+        let z_squared = (&self.z * &self.z).rem_euclid(p);
+
+        let s = (&self.x * &y_squared * 4u32).rem_euclid(p);
+        let m = (&self.x * &self.x * 3u32 + curve.a() * &z_squared * &z_squared).rem_euclid(p);
+
+        let x = (&m * &m - &s * 2u32).rem_euclid(p);
+        let y = (m * (s - &x) - &y_squared * &y_squared * 8u32).rem_euclid(p);
+        let z = (&self.y * &self.z * 2u32).rem_euclid(p);
+        JacobianPoint { x, y, z }
+    }
+
+    /// Addiert zwei Punkte ("add-1998-cmo-2"):
+    /// U1 = X1 Z2^2, U2 = X2 Z1^2, S1 = Y1 Z2^3, S2 = Y2 Z1^3, H = U2 - U1, R = S2 - S1,
+    /// X3 = R^2 - H^3 - 2 U1 H^2, Y3 = R (U1 H^2 - X3) - S1 H^3, Z3 = H Z1 Z2.
+    pub(crate) fn add<C: EllipticCurve>(&self, other: &Self, curve: &C) -> Self {
+        if self.is_infinite() {
+            return other.clone();
+        }
+        if other.is_infinite() {
+            return self.clone();
+        }
+        let p = curve.prime();
+        let z1_squared = (&self.z * &self.z).rem_euclid(p);
+        let z2_squared = (&other.z * &other.z).rem_euclid(p);
+
+        let u1 = (&self.x * &z2_squared).rem_euclid(p);
+        let u2 = (&other.x * &z1_squared).rem_euclid(p);
+        let s1 = (&self.y * z2_squared * &other.z).rem_euclid(p);
+        let s2 = (&other.y * z1_squared * &self.z).rem_euclid(p);
+
+        // Gleiche x-Koordinate: entweder derselbe Punkt oder zueinander negierte Punkte.
+        if u1 == u2 {
+            return if s1 == s2 {
+                self.double(curve)
+            } else {
+                Self::infinite()
+            };
+        }
+
+        let h = (u2 - &u1).rem_euclid(p);
+        let r = (s2 - &s1).rem_euclid(p);
+        let h_squared = (&h * &h).rem_euclid(p);
+        let h_cubed = (&h_squared * &h).rem_euclid(p);
+        let u1_h_squared = (u1 * h_squared).rem_euclid(p);
+
+        let x = (&r * &r - &h_cubed - &u1_h_squared * 2u32).rem_euclid(p);
+        let y = (r * (u1_h_squared - &x) - s1 * h_cubed).rem_euclid(p);
+        let z = (h * &self.z * &other.z).rem_euclid(p);
+        JacobianPoint { x, y, z }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math_core::ecc::named_curves::NamedCurve;
+    use crate::math_core::ecc::secure_finite_field_elliptic_curve::SecureFiniteFieldEllipticCurve;
+    use crate::math_core::ecc::weierstrass_elliptic_curve::WeierstrassEllipticCurve;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+    use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+    use crate::math_core::traits::parity::Parity;
+
+    /// Double-and-add in affinen Koordinaten als Referenz.
+    fn affine_multiply<C: EllipticCurve>(
+        point: &FiniteFieldEllipticCurvePoint,
+        scalar: &BigInt,
+        curve: &C,
+        service: &NumberTheoryService,
+    ) -> FiniteFieldEllipticCurvePoint {
+        let mut result = FiniteFieldEllipticCurvePoint::infinite();
+        let mut addend = point.clone();
+        let mut n = scalar.clone();
+        while n > BigInt::zero() {
+            if n.is_odd() {
+                result = result.add(&addend, curve, service).unwrap();
+            }
+            addend = addend.double(curve, service);
+            n >>= 1;
+        }
+        result
+    }
+
+    fn assert_identical_results<C: EllipticCurve>(
+        curve: &C,
+        service: &NumberTheoryWithPrngService,
+    ) {
+        let number_theory_service = &service.number_theory_service;
+        for _ in 0..20 {
+            let k = service.take_random_number_in_range(&BigInt::one(), curve.order_of_subgroup());
+            let expected = affine_multiply(curve.generator(), &k, curve, number_theory_service);
+            let actual = curve
+                .generator()
+                .multiply(&k, curve, number_theory_service)
+                .unwrap();
+            assert_eq!(actual, expected, "k = {} auf {}", k, curve);
+        }
+    }
+
+    #[test]
+    fn test_identical_results_on_secure_curve() {
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        let curve = SecureFiniteFieldEllipticCurve::new(5, 64, 40, &service).unwrap();
+        assert_identical_results(&curve, &service);
+    }
+
+    #[test]
+    fn test_identical_results_on_named_curves() {
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        for named_curve in [NamedCurve::P256, NamedCurve::BrainpoolP256r1] {
+            assert_identical_results(&named_curve.curve(), &service);
+        }
+    }
+
+    #[test]
+    fn test_special_cases() {
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        let number_theory_service = &service.number_theory_service;
+        // y^2 = x^3 + 2x + 2 (mod 17) mit dem Generator (5, 1) der Ordnung 19.
+        let curve = WeierstrassEllipticCurve::new(
+            2.into(),
+            2.into(),
+            17.into(),
+            19.into(),
+            1.into(),
+            FiniteFieldEllipticCurvePoint::new(5.into(), 1.into()),
+        )
+        .unwrap();
+        let generator = JacobianPoint::from_affine(&curve.generator);
+        let negated =
+            JacobianPoint::from_affine(&FiniteFieldEllipticCurvePoint::new(5.into(), 16.into()));
+
+        assert!(generator.add(&negated, &curve).is_infinite());
+        assert_eq!(
+            generator
+                .add(&generator, &curve)
+                .to_affine(&curve, number_theory_service)
+                .unwrap(),
+            curve.generator.double(&curve, number_theory_service)
+        );
+        assert!(JacobianPoint::infinite().double(&curve).is_infinite());
+
+        // (0, 0) liegt auf jeder sicheren Kurve und hat die Ordnung 2.
+        let secure_curve = SecureFiniteFieldEllipticCurve::new(5, 32, 40, &service).unwrap();
+        let small_order = FiniteFieldEllipticCurvePoint::new(0.into(), 0.into());
+        for k in 1..6 {
+            let expected = affine_multiply(
+                &small_order,
+                &k.into(),
+                &secure_curve,
+                number_theory_service,
+            );
+            let actual = small_order
+                .multiply(&k.into(), &secure_curve, number_theory_service)
+                .unwrap();
+            assert_eq!(actual, expected);
+        }
+    }
+}
@@ -1,2 +1,7 @@
+pub mod elliptic_curve;
 pub mod finite_field_elliptic_curve_point;
+pub mod jacobian_point;
+pub mod named_curves;
+pub mod point_encoding;
 pub mod secure_finite_field_elliptic_curve;
+pub mod weierstrass_elliptic_curve;
@@ -0,0 +1,292 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use bigdecimal::num_bigint::BigInt;
+use num::Num;
+
+use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
+use crate::math_core::ecc::weierstrass_elliptic_curve::WeierstrassEllipticCurve;
+use crate::shared::errors::EllipticCurveError;
+
+///
+/// Standardisierte elliptische Kurven mit festen Domain-Parametern. Schlüssel auf diesen Kurven
+/// können ohne die aufwändige Erzeugung einer eigenen Kurve erstellt werden und sind mit anderen
+/// Implementierungen austauschbar.
+///
+/// Quellen:
+/// * secp256k1: SEC 2, Abschnitt 2.4.1
+/// * P-256, P-384: FIPS 186-4, Anhang D.1.2
+/// * brainpoolP256r1, brainpoolP384r1: RFC 5639, Abschnitt 3
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NamedCurve {
+    Secp256k1,
+    P256,
+    P384,
+    BrainpoolP256r1,
+    BrainpoolP384r1,
+}
+
+/// Die Domain-Parameter einer Kurve als Hex-Strings.
+struct CurveParameters {
+    prime: &'static str,
+    a: &'static str,
+    b: &'static str,
+    generator_x: &'static str,
+    generator_y: &'static str,
+    order: &'static str,
+    cofactor: u32,
+}
+
+const SECP256K1: CurveParameters = CurveParameters {
+    prime: "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+    a: "0",
+    b: "7",
+    generator_x: "79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+    generator_y: "483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8",
+    order: "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+    cofactor: 1,
+};
+
+const P256: CurveParameters = CurveParameters {
+    prime: "FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFF",
+    a: "FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFC",
+    b: "5AC635D8AA3A93E7B3EBBD55769886BC651D06B0CC53B0F63BCE3C3E27D2604B",
+    generator_x: "6B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296",
+    generator_y: "4FE342E2FE1A7F9B8EE7EB4A7C0F9E162BCE33576B315ECECBB6406837BF51F5",
+    order: "FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551",
+    cofactor: 1,
+};
+
+const P384: CurveParameters = CurveParameters {
+    prime: "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFE\
+            FFFFFFFF0000000000000000FFFFFFFF",
+    a: "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFE\
+        FFFFFFFF0000000000000000FFFFFFFC",
+    b: "B3312FA7E23EE7E4988E056BE3F82D19181D9C6EFE8141120314088F5013875A\
+        C656398D8A2ED19D2A85C8EDD3EC2AEF",
+    generator_x: "AA87CA22BE8B05378EB1C71EF320AD746E1D3B628BA79B9859F741E082542A38\
+                  5502F25DBF55296C3A545E3872760AB7",
+    generator_y: "3617DE4A96262C6F5D9E98BF9292DC29F8F41DBD289A147CE9DA3113B5F0B8C0\
+                  0A60B1CE1D7E819D7A431D7C90EA0E5F",
+    order: "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFC7634D81F4372DDF\
+            581A0DB248B0A77AECEC196ACCC52973",
+    cofactor: 1,
+};
+
+const BRAINPOOL_P256R1: CurveParameters = CurveParameters {
+    prime: "A9FB57DBA1EEA9BC3E660A909D838D726E3BF623D52620282013481D1F6E5377",
+    a: "7D5A0975FC2C3057EEF67530417AFFE7FB8055C126DC5C6CE94A4B44F330B5D9",
+    b: "26DC5C6CE94A4B44F330B5D9BBD77CBF958416295CF7E1CE6BCCDC18FF8C07B6",
+    generator_x: "8BD2AEB9CB7E57CB2C4B482FFC81B7AFB9DE27E1E3BD23C23A4453BD9ACE3262",
+    generator_y: "547EF835C3DAC4FD97F8461A14611DC9C27745132DED8E545C1D54C72F046997",
+    order: "A9FB57DBA1EEA9BC3E660A909D838D718C397AA3B561A6F7901E0E82974856A7",
+    cofactor: 1,
+};
+
+const BRAINPOOL_P384R1: CurveParameters = CurveParameters {
+    prime: "8CB91E82A3386D280F5D6F7E50E641DF152F7109ED5456B412B1DA197FB71123\
+            ACD3A729901D1A71874700133107EC53",
+    a: "7BC382C63D8C150C3C72080ACE05AFA0C2BEA28E4FB22787139165EFBA91F90F\
+        8AA5814A503AD4EB04A8C7DD22CE2826",
+    b: "04A8C7DD22CE28268B39B55416F0447C2FB77DE107DCD2A62E880EA53EEB62D5\
+        7CB4390295DBC9943AB78696FA504C11",
+    generator_x: "1D1C64F068CF45FFA2A63A81B7C13F6B8847A3E77EF14FE3DB7FCAFE0CBD10E8\
+                  E826E03436D646AAEF87B2E247D4AF1E",
+    generator_y: "8ABE1D7520F9C2A45CB1EB8E95CFD55262B70B29FEEC5864E19C054FF9912928\
+                  0E4646217791811142820341263C5315",
+    order: "8CB91E82A3386D280F5D6F7E50E641DF152F7109ED5456B31F166E6CAC0425A7\
+            CF3AB6AF6B7FC3103B883202E9046565",
+    cofactor: 1,
+};
+
+impl NamedCurve {
+    /// Alle bekannten Kurven.
+    pub const ALL: [NamedCurve; 5] = [
+        NamedCurve::Secp256k1,
+        NamedCurve::P256,
+        NamedCurve::P384,
+        NamedCurve::BrainpoolP256r1,
+        NamedCurve::BrainpoolP384r1,
+    ];
+
+    /// Der Name der Kurve, wie er im jeweiligen Standard verwendet wird.
+    pub fn name(&self) -> &'static str {
+        match self {
+            NamedCurve::Secp256k1 => "secp256k1",
+            NamedCurve::P256 => "P-256",
+            NamedCurve::P384 => "P-384",
+            NamedCurve::BrainpoolP256r1 => "brainpoolP256r1",
+            NamedCurve::BrainpoolP384r1 => "brainpoolP384r1",
+        }
+    }
+
+    /// Weitere gebräuchliche Namen der Kurve, z.B. aus SEC 2 oder OpenSSL.
+    fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            NamedCurve::Secp256k1 => &[],
+            NamedCurve::P256 => &["secp256r1", "prime256v1"],
+            NamedCurve::P384 => &["secp384r1"],
+            NamedCurve::BrainpoolP256r1 => &[],
+            NamedCurve::BrainpoolP384r1 => &[],
+        }
+    }
+
+    /// Erstellt die Kurve mit den Domain-Parametern aus dem Standard.
+    ///
+    /// # Rückgabe
+    /// * `WeierstrassEllipticCurve` - Die Kurve inklusive Generator, Ordnung und Kofaktor.
+    pub fn curve(&self) -> WeierstrassEllipticCurve {
+        let parameters = match self {
+            NamedCurve::Secp256k1 => &SECP256K1,
+            NamedCurve::P256 => &P256,
+            NamedCurve::P384 => &P384,
+            NamedCurve::BrainpoolP256r1 => &BRAINPOOL_P256R1,
+            NamedCurve::BrainpoolP384r1 => &BRAINPOOL_P384R1,
+        };
+        // Die Parameter sind fest hinterlegt und werden in den Tests geprüft, daher kann hier
+        // nichts fehlschlagen.
+        WeierstrassEllipticCurve::new(
+            parse_hex(parameters.a),
+            parse_hex(parameters.b),
+            parse_hex(parameters.prime),
+            parse_hex(parameters.order),
+            parameters.cofactor.into(),
+            FiniteFieldEllipticCurvePoint::new(
+                parse_hex(parameters.generator_x),
+                parse_hex(parameters.generator_y),
+            ),
+        )
+        .expect("Invalid domain parameters for named curve")
+    }
+}
+
+impl Display for NamedCurve {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for NamedCurve {
+    type Err = EllipticCurveError;
+
+    /// Sucht eine Kurve anhand ihres Namens oder eines Alias. Groß- und Kleinschreibung wird
+    /// ignoriert.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        NamedCurve::ALL
+            .into_iter()
+            .find(|curve| {
+                curve.name().eq_ignore_ascii_case(name)
+                    || curve
+                        .aliases()
+                        .iter()
+                        .any(|alias| alias.eq_ignore_ascii_case(name))
+            })
+            .ok_or_else(|| EllipticCurveError::UnknownCurveError(name.to_string()))
+    }
+}
+
+fn parse_hex(value: &str) -> BigInt {
+    BigInt::from_str_radix(value, 16).expect("Invalid hex constant")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math_core::ecc::elliptic_curve::EllipticCurve;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+    use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+
+    fn point(x: &str, y: &str) -> FiniteFieldEllipticCurvePoint {
+        FiniteFieldEllipticCurvePoint::new(parse_hex(x), parse_hex(y))
+    }
+
+    #[test]
+    fn test_domain_parameters_are_valid() {
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        for named_curve in NamedCurve::ALL {
+            let curve = named_curve.curve();
+            assert!(curve.verify(20, &service), "{}", named_curve);
+        }
+    }
+
+    #[test]
+    fn test_point_multiplication_vectors() {
+        let service = NumberTheoryWithPrngService::new(Fast, 13).number_theory_service;
+
+        // Testvektoren für k = 2 und k = 112233445566778899 aus der Sammlung von NIST
+        // (ECC point multiplication test vectors) bzw. für secp256k1 aus SEC 2.
+        let vectors = [
+            (
+                NamedCurve::Secp256k1,
+                BigInt::from(2),
+                point(
+                    "C6047F9441ED7D6D3045406E95C07CD85C778E4B8CEF3CA7ABAC09B95C709EE5",
+                    "1AE168FEA63DC339A3C58419466CEAEEF7F632653266D0E1236431A950CFE52A",
+                ),
+            ),
+            (
+                NamedCurve::Secp256k1,
+                BigInt::from(3),
+                point(
+                    "F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+                    "388F7B0F632DE8140FE337E62A37F3566500A99934C2231B6CB9FD7584B8E672",
+                ),
+            ),
+            (
+                NamedCurve::P256,
+                BigInt::from(2),
+                point(
+                    "7CF27B188D034F7E8A52380304B51AC3C08969E277F21B35A60B48FC47669978",
+                    "07775510DB8ED040293D9AC69F7430DBBA7DADE63CE982299E04B79D227873D1",
+                ),
+            ),
+            (
+                NamedCurve::P256,
+                BigInt::from(112233445566778899u64),
+                point(
+                    "339150844EC15234807FE862A86BE77977DBFB3AE3D96F4C22795513AEAAB82F",
+                    "B1C14DDFDC8EC1B2583F51E85A5EB3A155840F2034730E9B5ADA38B674336A21",
+                ),
+            ),
+            (
+                NamedCurve::P384,
+                BigInt::from(2),
+                point(
+                    "08D999057BA3D2D969260045C55B97F089025959A6F434D651D207D19FB96E9E\
+                     4FE0E86EBE0E64F85B96A9C75295DF61",
+                    "8E80F1FA5B1B3CEDB7BFE8DFFD6DBA74B275D875BC6CC43E904E505F256AB425\
+                     5FFD43E94D39E22D61501E700A940E80",
+                ),
+            ),
+        ];
+
+        for (named_curve, k, expected) in vectors {
+            let curve = named_curve.curve();
+            let actual = curve.generator().multiply(&k, &curve, &service).unwrap();
+            assert_eq!(actual, expected, "{} mit k = {}", named_curve, k);
+        }
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "secp256k1".parse::<NamedCurve>().unwrap(),
+            NamedCurve::Secp256k1
+        );
+        assert_eq!("p-256".parse::<NamedCurve>().unwrap(), NamedCurve::P256);
+        assert_eq!(
+            "prime256v1".parse::<NamedCurve>().unwrap(),
+            NamedCurve::P256
+        );
+        assert_eq!("secp384r1".parse::<NamedCurve>().unwrap(), NamedCurve::P384);
+        assert_eq!(
+            "brainpoolP384r1".parse::<NamedCurve>().unwrap(),
+            NamedCurve::BrainpoolP384r1
+        );
+        assert!(matches!(
+            "P-512".parse::<NamedCurve>(),
+            Err(EllipticCurveError::UnknownCurveError(_))
+        ));
+    }
+}
@@ -0,0 +1,328 @@
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::{BigInt, Sign};
+use bigdecimal::num_traits::Euclid;
+
+use crate::math_core::ecc::elliptic_curve::EllipticCurve;
+use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
+use crate::math_core::number_theory::number_theory_service::{
+    NumberTheoryService, NumberTheoryServiceTrait,
+};
+use crate::math_core::traits::parity::Parity;
+use crate::shared::errors::EllipticCurveError::{InvalidPointEncodingError, PointNotOnCurveError};
+
+/// Präfix des Punktes im Unendlichen.
+const INFINITY_PREFIX: u8 = 0x00;
+/// Präfix eines komprimierten Punktes mit gerader y-Koordinate, bei ungerader y-Koordinate 0x03.
+const COMPRESSED_EVEN_PREFIX: u8 = 0x02;
+const COMPRESSED_ODD_PREFIX: u8 = 0x03;
+/// Präfix eines unkomprimierten Punktes.
+const UNCOMPRESSED_PREFIX: u8 = 0x04;
+
+///
+/// Die Form eines Punktes als Oktett-String nach SEC 1 (Abschnitt 2.3.3). Der Punkt im
+/// Unendlichen wird unabhängig davon immer als einzelnes Nullbyte kodiert.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PointEncoding {
+    /// 0x02 bzw. 0x03 je nach Parität von y, gefolgt von x.
+    Compressed,
+    /// 0x04, gefolgt von x und y.
+    Uncompressed,
+}
+
+impl FiniteFieldEllipticCurvePoint {
+    /// Kodiert den Punkt als Oktett-String nach SEC 1. Die Koordinaten werden dabei mit
+    /// führenden Nullen auf die Bytelänge des Modulus p aufgefüllt.
+    ///
+    /// # Argumente
+    /// * `curve` - Die Kurve, auf der der Punkt liegt.
+    /// * `encoding` - Komprimierte oder unkomprimierte Darstellung.
+    ///
+    /// # Rückgabe
+    /// * `Vec<u8>` - Der Oktett-String.
+    pub fn to_sec1_bytes<C: EllipticCurve>(&self, curve: &C, encoding: PointEncoding) -> Vec<u8> {
+        if self.is_infinite {
+            return vec![INFINITY_PREFIX];
+        }
+        let length = field_length(curve.prime());
+        let mut bytes = Vec::with_capacity(1 + 2 * length);
+        match encoding {
+            PointEncoding::Compressed => {
+                bytes.push(if self.y.is_odd() {
+                    COMPRESSED_ODD_PREFIX
+                } else {
+                    COMPRESSED_EVEN_PREFIX
+                });
+                bytes.extend(to_fixed_length_bytes(&self.x, length));
+            }
+            PointEncoding::Uncompressed => {
+                bytes.push(UNCOMPRESSED_PREFIX);
+                bytes.extend(to_fixed_length_bytes(&self.x, length));
+                bytes.extend(to_fixed_length_bytes(&self.y, length));
+            }
+        }
+        bytes
+    }
+
+    /// Liest einen Punkt aus einem Oktett-String nach SEC 1. Bei der komprimierten Form wird y
+    /// als Quadratwurzel von x^3 + ax + b (mod p) mit der Parität aus dem Präfix bestimmt.
+    ///
+    /// # Argumente
+    /// * `bytes` - Der Oktett-String.
+    /// * `curve` - Die Kurve, auf der der Punkt liegt.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// * `FiniteFieldEllipticCurvePoint` - Der Punkt.
+    ///
+    /// # Fehler
+    /// * `EllipticCurveError::InvalidPointEncodingError` - Falls Präfix oder Länge ungültig
+    ///   sind, eine Koordinate nicht kleiner als p ist oder zu x keine y-Koordinate existiert.
+    /// * `EllipticCurveError::PointNotOnCurveError` - Falls ein unkomprimierter Punkt nicht auf
+    ///   der Kurve liegt.
+    pub fn from_sec1_bytes<C: EllipticCurve>(
+        bytes: &[u8],
+        curve: &C,
+        service: &NumberTheoryService,
+    ) -> Result<Self> {
+        let prime = curve.prime();
+        let length = field_length(prime);
+        let (prefix, coordinates) = match bytes.split_first() {
+            Some(split) => split,
+            None => return Err(InvalidPointEncodingError("empty octet string".into()).into()),
+        };
+
+        match *prefix {
+            INFINITY_PREFIX => {
+                ensure!(
+                    coordinates.is_empty(),
+                    InvalidPointEncodingError("point at infinity must be a single byte".into())
+                );
+                Ok(FiniteFieldEllipticCurvePoint::infinite())
+            }
+            COMPRESSED_EVEN_PREFIX | COMPRESSED_ODD_PREFIX => {
+                ensure!(
+                    coordinates.len() == length,
+                    InvalidPointEncodingError(format!(
+                        "compressed point must have {} bytes, but has {}",
+                        1 + length,
+                        bytes.len()
+                    ))
+                );
+                let x = read_coordinate(coordinates, prime)?;
+                let right_side = (x.pow(3) + curve.a() * &x + curve.b()).rem_euclid(prime);
+                let mut y = match service.modulo_square_root(&right_side, prime) {
+                    Some(y) => y,
+                    None => {
+                        return Err(
+                            InvalidPointEncodingError(format!("no point with x = {}", x)).into(),
+                        )
+                    }
+                };
+                if y.is_odd() != (*prefix == COMPRESSED_ODD_PREFIX) {
+                    // Für y = 0 gibt es nur eine Wurzel, deren Parität dann nicht passt.
+                    ensure!(
+                        y != BigInt::from(0),
+                        InvalidPointEncodingError(format!("no odd y for x = {}", x))
+                    );
+                    y = prime - y;
+                }
+                Ok(FiniteFieldEllipticCurvePoint::new(x, y))
+            }
+            UNCOMPRESSED_PREFIX => {
+                ensure!(
+                    coordinates.len() == 2 * length,
+                    InvalidPointEncodingError(format!(
+                        "uncompressed point must have {} bytes, but has {}",
+                        1 + 2 * length,
+                        bytes.len()
+                    ))
+                );
+                let x = read_coordinate(&coordinates[..length], prime)?;
+                let y = read_coordinate(&coordinates[length..], prime)?;
+                let point = FiniteFieldEllipticCurvePoint::new(x, y);
+                ensure!(
+                    curve.has_point(&point),
+                    PointNotOnCurveError(point, curve.to_string())
+                );
+                Ok(point)
+            }
+            prefix => {
+                Err(InvalidPointEncodingError(format!("unknown prefix {:#04x}", prefix)).into())
+            }
+        }
+    }
+}
+
+/// Die Anzahl der Bytes, die für ein Element aus Z_p benötigt werden.
+fn field_length(prime: &BigInt) -> usize {
+    prime.bits().div_ceil(8) as usize
+}
+
+/// Wandelt eine nichtnegative Zahl in Big-Endian-Bytes der Länge `length` um.
+fn to_fixed_length_bytes(value: &BigInt, length: usize) -> Vec<u8> {
+    let (_, bytes) = value.to_bytes_be();
+    let mut padded = vec![0u8; length.saturating_sub(bytes.len())];
+    padded.extend(bytes);
+    padded
+}
+
+/// Liest eine Koordinate und prüft, dass sie kleiner als p ist.
+fn read_coordinate(bytes: &[u8], prime: &BigInt) -> Result<BigInt> {
+    let value = BigInt::from_bytes_be(Sign::Plus, bytes);
+    ensure!(
+        &value < prime,
+        InvalidPointEncodingError(format!("coordinate {} is not smaller than p", value))
+    );
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math_core::ecc::named_curves::NamedCurve;
+    use crate::math_core::ecc::secure_finite_field_elliptic_curve::SecureFiniteFieldEllipticCurve;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+    use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+    use crate::shared::errors::EllipticCurveError;
+    use crate::shared::hex::{from_hex, to_hex};
+    use bigdecimal::One;
+
+    #[test]
+    fn test_standard_generators() {
+        let service = NumberTheoryService::new(Fast);
+        let p256 = NamedCurve::P256.curve();
+        let compressed = "036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296";
+        assert_eq!(
+            to_hex(
+                &p256
+                    .generator
+                    .to_sec1_bytes(&p256, PointEncoding::Compressed)
+            ),
+            compressed
+        );
+        assert_eq!(
+            to_hex(
+                &p256
+                    .generator
+                    .to_sec1_bytes(&p256, PointEncoding::Uncompressed)
+            ),
+            "046b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296\
+             4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5"
+        );
+        let decoded = FiniteFieldEllipticCurvePoint::from_sec1_bytes(
+            &from_hex(compressed).unwrap(),
+            &p256,
+            &service,
+        )
+        .unwrap();
+        assert_eq!(decoded, p256.generator);
+
+        let secp256k1 = NamedCurve::Secp256k1.curve();
+        let compressed = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        assert_eq!(
+            to_hex(
+                &secp256k1
+                    .generator
+                    .to_sec1_bytes(&secp256k1, PointEncoding::Compressed)
+            ),
+            compressed
+        );
+        let decoded = FiniteFieldEllipticCurvePoint::from_sec1_bytes(
+            &from_hex(compressed).unwrap(),
+            &secp256k1,
+            &service,
+        )
+        .unwrap();
+        assert_eq!(decoded, secp256k1.generator);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        let number_theory_service = &service.number_theory_service;
+        // p ≡ 5 (mod 8) bei den sicheren Kurven, p ≡ 3 (mod 4) bei P-384.
+        let secure_curve = SecureFiniteFieldEllipticCurve::new(5, 64, 40, &service).unwrap();
+        let p384 = NamedCurve::P384.curve();
+
+        for _ in 0..10 {
+            let k = service
+                .take_random_number_in_range(&BigInt::one(), secure_curve.order_of_subgroup());
+            let point = secure_curve
+                .generator
+                .multiply(&k, &secure_curve, number_theory_service)
+                .unwrap();
+            for encoding in [PointEncoding::Compressed, PointEncoding::Uncompressed] {
+                let bytes = point.to_sec1_bytes(&secure_curve, encoding);
+                let decoded = FiniteFieldEllipticCurvePoint::from_sec1_bytes(
+                    &bytes,
+                    &secure_curve,
+                    number_theory_service,
+                )
+                .unwrap();
+                assert_eq!(decoded, point);
+            }
+
+            let point = p384
+                .generator
+                .multiply(&k, &p384, number_theory_service)
+                .unwrap();
+            let bytes = point.to_sec1_bytes(&p384, PointEncoding::Compressed);
+            assert_eq!(bytes.len(), 49);
+            let decoded = FiniteFieldEllipticCurvePoint::from_sec1_bytes(
+                &bytes,
+                &p384,
+                number_theory_service,
+            )
+            .unwrap();
+            assert_eq!(decoded, point);
+        }
+
+        let infinite = FiniteFieldEllipticCurvePoint::infinite();
+        let bytes = infinite.to_sec1_bytes(&p384, PointEncoding::Compressed);
+        assert_eq!(bytes, vec![0]);
+        assert!(
+            FiniteFieldEllipticCurvePoint::from_sec1_bytes(&bytes, &p384, number_theory_service)
+                .unwrap()
+                .is_infinite
+        );
+    }
+
+    #[test]
+    fn test_invalid_encodings() {
+        let service = NumberTheoryService::new(Fast);
+        let p256 = NamedCurve::P256.curve();
+        let is_invalid_encoding = |bytes: &[u8]| {
+            matches!(
+                FiniteFieldEllipticCurvePoint::from_sec1_bytes(bytes, &p256, &service)
+                    .unwrap_err()
+                    .downcast_ref::<EllipticCurveError>(),
+                Some(InvalidPointEncodingError(_))
+            )
+        };
+
+        assert!(is_invalid_encoding(&[]));
+        assert!(is_invalid_encoding(&[0x00, 0x00]));
+        assert!(is_invalid_encoding(&[0x05; 33]));
+        assert!(is_invalid_encoding(&[0x02; 32]));
+        // x = p ist keine gültige Koordinate.
+        let mut bytes = vec![0x02];
+        bytes.extend(to_fixed_length_bytes(&p256.prime, 32));
+        assert!(is_invalid_encoding(&bytes));
+        // Zu x = 1 gibt es auf P-256 keinen Punkt.
+        let mut bytes = vec![0x02];
+        bytes.extend(to_fixed_length_bytes(&BigInt::one(), 32));
+        assert!(is_invalid_encoding(&bytes));
+
+        let mut not_on_curve = p256
+            .generator
+            .to_sec1_bytes(&p256, PointEncoding::Uncompressed);
+        not_on_curve[64] ^= 1;
+        assert!(matches!(
+            FiniteFieldEllipticCurvePoint::from_sec1_bytes(&not_on_curve, &p256, &service)
+                .unwrap_err()
+                .downcast_ref::<EllipticCurveError>(),
+            Some(PointNotOnCurveError(..))
+        ));
+    }
+}
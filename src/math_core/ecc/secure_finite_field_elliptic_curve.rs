@@ -3,7 +3,6 @@ use std::ops::{AddAssign, Div, Neg};
 
 use crate::api::endpoints::mv::EllipticCurveBean;
 use anyhow::{ensure, Context, Result};
-use atomic_counter::RelaxedCounter;
 use bigdecimal::num_bigint::BigInt;
 use bigdecimal::num_traits::Euclid;
 use bigdecimal::{One, Signed, Zero};
@@ -11,12 +10,12 @@ use log::warn;
 use num::Integer;
 
 use crate::math_core::complex_number::{complex_euclidean_algorithm, ComplexNumber};
+use crate::math_core::ecc::elliptic_curve::EllipticCurve;
 use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
 use crate::math_core::number_theory::number_theory_service::{
     NumberTheoryService, NumberTheoryServiceTrait,
 };
 use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
-use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
 use crate::math_core::traits::divisible::Divisible;
 use crate::math_core::traits::increment::Increment;
 
@@ -51,6 +50,29 @@ impl Display for SecureFiniteFieldEllipticCurve {
     }
 }
 
+impl EllipticCurve for SecureFiniteFieldEllipticCurve {
+    fn a(&self) -> BigInt {
+        self.a.into()
+    }
+
+    /// Die sicheren Kurven haben immer b = 0.
+    fn b(&self) -> BigInt {
+        BigInt::zero()
+    }
+
+    fn prime(&self) -> &BigInt {
+        &self.prime
+    }
+
+    fn order_of_subgroup(&self) -> &BigInt {
+        &self.order_of_subgroup
+    }
+
+    fn generator(&self) -> &FiniteFieldEllipticCurvePoint {
+        &self.generator
+    }
+}
+
 impl From<EllipticCurveBean> for SecureFiniteFieldEllipticCurve {
     /// Mapped die Bean in das Domain-Modell
     fn from(curve: EllipticCurveBean) -> Self {
@@ -92,11 +114,9 @@ impl SecureFiniteFieldEllipticCurve {
         // Wird für einen späteren Vergleich benötigt
         let double_n = BigInt::from(n).double();
 
-        let prng = PseudoRandomNumberGenerator::new_seeded();
-        let counter = RelaxedCounter::new(1);
         let mut prime: BigInt;
         loop {
-            prime = prng.generate_prime(modul_width, miller_rabin_iterations, &counter);
+            prime = service_wrapper.generate_prime_with_width(modul_width, miller_rabin_iterations);
             // Die Primzahl muss mod 8 kongruent 5 genügen und darf 2n nicht teilen
             if prime.rem_euclid(&8.into()) == 5.into() && !double_n.is_multiple_of(&prime) {
                 break;
@@ -388,17 +408,6 @@ impl SecureFiniteFieldEllipticCurve {
         }
         Ok(generator)
     }
-
-    /// Überprüft, ob ein Punkt auf der elliptischen Kurve liegt.
-    pub fn has_point(&self, point: &FiniteFieldEllipticCurvePoint) -> bool {
-        let x_squared = &point.x.pow(2);
-        let x_cubed = &point.x * x_squared;
-        let y_squared = point.y.pow(2);
-
-        // y^2 = x^3 + ax (mod p) ist äquivalent zu (x^3 + ax - y^2) % p == 0
-        let remainder = (x_cubed + &self.a * &point.x - y_squared).rem_euclid(&self.prime);
-        remainder == BigInt::zero()
-    }
 }
 
 #[cfg(test)]
@@ -544,6 +553,18 @@ mod tests {
         assert!(curve.has_point(&point));
     }
 
+    #[test]
+    fn test_curve_is_reproducible_from_seed() {
+        let generate = |seed| {
+            let service = NumberTheoryWithPrngService::new(Fast, seed);
+            SecureFiniteFieldEllipticCurve::new(5, 32, 40, &service).unwrap()
+        };
+        let (first, second) = (generate(23), generate(23));
+        assert_eq!(first.prime, second.prime);
+        assert_eq!(first.order_of_subgroup, second.order_of_subgroup);
+        assert_eq!(first.generator, second.generator);
+    }
+
     #[test]
     fn test_calculate_legendre_symbol() {
         let prime = BigInt::from(13);
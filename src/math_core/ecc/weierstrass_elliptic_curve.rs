@@ -0,0 +1,300 @@
+use std::fmt::Display;
+
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::num_traits::Euclid;
+use bigdecimal::Zero;
+
+use crate::math_core::ecc::elliptic_curve::EllipticCurve;
+use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
+use crate::math_core::ecc::secure_finite_field_elliptic_curve::SecureFiniteFieldEllipticCurve;
+use crate::math_core::number_theory_with_prng_service::NumberTheoryWithPrngService;
+use crate::math_core::traits::increment::Increment;
+use crate::shared::errors::EllipticCurveError;
+
+///
+/// Repräsentiert eine allgemeine elliptische Kurve in kurzer Weierstraß-Form
+/// y^2 = x^3 + a · x + b (mod p) mit beliebigen Koeffizienten a und b sowie einer zyklischen
+/// Untergruppe der Ordnung q, die von einem festen Generator erzeugt wird.
+///
+/// Anders als bei `SecureFiniteFieldEllipticCurve` werden die Parameter nicht erzeugt, sondern
+/// vorgegeben, z.B. aus einem Standard.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct WeierstrassEllipticCurve {
+    /// Der Koeffizient a der elliptischen Kurve, reduziert modulo p
+    pub a: BigInt,
+    /// Der Koeffizient b der elliptischen Kurve, reduziert modulo p
+    pub b: BigInt,
+    /// Der Modulus p der elliptischen Kurve
+    pub prime: BigInt,
+    /// Die Ordnung der zyklischen Untergruppe / des Generators
+    pub order_of_subgroup: BigInt,
+    /// Der Kofaktor h = |E(Z_p)| / q
+    pub cofactor: BigInt,
+    /// Der Generator der zyklischen Untergruppe
+    pub generator: FiniteFieldEllipticCurvePoint,
+}
+
+impl Display for WeierstrassEllipticCurve {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "y^2 = x^3 + {} * x + {} (mod {}),\nGenerator: {}",
+            self.a, self.b, self.prime, self.generator
+        )
+    }
+}
+
+impl EllipticCurve for WeierstrassEllipticCurve {
+    fn a(&self) -> BigInt {
+        self.a.clone()
+    }
+
+    fn b(&self) -> BigInt {
+        self.b.clone()
+    }
+
+    fn prime(&self) -> &BigInt {
+        &self.prime
+    }
+
+    fn order_of_subgroup(&self) -> &BigInt {
+        &self.order_of_subgroup
+    }
+
+    fn generator(&self) -> &FiniteFieldEllipticCurvePoint {
+        &self.generator
+    }
+}
+
+impl From<SecureFiniteFieldEllipticCurve> for WeierstrassEllipticCurve {
+    /// Eine sichere Kurve y^2 = x^3 - n^2 · x ist der Spezialfall a = -n^2 und b = 0.
+    fn from(curve: SecureFiniteFieldEllipticCurve) -> Self {
+        WeierstrassEllipticCurve {
+            a: BigInt::from(curve.a).rem_euclid(&curve.prime),
+            b: BigInt::zero(),
+            prime: curve.prime,
+            order_of_subgroup: curve.order_of_subgroup,
+            // Die sicheren Kurven haben die Gruppenordnung N = 8q.
+            cofactor: BigInt::from(8),
+            generator: curve.generator,
+        }
+    }
+}
+
+impl WeierstrassEllipticCurve {
+    /// Erstellt eine neue Kurve aus vorgegebenen Parametern.
+    ///
+    /// # Argumente
+    /// * `a` - Der Koeffizient a, wird modulo p reduziert.
+    /// * `b` - Der Koeffizient b, wird modulo p reduziert.
+    /// * `prime` - Der Modulus p.
+    /// * `order_of_subgroup` - Die Ordnung q des Generators.
+    /// * `cofactor` - Der Kofaktor h = |E(Z_p)| / q.
+    /// * `generator` - Der Generator der Untergruppe.
+    ///
+    /// # Rückgabe
+    /// * `WeierstrassEllipticCurve` - Die Kurve.
+    ///
+    /// # Fehler
+    /// * `EllipticCurveError::InvalidPrimeError` - Falls p nicht größer als 3 ist. In
+    ///   Charakteristik 2 und 3 lässt sich nicht jede Kurve in kurzer Weierstraß-Form schreiben.
+    /// * `EllipticCurveError::SingularCurveError` - Falls 4a^3 + 27b^2 ≡ 0 (mod p) gilt.
+    /// * `EllipticCurveError::InvalidGeneratorError` - Falls der Generator im Unendlichen oder
+    ///   nicht auf der Kurve liegt.
+    ///
+    /// Ob p und q tatsächlich Primzahlen sind, q die Ordnung des Generators ist und der
+    /// Kofaktor passt, wird hier nicht geprüft, siehe dazu `verify`.
+    pub fn new(
+        a: BigInt,
+        b: BigInt,
+        prime: BigInt,
+        order_of_subgroup: BigInt,
+        cofactor: BigInt,
+        generator: FiniteFieldEllipticCurvePoint,
+    ) -> Result<Self> {
+        ensure!(
+            prime > BigInt::from(3),
+            EllipticCurveError::InvalidPrimeError(prime.to_string())
+        );
+        let a = a.rem_euclid(&prime);
+        let b = b.rem_euclid(&prime);
+
+        let discriminant =
+            (BigInt::from(4) * a.pow(3) + BigInt::from(27) * b.pow(2)).rem_euclid(&prime);
+        ensure!(
+            !discriminant.is_zero(),
+            EllipticCurveError::SingularCurveError(a.to_string(), b.to_string(), prime.to_string())
+        );
+
+        let curve = WeierstrassEllipticCurve {
+            a,
+            b,
+            prime,
+            order_of_subgroup,
+            cofactor,
+            generator,
+        };
+        ensure!(
+            !curve.generator.is_infinite && curve.has_point(&curve.generator),
+            EllipticCurveError::InvalidGeneratorError(curve.generator.clone())
+        );
+        Ok(curve)
+    }
+
+    /// Überprüft, ob p und q (wahrscheinlich) Primzahlen sind, der Generator die Ordnung q hat und
+    /// die Gruppenordnung h · q die Hasse-Schranke |h · q - (p + 1)| <= 2 · sqrt(p) einhält.
+    ///
+    /// # Argumente
+    /// * `miller_rabin_iterations` - Die Anzahl der Iterationen für den Miller-Rabin-Test.
+    /// * `service` - Der Service für die Zahlentheorie und die Zufallszahlen.
+    ///
+    /// # Rückgabe
+    /// * `bool` - Ob die Parameter gültig sind.
+    pub fn verify(
+        &self,
+        miller_rabin_iterations: u32,
+        service: &NumberTheoryWithPrngService,
+    ) -> bool {
+        if !service.is_probably_prime(&self.prime, miller_rabin_iterations)
+            || !service.is_probably_prime(&self.order_of_subgroup, miller_rabin_iterations)
+        {
+            return false;
+        }
+        let trace: BigInt = &self.cofactor * &self.order_of_subgroup - self.prime.increment();
+        if trace.pow(2) > BigInt::from(4) * &self.prime {
+            return false;
+        }
+        // Da `multiply` für (Generator, q) direkt den Punkt im Unendlichen liefert, wird hier
+        // (q - 1) · G + G berechnet.
+        self.generator
+            .multiply(
+                &self.order_of_subgroup.decrement(),
+                self,
+                &service.number_theory_service,
+            )
+            .and_then(|point| point.add(&self.generator, self, &service.number_theory_service))
+            .map(|point| point.is_infinite)
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+
+    /// y^2 = x^3 + 2x + 2 (mod 17) mit dem Generator (5, 1) der Ordnung 19.
+    fn small_curve() -> WeierstrassEllipticCurve {
+        WeierstrassEllipticCurve::new(
+            2.into(),
+            2.into(),
+            17.into(),
+            19.into(),
+            1.into(),
+            FiniteFieldEllipticCurvePoint::new(5.into(), 1.into()),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_point_arithmetic_with_b() {
+        let curve = small_curve();
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        let service = &service.number_theory_service;
+        let generator = &curve.generator;
+
+        let doubled = generator.double(&curve, service);
+        assert_eq!(
+            doubled,
+            FiniteFieldEllipticCurvePoint::new(6.into(), 3.into())
+        );
+        let tripled = doubled.add(generator, &curve, service).unwrap();
+        assert_eq!(
+            tripled,
+            FiniteFieldEllipticCurvePoint::new(10.into(), 6.into())
+        );
+
+        for k in 1..19 {
+            let point = generator.multiply(&k.into(), &curve, service).unwrap();
+            assert!(!point.is_infinite);
+            assert!(curve.has_point(&point));
+        }
+        let point = generator.multiply(&18.into(), &curve, service).unwrap();
+        assert!(point.add(generator, &curve, service).unwrap().is_infinite);
+    }
+
+    #[test]
+    fn test_verify() {
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        assert!(small_curve().verify(20, &service));
+
+        let mut wrong_order = small_curve();
+        wrong_order.order_of_subgroup = 23.into();
+        assert!(!wrong_order.verify(20, &service));
+
+        // Mit h = 2 wäre die Gruppenordnung 38 und läge außerhalb der Hasse-Schranke.
+        let mut wrong_cofactor = small_curve();
+        wrong_cofactor.cofactor = 2.into();
+        assert!(!wrong_cofactor.verify(20, &service));
+    }
+
+    #[test]
+    fn test_rejects_invalid_parameters() {
+        let generator = FiniteFieldEllipticCurvePoint::new(5.into(), 1.into());
+
+        // 4 · (-3)^3 + 27 · 2^2 = 0, die Kurve y^2 = x^3 - 3x + 2 ist singulär.
+        let singular = WeierstrassEllipticCurve::new(
+            (-3).into(),
+            2.into(),
+            17.into(),
+            19.into(),
+            1.into(),
+            generator.clone(),
+        );
+        assert!(matches!(
+            singular.unwrap_err().downcast_ref::<EllipticCurveError>(),
+            Some(EllipticCurveError::SingularCurveError(..))
+        ));
+
+        let small_prime = WeierstrassEllipticCurve::new(
+            2.into(),
+            2.into(),
+            3.into(),
+            19.into(),
+            1.into(),
+            generator,
+        );
+        assert!(small_prime.is_err());
+
+        let not_on_curve = WeierstrassEllipticCurve::new(
+            2.into(),
+            2.into(),
+            17.into(),
+            19.into(),
+            1.into(),
+            FiniteFieldEllipticCurvePoint::new(5.into(), 2.into()),
+        );
+        assert!(not_on_curve.is_err());
+    }
+
+    #[test]
+    fn test_from_secure_curve() {
+        let service = NumberTheoryWithPrngService::new(Fast, 13);
+        let secure = SecureFiniteFieldEllipticCurve::new(5, 32, 40, &service).unwrap();
+        let general = WeierstrassEllipticCurve::from(secure.clone());
+        assert!(general.verify(40, &service));
+
+        let k = BigInt::from(12345);
+        let expected = secure
+            .generator
+            .multiply(&k, &secure, &service.number_theory_service)
+            .unwrap();
+        let actual = general
+            .generator
+            .multiply(&k, &general, &service.number_theory_service)
+            .unwrap();
+        assert_eq!(expected, actual);
+    }
+}
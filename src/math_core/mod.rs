@@ -1,7 +1,11 @@
 pub mod babystep_giantstep;
 pub mod complex_number;
+pub mod csprng;
 pub mod ecc;
 pub mod number_theory;
 pub mod number_theory_with_prng_service;
+pub mod prime_generator;
 pub mod pseudo_random_number_generator;
+pub mod random_source;
+pub mod randomness_tests;
 pub mod traits;
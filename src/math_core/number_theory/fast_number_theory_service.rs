@@ -10,7 +10,7 @@ use crate::math_core::number_theory::number_theory_service::{
     NumberTheoryService, NumberTheoryServiceTrait,
 };
 use crate::math_core::number_theory::primality_test::PrimalityTest;
-use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+use crate::math_core::random_source::RandomSource;
 use crate::shared::errors::ArithmeticError;
 
 #[derive(Clone, Copy, Debug)]
@@ -44,12 +44,7 @@ impl NumberTheoryServiceTrait for FastNumberTheoryService {
         Ok((modul + extended_euclid_result.y).rem_euclid(modul))
     }
 
-    fn is_probably_prime(
-        &self,
-        p: &BigInt,
-        repeats: u32,
-        random_generator: &PseudoRandomNumberGenerator,
-    ) -> bool {
+    fn is_probably_prime(&self, p: &BigInt, repeats: u32, random: &dyn RandomSource) -> bool {
         let primality_test = PrimalityTest::new(NumberTheoryService::new(Fast));
 
         // Enthält noch einige weitere Tests, die für slow nicht vorgesehen sind.
@@ -57,6 +52,6 @@ impl NumberTheoryServiceTrait for FastNumberTheoryService {
             return false;
         }
         // Sind die primitiven Tests bestanden, läuft miller_rabin an.
-        primality_test.miller_rabin(p, repeats, random_generator)
+        primality_test.miller_rabin(p, repeats, random)
     }
 }
@@ -2,4 +2,5 @@ pub mod extended_euclid_result;
 pub mod fast_number_theory_service;
 pub mod number_theory_service;
 pub mod primality_test;
+pub mod prime_sieve;
 pub mod slow_number_theory_service;
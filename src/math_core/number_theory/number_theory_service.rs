@@ -1,10 +1,10 @@
 use crate::math_core::number_theory::extended_euclid_result::ExtendedEuclidResult;
 use anyhow::Result;
-use num::BigInt;
+use num::{BigInt, Integer, One, Zero};
 
 use crate::math_core::number_theory::fast_number_theory_service::FastNumberTheoryService;
 use crate::math_core::number_theory::slow_number_theory_service::SlowNumberTheoryService;
-use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+use crate::math_core::random_source::RandomSource;
 
 /// Gibt an, ob die schnelle oder die langsame Implementierung des `NumberTheoryService` verwendet werden soll.
 pub enum NumberTheoryServiceSpeed {
@@ -59,18 +59,13 @@ impl NumberTheoryServiceTrait for NumberTheoryService {
         }
     }
 
-    fn is_probably_prime(
-        &self,
-        p: &BigInt,
-        repeats: u32,
-        random_generator: &PseudoRandomNumberGenerator,
-    ) -> bool {
+    fn is_probably_prime(&self, p: &BigInt, repeats: u32, random: &dyn RandomSource) -> bool {
         match self {
             NumberTheoryService::FastService(service) => {
-                service.is_probably_prime(p, repeats, random_generator)
+                service.is_probably_prime(p, repeats, random)
             }
             NumberTheoryService::SlowService(service) => {
-                service.is_probably_prime(p, repeats, random_generator)
+                service.is_probably_prime(p, repeats, random)
             }
         }
     }
@@ -158,17 +153,98 @@ pub trait NumberTheoryServiceTrait {
     /// # Argumente
     /// * `p`: Der Integer, für den der Primzahltest durchgeführt werden soll.
     /// * `repeats`: Die Anzahl der Wiederholungen des Tests.
-    /// * `random_generator`: Ein Pseudozufallszahlengenerator, der für die Erzeugung
-    ///   der Zufallszahlen verwendet wird.
+    /// * `random`: Die Quelle der Zeugen für den Test.
     ///
     /// # Rückgabe
     /// * `true`, wenn der Integer eine vermutlich Primzahl ist, `false`, wenn nicht.
-    fn is_probably_prime(
-        &self,
-        p: &BigInt,
-        repeats: u32,
-        random_generator: &PseudoRandomNumberGenerator,
-    ) -> bool;
+    fn is_probably_prime(&self, p: &BigInt, repeats: u32, random: &dyn RandomSource) -> bool;
+
+    /// Berechnet eine Quadratwurzel von `a` modulo einer ungeraden Primzahl `prime` mit dem
+    /// Algorithmus von Tonelli und Shanks. Für p ≡ 3 (mod 4) ist die Wurzel direkt
+    /// a^((p + 1) / 4).
+    ///
+    /// # Argumente
+    ///
+    /// * `a` - Die Zahl, deren Wurzel gesucht ist.
+    /// * `prime` - Der Modulus, eine ungerade Primzahl.
+    ///
+    /// # Rückgabewert
+    ///
+    /// * Eine Wurzel r mit r^2 ≡ a (mod p) und 0 <= r < p, falls `a` ein quadratischer Rest ist.
+    ///   Die zweite Wurzel ist p - r.
+    /// * `None`, falls `a` kein quadratischer Rest ist. Ist `prime` zusammengesetzt, kann der
+    ///   Algorithmus ebenfalls mit `None` abbrechen, ohne dass dies garantiert ist.
+    ///
+    /// # Beispiel
+    ///
+    /// ```rust
+    /// use bigdecimal::num_bigint::BigInt;
+    /// use encryption_tool::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+    /// use encryption_tool::math_core::number_theory::number_theory_service::{
+    ///     NumberTheoryService, NumberTheoryServiceTrait,
+    /// };
+    ///
+    /// let service = NumberTheoryService::new(Fast);
+    /// let root = service.modulo_square_root(&BigInt::from(10), &BigInt::from(13));
+    ///
+    /// assert_eq!(root, Some(BigInt::from(7)));
+    /// ```
+    fn modulo_square_root(&self, a: &BigInt, prime: &BigInt) -> Option<BigInt> {
+        let a = a.mod_floor(prime);
+        if a.is_zero() {
+            return Some(a);
+        }
+        let one = BigInt::one();
+        let p_minus_one = prime - &one;
+        let euler_exponent: BigInt = &p_minus_one >> 1;
+
+        // Euler-Kriterium: a ist genau dann ein quadratischer Rest, wenn a^((p - 1) / 2) ≡ 1 ist.
+        if self.fast_exponentiation(&a, &euler_exponent, prime) != one {
+            return None;
+        }
+        if (prime % 4u32) == BigInt::from(3) {
+            let exponent: BigInt = (prime + &one) >> 2;
+            return Some(self.fast_exponentiation(&a, &exponent, prime));
+        }
+
+        // p - 1 = q * 2^s mit ungeradem q
+        let s = p_minus_one.trailing_zeros().unwrap_or(0);
+        let q: BigInt = &p_minus_one >> s;
+
+        // Ein beliebiger quadratischer Nichtrest z. Für eine Primzahl ist die Hälfte aller
+        // Reste ein Nichtrest, bei zusammengesetztem Modulus gibt es womöglich keinen.
+        let mut z = BigInt::from(2);
+        while self.fast_exponentiation(&z, &euler_exponent, prime) != p_minus_one {
+            z += 1;
+            if &z >= prime {
+                return None;
+            }
+        }
+
+        let mut m = s;
+        let mut c = self.fast_exponentiation(&z, &q, prime);
+        let mut t = self.fast_exponentiation(&a, &q, prime);
+        let mut r = self.fast_exponentiation(&a, &((&q + &one) >> 1), prime);
+        while t != one {
+            // Kleinstes i mit t^(2^i) ≡ 1, es gilt 0 < i < m.
+            let mut i = 0;
+            let mut t_power = t.clone();
+            while t_power != one {
+                t_power = (&t_power * &t_power).mod_floor(prime);
+                i += 1;
+                // Nur bei zusammengesetztem Modulus möglich.
+                if i >= m {
+                    return None;
+                }
+            }
+            let b = self.fast_exponentiation(&c, &(BigInt::one() << (m - i - 1)), prime);
+            m = i;
+            c = (&b * &b).mod_floor(prime);
+            t = (t * &c).mod_floor(prime);
+            r = (r * b).mod_floor(prime);
+        }
+        Some(r)
+    }
 }
 
 #[cfg(test)]
@@ -178,6 +254,7 @@ mod tests {
     use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
         Fast, Slow,
     };
+    use crate::math_core::random_source::PrngRandomSource;
 
     use super::*;
 
@@ -249,17 +326,12 @@ mod tests {
     fn is_probably_prime_test() {
         let slow_service = NumberTheoryService::new(Slow);
         assert_eq!(
-            slow_service.is_probably_prime(
-                &11.into(),
-                100,
-                &PseudoRandomNumberGenerator::new(11, slow_service)
-            ),
+            slow_service.is_probably_prime(&11.into(), 100, &PrngRandomSource::seeded(11)),
             true
         );
 
         run_test_for_all_services(|service| {
-            let random_generator: &PseudoRandomNumberGenerator =
-                &PseudoRandomNumberGenerator::new(11, service);
+            let random_generator = &PrngRandomSource::seeded(11);
 
             assert_eq!(
                 service.is_probably_prime(
@@ -367,6 +439,50 @@ mod tests {
         });
     }
 
+    #[test]
+    fn modulo_square_root_test() {
+        run_test_for_all_services(|service| {
+            // 13 ≡ 5 (mod 8), 17 ≡ 1 (mod 16) und 23 ≡ 3 (mod 4) decken alle Zweige ab.
+            for prime in [13, 17, 23, 97, 65537] {
+                let prime = BigInt::from(prime);
+                let mut residues = 0;
+                for a in 0..200 {
+                    let a = BigInt::from(a);
+                    let is_residue = (&a % &prime).is_zero()
+                        || service.fast_exponentiation(&a, &((&prime - 1) >> 1), &prime)
+                            == BigInt::one();
+                    match service.modulo_square_root(&a, &prime) {
+                        Some(root) => {
+                            assert!(is_residue);
+                            assert!(root < prime);
+                            assert_eq!((&root * &root) % &prime, &a % &prime);
+                            residues += 1;
+                        }
+                        None => assert!(!is_residue, "{} mod {}", a, prime),
+                    }
+                }
+                assert!(residues > 0);
+            }
+
+            assert_eq!(
+                service.modulo_square_root(&10.into(), &13.into()),
+                Some(7.into())
+            );
+            assert_eq!(service.modulo_square_root(&5.into(), &13.into()), None);
+            assert_eq!(
+                service.modulo_square_root(&(-3).into(), &13.into()),
+                Some(7.into())
+            );
+
+            // 21 ≡ 1 (mod 4) ist zusammengesetzt und hat keinen Nichtrest im Sinne des
+            // Euler-Kriteriums, die Suche muss trotzdem enden.
+            for a in 0..21 {
+                service.modulo_square_root(&a.into(), &21.into());
+            }
+            assert_eq!(service.modulo_square_root(&1.into(), &21.into()), None);
+        });
+    }
+
     #[test]
     fn fast_exponentiation_big_numbers() {
         run_test_for_all_services(|service| {
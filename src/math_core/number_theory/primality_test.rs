@@ -1,11 +1,10 @@
-use atomic_counter::RelaxedCounter;
 use log::warn;
 use num::{BigInt, One, Zero};
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
 
 use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
 use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceTrait;
-use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+use crate::math_core::random_source::RandomSource;
 use crate::math_core::traits::divisible::Divisible;
 use crate::math_core::traits::increment::Increment;
 use crate::math_core::traits::parity::Parity;
@@ -53,17 +52,12 @@ impl PrimalityTest {
     /// # Arguments
     /// * `p`: Der Integer, für den der Primzahltest durchgeführt werden soll.
     /// * `repeats`: Die Anzahl der Wiederholungen des Tests.
-    /// * `random_generator`: Ein Pseudozufallszahlengenerator, der für die Erzeugung
-    ///   der Zufallszahlen verwendet wird.
+    /// * `random`: Die Quelle der Zeugen. Die Zeugen werden vorab der Reihe nach gezogen, damit
+    ///   eine Quelle mit Seed unabhängig von der parallelen Ausführung reproduzierbar bleibt.
     ///
     /// # Rückgabe
     /// * `true`, wenn der Integer wahrscheinlich eine Primzahl ist, `false`, wenn nicht.
-    pub fn miller_rabin(
-        &self,
-        p: &BigInt,
-        repeats: u32,
-        random_generator: &PseudoRandomNumberGenerator,
-    ) -> bool {
+    pub fn miller_rabin(&self, p: &BigInt, repeats: u32, random: &dyn RandomSource) -> bool {
         let mut d = p.decrement();
         let mut s = BigInt::zero();
 
@@ -72,16 +66,25 @@ impl PrimalityTest {
             s.increment_assign();
         }
 
-        // Zähler für den Zugriff auf das Element der Zufallsfolge.
-        let n_counter = RelaxedCounter::new(0);
-
-        (0..repeats).into_par_iter().all(|_| {
-            let mut a = random_generator.take(&2.into(), &p, &n_counter);
-            while p.is_divisible_by(&a) {
-                a = random_generator.take(&2.into(), &p, &n_counter);
+        let take_witness = || loop {
+            let a = random.take(&2.into(), p);
+            if !p.is_divisible_by(&a) {
+                break a;
             }
-            self.miller_rabin_iteration(p, &s, &d, &a)
-        })
+        };
+
+        if repeats == 0 {
+            return true;
+        }
+        // Die meisten zusammengesetzten Zahlen scheitern schon am ersten Zeugen. Erst danach
+        // werden die übrigen Zeugen gezogen und parallel geprüft.
+        if !self.miller_rabin_iteration(p, &s, &d, &take_witness()) {
+            return false;
+        }
+        let witnesses: Vec<BigInt> = (1..repeats).map(|_| take_witness()).collect();
+        witnesses
+            .into_par_iter()
+            .all(|a| self.miller_rabin_iteration(p, &s, &d, &a))
     }
 
     /// Diese Methode führt eine Iteration des Miller-Rabin-Primzahltests für den angegebenen Integer durch.
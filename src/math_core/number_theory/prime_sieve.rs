@@ -0,0 +1,275 @@
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::num_traits::ToPrimitive;
+use bigdecimal::One;
+use log::{debug, trace};
+
+use crate::math_core::random_source::RandomSource;
+
+/// Die Standardgrenze der Tabelle kleiner Primzahlen, mit denen gesiebt wird.
+pub const DEFAULT_SMALL_PRIME_BOUND: u32 = 2048;
+/// Die Standardlänge des gesiebten Intervalls in Kandidaten.
+pub const DEFAULT_INTERVAL_LENGTH: usize = 2048;
+
+///
+/// Inkrementelle Primzahlsuche: Statt nach jedem gescheiterten Miller-Rabin-Test einen neuen
+/// Zufallskandidaten zu ziehen, wird ein einziger zufälliger Startwert gezogen und das Intervall
+/// der folgenden Kandidaten mit einer Tabelle kleiner Primzahlen gesiebt. Der teure
+/// Miller-Rabin-Test läuft nur noch auf den Kandidaten, die das Sieb überstehen. Enthält das
+/// Intervall keine Primzahl, wird ein neuer Startwert gezogen.
+///
+/// Die Primzahlen sind dadurch nicht mehr ganz gleichverteilt: Primzahlen nach einer großen
+/// Lücke werden häufiger gefunden. Für die Sicherheit der Verfahren ist das unerheblich.
+///
+#[derive(Clone, Debug)]
+pub struct IncrementalPrimeSearch {
+    /// Die ungeraden Primzahlen unterhalb der gewählten Grenze, aufsteigend.
+    small_primes: Vec<u32>,
+    /// Die Anzahl der Kandidaten pro Intervall.
+    interval_length: usize,
+}
+
+impl Default for IncrementalPrimeSearch {
+    fn default() -> Self {
+        Self::new(DEFAULT_SMALL_PRIME_BOUND, DEFAULT_INTERVAL_LENGTH)
+    }
+}
+
+impl IncrementalPrimeSearch {
+    /// Erstellt die Suche mit allen ungeraden Primzahlen unterhalb der Grenze als Siebtabelle.
+    ///
+    /// # Argumente
+    /// * `small_prime_bound` - Die Grenze der Tabelle kleiner Primzahlen. Eine größere Tabelle
+    ///   sortiert mehr Kandidaten aus, kostet aber mehr Zeit pro Intervall.
+    /// * `interval_length` - Die Anzahl der Kandidaten pro Intervall, mindestens 1.
+    ///
+    /// # Rückgabe
+    /// * `IncrementalPrimeSearch` - Die Suche.
+    pub fn new(small_prime_bound: u32, interval_length: usize) -> Self {
+        assert!(interval_length > 0, "Das Intervall darf nicht leer sein");
+        IncrementalPrimeSearch {
+            small_primes: Self::sieve_of_eratosthenes(small_prime_bound),
+            interval_length,
+        }
+    }
+
+    /// Gibt die Tabelle der kleinen Primzahlen zurück.
+    pub fn small_primes(&self) -> &[u32] {
+        &self.small_primes
+    }
+
+    /// Sucht eine Primzahl mit der angegebenen Breite.
+    ///
+    /// # Argumente
+    /// * `size` - Die Bit-Breite der Primzahl.
+    /// * `random` - Die Quelle der Startwerte.
+    /// * `is_probably_prime` - Der Primzahltest für die Kandidaten, die das Sieb überstehen.
+    ///
+    /// # Rückgabe
+    /// Die gefundene Primzahl.
+    pub fn find_prime(
+        &self,
+        size: u32,
+        random: &dyn RandomSource,
+        is_probably_prime: impl Fn(&BigInt) -> bool,
+    ) -> BigInt {
+        debug!("Suche inkrementell eine Primzahl mit size {}", size);
+        self.search(size, 2, &[0], random, is_probably_prime)
+    }
+
+    /// Sucht eine sichere Primzahl p mit der angegebenen Breite, bei der auch (p-1)/2 prim ist.
+    /// Die Kandidaten laufen in Schritten von 4 über p ≡ 3 mod 4, damit (p-1)/2 ungerade ist.
+    /// Gesiebt wird zusätzlich p ≡ 1 mod r, weil dann r ein Teiler von (p-1)/2 ist.
+    ///
+    /// # Argumente
+    /// * `size` - Die Bit-Breite der Primzahl.
+    /// * `random` - Die Quelle der Startwerte.
+    /// * `is_probably_prime` - Der Primzahltest, der auf (p-1)/2 und p angewendet wird.
+    ///
+    /// # Rückgabe
+    /// Die gefundene sichere Primzahl.
+    pub fn find_safe_prime(
+        &self,
+        size: u32,
+        random: &dyn RandomSource,
+        is_probably_prime: impl Fn(&BigInt) -> bool,
+    ) -> BigInt {
+        debug!("Suche inkrementell eine sichere Primzahl mit size {}", size);
+        self.search(size, 4, &[0, 1], random, |candidate| {
+            let source_prime: BigInt = (candidate - BigInt::one()) >> 1;
+            is_probably_prime(&source_prime) && is_probably_prime(candidate)
+        })
+    }
+
+    /// Durchsucht Intervalle `start + step · i` für `i` von 0 bis zur Intervalllänge.
+    ///
+    /// # Argumente
+    /// * `size` - Die Bit-Breite der Primzahl.
+    /// * `step` - Der Abstand der Kandidaten, 2 oder 4. Bei 4 ist jeder Kandidat ≡ 3 mod 4.
+    /// * `forbidden_residues` - Die Reste modulo einer kleinen Primzahl, die einen Kandidaten
+    ///   ausschließen.
+    /// * `random` - Die Quelle der Startwerte.
+    /// * `is_probably_prime` - Der Test für die Kandidaten, die das Sieb überstehen.
+    fn search(
+        &self,
+        size: u32,
+        step: u32,
+        forbidden_residues: &[u32],
+        random: &dyn RandomSource,
+        is_probably_prime: impl Fn(&BigInt) -> bool,
+    ) -> BigInt {
+        let lower_bound = BigInt::one() << (size - 1);
+        let upper_bound = BigInt::one() << size;
+        // Nur mit Primzahlen kleiner als jeder Kandidat sieben, damit eine kleine Primzahl
+        // nicht sich selbst aussiebt. Bei sicheren Primzahlen gilt das auch für (p-1)/2.
+        let sieve_limit = &lower_bound / (2 * step);
+        let small_primes: Vec<u32> = self
+            .small_primes
+            .iter()
+            .copied()
+            .take_while(|&prime| BigInt::from(prime) < sieve_limit)
+            .collect();
+
+        loop {
+            let mut start = random.take_uneven(&lower_bound, &(&upper_bound - BigInt::one()));
+            if step == 4 && &start % 4 == BigInt::one() {
+                start += 2;
+            }
+            trace!("Siebe das Intervall ab Startwert {}", start);
+
+            let composite = self.sieve(&start, step, forbidden_residues, &small_primes);
+            for offset in (0..self.interval_length).filter(|&i| !composite[i]) {
+                let candidate = &start + BigInt::from(step as usize * offset);
+                if candidate >= upper_bound {
+                    break;
+                }
+                if is_probably_prime(&candidate) {
+                    debug!("Gefundene Primzahl {} mit Abstand {}", candidate, offset);
+                    return candidate;
+                }
+                trace!(
+                    "Kandidat {} hat das Sieb überstanden, ist aber nicht prim",
+                    candidate
+                );
+            }
+            trace!("Intervall ab {} enthält keine Primzahl", start);
+        }
+    }
+
+    /// Markiert alle `i`, für die `start + step · i` modulo einer kleinen Primzahl einen der
+    /// verbotenen Reste hat.
+    fn sieve(
+        &self,
+        start: &BigInt,
+        step: u32,
+        forbidden_residues: &[u32],
+        small_primes: &[u32],
+    ) -> Vec<bool> {
+        let mut composite = vec![false; self.interval_length];
+        for &prime in small_primes {
+            let prime = u64::from(prime);
+            // Das unwrap() wird niemals fehlschlagen, weil der Rest kleiner als prime ist.
+            let residue = (start % prime).to_u64().unwrap();
+            // step ist eine Zweierpotenz, das Inverse also eine Potenz des Inversen von 2.
+            let inverse_of_two = prime.div_ceil(2);
+            let step_inverse =
+                (0..step.trailing_zeros()).fold(1, |inverse, _| inverse * inverse_of_two % prime);
+            for &forbidden in forbidden_residues {
+                // start + step · i ≡ forbidden  <=>  i ≡ (forbidden - start) · step^-1
+                let first = (u64::from(forbidden) + prime - residue) % prime * step_inverse % prime;
+                for index in (first as usize..self.interval_length).step_by(prime as usize) {
+                    composite[index] = true;
+                }
+            }
+        }
+        composite
+    }
+
+    /// Berechnet alle ungeraden Primzahlen unterhalb der Grenze mit dem Sieb des Eratosthenes.
+    fn sieve_of_eratosthenes(bound: u32) -> Vec<u32> {
+        let bound = bound as usize;
+        let mut is_composite = vec![false; bound];
+        let mut primes = Vec::new();
+        for number in (3..bound).step_by(2) {
+            if is_composite[number] {
+                continue;
+            }
+            primes.push(number as u32);
+            for multiple in (number * number..bound).step_by(2 * number) {
+                is_composite[multiple] = true;
+            }
+        }
+        primes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+    use crate::math_core::number_theory::number_theory_service::{
+        NumberTheoryService, NumberTheoryServiceTrait,
+    };
+    use crate::math_core::random_source::{PrngRandomSource, RecordedRandomSource};
+
+    fn is_prime(candidate: &BigInt) -> bool {
+        let service = NumberTheoryService::new(Fast);
+        service.is_probably_prime(candidate, 20, &PrngRandomSource::seeded(3))
+    }
+
+    #[test]
+    fn test_small_prime_table() {
+        let search = IncrementalPrimeSearch::new(1000, 16);
+        assert_eq!(search.small_primes().len(), 167);
+        assert_eq!(&search.small_primes()[..5], &[3, 5, 7, 11, 13]);
+        assert_eq!(search.small_primes().last(), Some(&997));
+    }
+
+    #[test]
+    fn test_finds_next_prime_after_start() {
+        // 32771 ist die kleinste Primzahl ab 32769.
+        let search = IncrementalPrimeSearch::default();
+        let random = RecordedRandomSource::new([BigInt::from(32769)]);
+        assert_eq!(
+            search.find_prime(16, &random, is_prime),
+            BigInt::from(32771)
+        );
+    }
+
+    #[test]
+    fn test_draws_new_start_for_interval_without_prime() {
+        // 32769 = 3 · 10923 ist zusammengesetzt, das Intervall der Länge 1 damit erschöpft.
+        let search = IncrementalPrimeSearch::new(DEFAULT_SMALL_PRIME_BOUND, 1);
+        let random = RecordedRandomSource::new([BigInt::from(32769), BigInt::from(32771)]);
+        assert_eq!(
+            search.find_prime(16, &random, is_prime),
+            BigInt::from(32771)
+        );
+        assert_eq!(random.remaining(), 0);
+    }
+
+    #[test]
+    fn test_finds_next_safe_prime_after_start() {
+        // 32843 = 2 · 16421 + 1 ist die kleinste sichere Primzahl ab 32769.
+        let search = IncrementalPrimeSearch::default();
+        let random = RecordedRandomSource::new([BigInt::from(32769)]);
+        assert_eq!(
+            search.find_safe_prime(16, &random, is_prime),
+            BigInt::from(32843)
+        );
+    }
+
+    #[test]
+    fn test_finds_large_primes() {
+        let search = IncrementalPrimeSearch::default();
+        let random = PrngRandomSource::seeded(11);
+        for size in [64, 256, 512] {
+            let prime = search.find_prime(size, &random, is_prime);
+            assert_eq!(prime.bits(), u64::from(size));
+            assert!(is_prime(&prime));
+        }
+
+        let safe_prime = search.find_safe_prime(128, &random, is_prime);
+        assert_eq!(safe_prime.bits(), 128);
+        assert!(is_prime(&((&safe_prime - 1) / 2)));
+    }
+}
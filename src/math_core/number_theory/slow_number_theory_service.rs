@@ -9,7 +9,7 @@ use crate::math_core::number_theory::number_theory_service::{
     NumberTheoryService, NumberTheoryServiceTrait,
 };
 use crate::math_core::number_theory::primality_test::PrimalityTest;
-use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+use crate::math_core::random_source::RandomSource;
 use crate::math_core::traits::divisible::Divisible;
 use crate::math_core::traits::parity::Parity;
 use crate::shared::errors::ArithmeticError;
@@ -91,18 +91,13 @@ impl NumberTheoryServiceTrait for SlowNumberTheoryService {
         return Ok((modul + extended_euclid_result.y).rem_euclid(modul));
     }
 
-    fn is_probably_prime(
-        &self,
-        p: &BigInt,
-        repeats: u32,
-        random_generator: &PseudoRandomNumberGenerator,
-    ) -> bool {
+    fn is_probably_prime(&self, p: &BigInt, repeats: u32, random: &dyn RandomSource) -> bool {
         let primality_test = PrimalityTest::new(NumberTheoryService::new(Slow));
 
         if PrimalityTest::fails_primitive_prime_checks(p) {
             return false;
         }
 
-        primality_test.miller_rabin(p, repeats, random_generator)
+        primality_test.miller_rabin(p, repeats, random)
     }
 }
@@ -4,30 +4,75 @@ use bigdecimal::num_bigint::BigInt;
 use crate::math_core::number_theory::number_theory_service::{
     NumberTheoryService, NumberTheoryServiceSpeed, NumberTheoryServiceTrait,
 };
-use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+use crate::math_core::prime_generator::{PrimeGenerator, PrimeSearch};
+use crate::math_core::pseudo_random_number_generator::{PrngBackend, PseudoRandomNumberGenerator};
+use crate::math_core::random_source::RandomSource;
 
 /// Ein Wrapper für die Kombination aus NumberTheoryService und einem PseudoRandomNumberGenerator.
 /// Dieser Wrapper ist stateful, weil er den Zähler des PRNG hält und verwaltet.
+///
+/// Der Wrapper ist selbst eine `RandomSource`. Die Zufallszahlen stammen aus dem PRNG oder,
+/// falls gesetzt, aus einer beliebigen anderen Quelle, siehe `with_random_source`.
 pub struct NumberTheoryWithPrngService {
     pub number_theory_service: NumberTheoryService,
     pub prng: PseudoRandomNumberGenerator,
     pub prng_counter: RelaxedCounter,
+    random_source: Option<Box<dyn RandomSource>>,
+    prime_search: PrimeSearch,
 }
 
 impl NumberTheoryWithPrngService {
-    /// Erstellt eine neue Instanz des Wrappers mit der übergebenen Konfiguration.
+    /// Erstellt eine neue Instanz des Wrappers mit der übergebenen Konfiguration. Als
+    /// Zufallsfolge wird die reproduzierbare Weyl-Folge zum Seed verwendet.
     pub fn new(speed: NumberTheoryServiceSpeed, random_seed: u32) -> Self {
+        Self::with_backend(speed, PrngBackend::Weyl(random_seed))
+    }
+
+    /// Erstellt eine neue Instanz des Wrappers mit der gewählten Quelle der Zufallsfolge, z.B.
+    /// einem kryptografisch sicheren Generator für echte Schlüssel.
+    pub fn with_backend(speed: NumberTheoryServiceSpeed, backend: PrngBackend) -> Self {
         let number_theory_service = NumberTheoryService::new(speed);
-        let prng = PseudoRandomNumberGenerator::new(random_seed, number_theory_service);
+        let prng = PseudoRandomNumberGenerator::with_backend(backend);
         let prng_counter = RelaxedCounter::new(1);
 
         Self {
             number_theory_service,
             prng,
             prng_counter,
+            random_source: None,
+            prime_search: PrimeSearch::default(),
+        }
+    }
+
+    /// Erstellt eine neue Instanz des Wrappers, die alle Zufallszahlen aus der übergebenen
+    /// Quelle zieht, z.B. aus aufgezeichneten Werten in Tests, auch die Zeugen für den
+    /// Miller-Rabin-Test. Der PRNG wird dann nicht verwendet, daher genügt eine Weyl-Folge, für
+    /// die keine Entropie des Betriebssystems gelesen werden muss.
+    pub fn with_random_source(
+        speed: NumberTheoryServiceSpeed,
+        random_source: impl RandomSource + 'static,
+    ) -> Self {
+        Self {
+            random_source: Some(Box::new(random_source)),
+            ..Self::new(speed, 0)
         }
     }
 
+    /// Setzt das Verfahren, mit dem der Service Primzahlen sucht, siehe `prime_generator`.
+    ///
+    /// # Argumente
+    /// * `prime_search` - Das Verfahren, standardmäßig `PrimeSearch::Random`.
+    pub fn with_prime_search(mut self, prime_search: PrimeSearch) -> Self {
+        self.prime_search = prime_search;
+        self
+    }
+
+    /// Gibt einen Primzahlgenerator mit dem gewählten Suchverfahren zurück. Als Zufallsquelle
+    /// wird ihm der Service selbst übergeben.
+    pub fn prime_generator(&self) -> PrimeGenerator {
+        PrimeGenerator::new(self.number_theory_service).with_prime_search(self.prime_search.clone())
+    }
+
     /// Diese Methode gibt eine Zufallszahl im Bereich von a bis b zurück.
     /// Inkrementiert den AtomicCounter n_counter!
     ///
@@ -40,7 +85,10 @@ impl NumberTheoryWithPrngService {
     /// Die Zufallszahl.
     pub fn take_random_number_in_range(&self, a: &BigInt, b: &BigInt) -> BigInt {
         // TODO Schnelle Lib Variante einbauen, je nach dem, welcher Speed hier gewrapped ist.
-        self.prng.take(a, b, &self.prng_counter)
+        match &self.random_source {
+            Some(random_source) => random_source.take(a, b),
+            None => self.prng.take(a, b, &self.prng_counter),
+        }
     }
 
     /// Diese Methode gibt eine ungerade Zufallszahl im Bereich von a bis b zurück.
@@ -55,7 +103,10 @@ impl NumberTheoryWithPrngService {
     /// Die ungerade Zufallszahl.
     pub fn take_random_uneven_number_in_range(&self, a: &BigInt, b: &BigInt) -> BigInt {
         // TODO Schnelle Lib Variante einbauen, je nach dem, welcher Speed hier gewrapped ist.
-        self.prng.take_uneven(a, b, &self.prng_counter)
+        match &self.random_source {
+            Some(random_source) => random_source.take_uneven(a, b),
+            None => self.prng.take_uneven(a, b, &self.prng_counter),
+        }
     }
 
     /// Generiert eine Primzahl mit der angegebenen Breite.
@@ -69,8 +120,8 @@ impl NumberTheoryWithPrngService {
     /// Die generierte Primzahl.
     pub fn generate_prime_with_width(&self, size: u32, miller_rabin_iterations: u32) -> BigInt {
         // TODO Schnelle Lib Variante einbauen, je nach dem, welcher Speed hier gewrapped ist.
-        self.prng
-            .generate_prime(size, miller_rabin_iterations, &self.prng_counter)
+        self.prime_generator()
+            .generate_prime(size, miller_rabin_iterations, self)
     }
 
     /// Prüft, ob die übergebene Zahl wahrscheinlich eine Primzahl ist.
@@ -83,7 +134,7 @@ impl NumberTheoryWithPrngService {
     /// Wahr, wenn die Zahl wahrscheinlich eine Primzahl ist, sonst falsch.
     pub fn is_probably_prime(&self, p: &BigInt, repeats: u32) -> bool {
         self.number_theory_service
-            .is_probably_prime(p, repeats, &self.prng)
+            .is_probably_prime(p, repeats, self)
     }
 
     /// Setzt den Wert des Counters für den PRNG zurück auf 1.
@@ -92,3 +143,13 @@ impl NumberTheoryWithPrngService {
         self.prng_counter.inc();
     }
 }
+
+impl RandomSource for NumberTheoryWithPrngService {
+    fn take(&self, a: &BigInt, b: &BigInt) -> BigInt {
+        self.take_random_number_in_range(a, b)
+    }
+
+    fn take_uneven(&self, a: &BigInt, b: &BigInt) -> BigInt {
+        self.take_random_uneven_number_in_range(a, b)
+    }
+}
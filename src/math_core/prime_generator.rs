@@ -0,0 +1,396 @@
+use bigdecimal::num_bigint::BigInt;
+use log::{debug, trace};
+
+use crate::math_core::number_theory::number_theory_service::{
+    NumberTheoryService, NumberTheoryServiceTrait,
+};
+use crate::math_core::number_theory::prime_sieve::IncrementalPrimeSearch;
+use crate::math_core::random_source::RandomSource;
+use crate::math_core::traits::divisible::Divisible;
+use crate::math_core::traits::increment::Increment;
+
+///
+/// Das Verfahren, mit dem `generate_prime` und die davon abgeleiteten Methoden Primzahlen suchen.
+///
+#[derive(Clone, Debug, Default)]
+pub enum PrimeSearch {
+    /// Nach jedem gescheiterten Test wird ein neuer zufälliger ungerader Kandidat gezogen.
+    #[default]
+    Random,
+    /// Ab einem zufälligen Startwert wird ein Intervall gesiebt, siehe `IncrementalPrimeSearch`.
+    Incremental(IncrementalPrimeSearch),
+}
+
+///
+/// Erzeugt Primzahlen aus einer `RandomSource`. Der Generator selbst hält keine Zufallsfolge:
+/// Kandidaten, Startwerte und die Zeugen für den Miller-Rabin-Test stammen alle aus der
+/// übergebenen Quelle. Mit einer Quelle mit Seed ist die Primzahlerzeugung damit reproduzierbar.
+///
+#[derive(Clone, Debug)]
+pub struct PrimeGenerator {
+    number_theory_service: NumberTheoryService,
+    prime_search: PrimeSearch,
+}
+
+impl PrimeGenerator {
+    /// Erstellt einen Generator, der nach jedem gescheiterten Test einen neuen Zufallskandidaten
+    /// zieht.
+    ///
+    /// # Argumente
+    /// * `number_theory_service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// * PrimeGenerator
+    pub fn new(number_theory_service: NumberTheoryService) -> Self {
+        PrimeGenerator {
+            number_theory_service,
+            prime_search: PrimeSearch::default(),
+        }
+    }
+
+    /// Setzt das Verfahren für die Primzahlsuche.
+    ///
+    /// # Argumente
+    /// * `prime_search` - Das Verfahren, standardmäßig `PrimeSearch::Random`.
+    ///
+    /// # Rückgabe
+    /// * PrimeGenerator
+    pub fn with_prime_search(mut self, prime_search: PrimeSearch) -> Self {
+        self.prime_search = prime_search;
+        self
+    }
+
+    /// Generiert eine Primzahl mit der angegebenen Breite. Das Suchverfahren wird mit
+    /// `with_prime_search` gewählt und gilt auch für `get_distinct_primes` und die Blum-Primzahlen.
+    ///
+    /// # Argumente
+    /// * `size` - Die Bit-Breite der Primzahl.
+    /// * `miller_rabin_iterations` - Die Anzahl der Iterationen für den Miller-Rabin-Test.
+    /// * `random` - Die Quelle der Kandidaten und der Zeugen für Miller-Rabin.
+    ///
+    /// # Rückgabe
+    /// Die generierte Primzahl.
+    pub fn generate_prime(
+        &self,
+        size: u32,
+        miller_rabin_iterations: u32,
+        random: &dyn RandomSource,
+    ) -> BigInt {
+        debug!(
+            "Generiere eine Primzahl mit size {} und Miller-Rabin-Iterations {}",
+            size, miller_rabin_iterations
+        );
+
+        if let PrimeSearch::Incremental(search) = &self.prime_search {
+            return search.find_prime(size, random, |candidate| {
+                self.number_theory_service.is_probably_prime(
+                    candidate,
+                    miller_rabin_iterations,
+                    random,
+                )
+            });
+        }
+
+        let upper_bound = &BigInt::from(2).pow(size);
+        let lower_bound = &BigInt::from(2).pow(size - 1);
+
+        let mut prime_candidate = random.take_uneven(lower_bound, upper_bound);
+
+        while !self.number_theory_service.is_probably_prime(
+            &prime_candidate,
+            miller_rabin_iterations,
+            random,
+        ) {
+            trace!(
+                "Generierter Primkandidat {} ist keine Primzahl",
+                prime_candidate
+            );
+            prime_candidate = random.take_uneven(lower_bound, upper_bound);
+        }
+        debug!(
+            "Generierter Primkandidat {} ist eine Primzahl",
+            prime_candidate
+        );
+        prime_candidate
+    }
+
+    /// Generiert eine Blum-Primzahl mit der angegebenen Breite, also eine Primzahl p mit
+    /// p ≡ 3 mod 4. Für solche Primzahlen lassen sich Quadratwurzeln effizient als
+    /// `a^((p + 1) / 4) mod p` berechnen.
+    ///
+    /// # Argumente
+    /// * `size` - Die Bit-Breite der Primzahl.
+    /// * `miller_rabin_iterations` - Die Anzahl der Iterationen für den Miller-Rabin-Test.
+    /// * `random` - Die Quelle der Kandidaten und der Zeugen für Miller-Rabin.
+    ///
+    /// # Rückgabe
+    /// Die generierte Blum-Primzahl.
+    pub fn generate_blum_prime(
+        &self,
+        size: u32,
+        miller_rabin_iterations: u32,
+        random: &dyn RandomSource,
+    ) -> BigInt {
+        let four = BigInt::from(4);
+        let three = BigInt::from(3);
+        loop {
+            let prime = self.generate_prime(size, miller_rabin_iterations, random);
+            if &prime % &four == three {
+                return prime;
+            }
+            trace!("Generierte Primzahl {} ist keine Blum-Primzahl", prime);
+        }
+    }
+
+    /// Generiert eine sichere Primzahl p mit der angegebenen Breite, bei der auch (p-1)/2 eine
+    /// Primzahl ist.
+    ///
+    /// # Argumente
+    /// * `size` - Die Bit-Breite der Primzahl.
+    /// * `miller_rabin_iterations` - Die Anzahl der Iterationen für die Miller-Rabin-Tests.
+    /// * `random` - Die Quelle der Kandidaten und der Zeugen für Miller-Rabin.
+    ///
+    /// # Rückgabe
+    /// Die sichere Primzahl p und die Primzahl (p-1)/2.
+    fn generate_safe_prime(
+        &self,
+        size: u32,
+        miller_rabin_iterations: u32,
+        random: &dyn RandomSource,
+    ) -> (BigInt, BigInt) {
+        let is_probably_prime = |candidate: &BigInt| {
+            self.number_theory_service
+                .is_probably_prime(candidate, miller_rabin_iterations, random)
+        };
+        if let PrimeSearch::Incremental(search) = &self.prime_search {
+            let prime = search.find_safe_prime(size, random, is_probably_prime);
+            let source_prime = prime.decrement().half();
+            return (prime, source_prime);
+        }
+
+        loop {
+            let prime_candidate = self.generate_prime(size, miller_rabin_iterations, random);
+            let source_prime = prime_candidate.decrement().half();
+            if is_probably_prime(&source_prime) {
+                debug!(
+                    "Generierter Primkandidat {} ist eine sichere Primzahl",
+                    prime_candidate
+                );
+                return (prime_candidate, source_prime);
+            }
+            trace!(
+                "Generierter Primkandidat {} ist keine sichere Primzahl",
+                prime_candidate
+            );
+        }
+    }
+
+    /// Generiert eine sichere Primzahl mit der angegebenen Breite und liefert eine passende
+    /// Primitivwurzel.
+    /// Eine sichere Primzahl ist eine Primzahl p, bei der auch (p-1)/2 eine Primzahl ist.
+    /// Eine Primitivwurzel ist ein Element g, welches jede Zahl aus der Menge {1, 2, ..., p-1}
+    /// als Potenz von g darstellen kann.
+    ///
+    /// # Argumente
+    /// * `size` - Die Bit-Breite der Primzahl.
+    /// * `miller_rabin_iterations` - Die Anzahl der Iterationen für die Miller-Rabin-Tests.
+    /// * `random` - Die Quelle der Kandidaten und der Zeugen für Miller-Rabin.
+    ///
+    /// # Rückgabe
+    /// Die generierte sichere Primzahl und die Primitivwurzel.
+    pub fn generate_secure_prime_with_primitive_root(
+        &self,
+        size: u32,
+        miller_rabin_iterations: u32,
+        random: &dyn RandomSource,
+    ) -> (BigInt, BigInt) {
+        debug!(
+            "Generiere eine sichere Primzahl mit size {} und Miller-Rabin-Iterations {}",
+            size, miller_rabin_iterations
+        );
+
+        let (prime_candidate, source_prime) =
+            self.generate_safe_prime(size, miller_rabin_iterations, random);
+
+        debug!(
+            "Generiere Primitivwurzel für die sichere Primzahl {}",
+            prime_candidate
+        );
+        let mut primitive_root_candidate: BigInt;
+        // Bestimmung der Primitivwurzel
+        loop {
+            primitive_root_candidate =
+                random.take(&2.into(), &(&prime_candidate - BigInt::from(2)));
+            // Eine Zahl g ist eine Primitivwurzel, wenn g^(q) mod p = p - 1
+            // mit q = source_prime und p = prime_candidate
+            // Die Prüfung geschieht normalerweise mit -1, aber weil fast_exponentiation mit
+            // euklidischem Rest rechnet, muss hier p - 1 verwendet werden.
+            let is_primitive_root = self.number_theory_service.fast_exponentiation(
+                &primitive_root_candidate,
+                &source_prime,
+                &prime_candidate,
+            ) == prime_candidate.decrement();
+
+            if is_primitive_root {
+                debug!(
+                    "Generierter Primitivwurzelkandidat {} ist eine Primitivwurzel",
+                    primitive_root_candidate
+                );
+                break;
+            }
+            trace!(
+                "Generierter Primitivwurzelkandidat {} ist keine Primitivwurzel",
+                primitive_root_candidate
+            );
+        }
+
+        (prime_candidate, primitive_root_candidate)
+    }
+
+    /// Generiert `count` verschiedene Primzahlen, deren Bit-Breiten zusammen `size` ergeben.
+    /// Ist `size` nicht durch `count` teilbar, werden die ersten Primzahlen um ein Bit breiter.
+    ///
+    /// # Argumente
+    /// * `size` - Die gesamte Bit-Breite aller Primzahlen.
+    /// * `count` - Die Anzahl der Primzahlen, mindestens 2.
+    /// * `miller_rabin_iterations` - Die Anzahl der Iterationen für den Miller-Rabin-Test.
+    /// * `random` - Die Quelle der Kandidaten und der Zeugen für Miller-Rabin.
+    ///
+    /// # Rückgabe
+    /// * `Vec<BigInt>` - Die paarweise verschiedenen Primzahlen.
+    pub fn get_distinct_primes(
+        &self,
+        size: u32,
+        count: u32,
+        miller_rabin_iterations: u32,
+        random: &dyn RandomSource,
+    ) -> Vec<BigInt> {
+        assert!(count >= 2, "Es werden mindestens zwei Primzahlen benötigt");
+        let mut primes: Vec<BigInt> = Vec::with_capacity(count as usize);
+
+        for index in 0..count {
+            let prime_size = size / count + u32::from(index < size % count);
+            let mut prime = self.generate_prime(prime_size, miller_rabin_iterations, random);
+            while primes.contains(&prime) {
+                trace!(
+                    "Generierte Primzahl {} wurde bereits erzeugt. Starte neuen Versuch",
+                    prime
+                );
+                prime = self.generate_prime(prime_size, miller_rabin_iterations, random);
+            }
+            primes.push(prime);
+        }
+        primes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+    use crate::math_core::random_source::PrngRandomSource;
+
+    use super::*;
+
+    #[test]
+    fn test_get_distinct_primes() {
+        let service: NumberTheoryService = NumberTheoryService::new(Fast);
+        let generator = PrimeGenerator::new(service);
+        let source = PrngRandomSource::seeded(17);
+
+        for count in 2..=5 {
+            let primes = generator.get_distinct_primes(256, count, 20, &source);
+            assert_eq!(primes.len(), count as usize);
+            for (index, prime) in primes.iter().enumerate() {
+                assert!(!primes[index + 1..].contains(prime));
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate_blum_prime() {
+        let service: NumberTheoryService = NumberTheoryService::new(Fast);
+        let generator = PrimeGenerator::new(service);
+        let source = PrngRandomSource::seeded(19);
+
+        for _ in 0..5 {
+            let prime = generator.generate_blum_prime(128, 20, &source);
+            assert_eq!(prime.bits(), 128);
+            assert_eq!(&prime % 4, BigInt::from(3));
+            assert!(service.is_probably_prime(&prime, 20, &source));
+        }
+    }
+
+    #[test]
+    fn test_incremental_prime_search() {
+        let service: NumberTheoryService = NumberTheoryService::new(Fast);
+        let generator = PrimeGenerator::new(service)
+            .with_prime_search(PrimeSearch::Incremental(IncrementalPrimeSearch::default()));
+        let source = PrngRandomSource::seeded(29);
+
+        let primes = generator.get_distinct_primes(1024, 3, 20, &source);
+        assert_eq!(primes.len(), 3);
+        for (index, prime) in primes.iter().enumerate() {
+            assert!(!primes[index + 1..].contains(prime));
+            assert!(service.is_probably_prime(prime, 20, &source));
+        }
+
+        let (prime, root) = generator.generate_secure_prime_with_primitive_root(128, 20, &source);
+        let source_prime: BigInt = (&prime - 1) / 2;
+        assert_eq!(prime.bits(), 128);
+        assert!(service.is_probably_prime(&prime, 20, &source));
+        assert!(service.is_probably_prime(&source_prime, 20, &source));
+        assert_eq!(
+            service.fast_exponentiation(&root, &source_prime, &prime),
+            &prime - 1
+        );
+    }
+
+    /// Vergleicht die Laufzeit der inkrementellen Suche mit dem Ziehen neuer Zufallskandidaten,
+    /// jeweils mit derselben Zufallsfolge.
+    ///
+    /// Aufruf: `cargo test --release benchmark_prime_search -- --ignored --nocapture`
+    #[test]
+    #[ignore] // Zeitmessung, nur manuell und im Release-Build aussagekräftig.
+    fn test_benchmark_prime_search() {
+        let service: NumberTheoryService = NumberTheoryService::new(Fast);
+        let strategies = [
+            ("Zufällige Kandidaten", PrimeSearch::Random),
+            (
+                "Inkrementelles Sieb",
+                PrimeSearch::Incremental(IncrementalPrimeSearch::default()),
+            ),
+        ];
+
+        let mut durations = Vec::new();
+        for (name, prime_search) in strategies {
+            let generator = PrimeGenerator::new(service).with_prime_search(prime_search);
+            let source = PrngRandomSource::seeded(31);
+
+            let start = Instant::now();
+            for _ in 0..10 {
+                std::hint::black_box(generator.get_distinct_primes(1024, 2, 40, &source));
+            }
+            let distinct_primes = start.elapsed();
+
+            let start = Instant::now();
+            for _ in 0..5 {
+                std::hint::black_box(
+                    generator.generate_secure_prime_with_primitive_root(128, 40, &source),
+                );
+            }
+            let safe_primes = start.elapsed();
+
+            println!(
+                "{:<21} 10 × get_distinct_primes(1024, 2): {:>8.1} ms, \
+                 5 × sichere Primzahl (128 Bit): {:>8.1} ms",
+                name,
+                distinct_primes.as_secs_f64() * 1e3,
+                safe_primes.as_secs_f64() * 1e3
+            );
+            durations.push(distinct_primes + safe_primes);
+        }
+        assert!(durations[1] < durations[0]);
+    }
+}
@@ -1,23 +1,50 @@
-use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
-use crate::math_core::number_theory::number_theory_service::{
-    NumberTheoryService, NumberTheoryServiceTrait,
-};
-use crate::math_core::traits::divisible::Divisible;
 use atomic_counter::{AtomicCounter, RelaxedCounter};
-use bigdecimal::num_bigint::{BigInt, ToBigInt};
+use bigdecimal::num_bigint::{BigInt, Sign, ToBigInt};
 use bigdecimal::{BigDecimal, One};
-use log::{debug, trace};
-use num::Integer;
+use log::trace;
 use rand::Rng;
+use std::sync::{Mutex, PoisonError};
+
+use crate::math_core::csprng::blum_blum_shub::BlumBlumShub;
+use crate::math_core::csprng::chacha20_rng::ChaCha20Rng;
+use crate::math_core::csprng::hmac_drbg::HmacDrbg;
+use crate::math_core::csprng::RandomByteGenerator;
 
 use crate::math_core::traits::increment::Increment;
 
 ///
-/// Iterator für eine deterministische Zufallszahlfolge.
+/// Die Quelle der Zufallsfolge eines `PseudoRandomNumberGenerator`.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PrngBackend {
+    /// Die deterministische Weyl-Folge n · sqrt(m) mod 1 zum angegebenen Seed m. Sie ist
+    /// reproduzierbar und eignet sich für Demonstrationen, aber nicht für echte Schlüssel.
+    Weyl(u32),
+    /// HMAC_DRBG nach NIST SP 800-90A, geseedet mit Entropie des Betriebssystems.
+    HmacDrbg,
+    /// Der Schlüsselstrom von ChaCha20, geseedet mit Entropie des Betriebssystems.
+    ChaCha20,
+    /// Blum Blum Shub mit einem 1024-Bit-Modulus, geseedet mit Entropie des Betriebssystems.
+    /// Beweisbar sicher unter der Annahme, dass Faktorisieren schwer ist, aber langsam.
+    BlumBlumShub,
+}
+
+/// Der Zustand der gewählten Quelle.
+enum BackendState {
+    Weyl {
+        sqrt_m: BigDecimal,
+    },
+    /// Die kryptografisch sicheren Generatoren sind zustandsbehaftet. Der Mutex erlaubt die
+    /// Nutzung über `&self`, so wie bei der Weyl-Folge mit dem atomaren Zähler.
+    Secure(Mutex<Box<dyn RandomByteGenerator>>),
+}
+
+///
+/// Iterator für eine Zufallszahlfolge. Standardmäßig ist das die deterministische Weyl-Folge,
+/// alternativ kann ein kryptografisch sicherer Generator gewählt werden, siehe `PrngBackend`.
 ///
 pub struct PseudoRandomNumberGenerator {
-    sqrt_m: BigDecimal,
-    number_theory_service: NumberTheoryService,
+    source: BackendState,
 }
 
 impl PseudoRandomNumberGenerator {
@@ -32,7 +59,7 @@ impl PseudoRandomNumberGenerator {
     /// # Rückgabe
     /// * PseudoRandomNumberGenerator
     ///
-    pub fn new(random_seed: u32, number_theory_service: NumberTheoryService) -> Self {
+    pub fn new(random_seed: u32) -> Self {
         let mut initial_random = random_seed;
         let sqrt_m;
         loop {
@@ -49,15 +76,45 @@ impl PseudoRandomNumberGenerator {
             }
         }
         return Self {
-            sqrt_m,
-            number_theory_service,
+            source: BackendState::Weyl { sqrt_m },
+        };
+    }
+
+    /// Erstellt eine neue Instanz mit der gewählten Quelle der Zufallsfolge.
+    ///
+    /// # Argumente
+    /// * `backend` - Die Quelle, bei der Weyl-Folge inklusive Seed.
+    ///
+    /// # Rückgabe
+    /// * PseudoRandomNumberGenerator
+    pub fn with_backend(backend: PrngBackend) -> Self {
+        let generator: Box<dyn RandomByteGenerator> = match backend {
+            PrngBackend::Weyl(random_seed) => return Self::new(random_seed),
+            PrngBackend::HmacDrbg => Box::new(HmacDrbg::from_os_entropy()),
+            PrngBackend::ChaCha20 => Box::new(ChaCha20Rng::from_os_entropy()),
+            PrngBackend::BlumBlumShub => Box::new(BlumBlumShub::from_os_entropy()),
         };
+        Self::with_generator(generator)
+    }
+
+    /// Erstellt eine neue Instanz, die die Zufallsfolge aus dem übergebenen kryptografisch
+    /// sicheren Generator bezieht, z.B. einem zum Seed reproduzierbaren.
+    ///
+    /// # Argumente
+    /// * `generator` - Der Generator.
+    ///
+    /// # Rückgabe
+    /// * PseudoRandomNumberGenerator
+    pub fn with_generator(generator: Box<dyn RandomByteGenerator>) -> Self {
+        Self {
+            source: BackendState::Secure(Mutex::new(generator)),
+        }
     }
 
     /// Initialisert eine neue Instanz des PseudoRandomNumberGenerator mit einer zufälligen Seed.
     pub fn new_seeded() -> Self {
         let random_seed = rand::thread_rng().gen();
-        Self::new(random_seed, NumberTheoryService::new(Fast))
+        Self::new(random_seed)
     }
 
     /// Diese Methode gibt eine Zufallszahl im Bereich von a bis b zurück.
@@ -65,7 +122,8 @@ impl PseudoRandomNumberGenerator {
     ///
     /// # Argumente
     /// * `a` - Die untere Grenze des Bereichs.
-    /// * `b` - Die obere Grenze des Bereichs.
+    /// * `b` - Die obere Grenze des Bereichs, mindestens `a`. Für einen leeren Bereich gibt
+    ///   es keine Zufallszahl, die Methode bricht dann mit einem Panic ab.
     /// * `n_counter` - Der AtomicCounter, der den Index des Elementes aus der Zufallsfolge enthält.
     ///
     /// # Rückgabe
@@ -77,13 +135,42 @@ impl PseudoRandomNumberGenerator {
             b,
             n_counter.get()
         );
-        let factor: BigDecimal =
-            (BigDecimal::from(n_counter.inc() as u32) * &self.sqrt_m) % BigDecimal::one();
-        let range: BigDecimal = (b - a + BigInt::one()).into();
+        assert!(a <= b, "Der Bereich von {} bis {} ist leer", a, b);
+        let range = b - a + BigInt::one();
+        match &self.source {
+            BackendState::Weyl { sqrt_m } => {
+                let factor: BigDecimal =
+                    (BigDecimal::from(n_counter.inc() as u32) * sqrt_m) % BigDecimal::one();
 
-        // Das unwrap() wird niemals fehlschlagen, weil die Implementation von to_bigint() nur
-        // Some, aber niemals None zurückgibt.
-        a + (factor * range).to_bigint().unwrap()
+                // Das unwrap() wird niemals fehlschlagen, weil die Implementation von
+                // to_bigint() nur Some, aber niemals None zurückgibt.
+                a + (factor * BigDecimal::from(range)).to_bigint().unwrap()
+            }
+            BackendState::Secure(generator) => {
+                // Der Zähler wird nur noch zur Buchführung mitgezählt.
+                n_counter.inc();
+                let mut generator = generator.lock().unwrap_or_else(PoisonError::into_inner);
+                a + Self::uniform_below(generator.as_mut(), &range)
+            }
+        }
+    }
+
+    /// Zieht eine gleichverteilte Zahl aus [0, bound) per Verwerfungsmethode: Es werden so viele
+    /// Bits gezogen, wie `bound` breit ist, und Werte >= bound verworfen.
+    fn uniform_below(generator: &mut dyn RandomByteGenerator, bound: &BigInt) -> BigInt {
+        let bits = bound.bits();
+        let mut bytes = vec![0u8; bits.div_ceil(8) as usize];
+        let excess_bits = bytes.len() as u64 * 8 - bits;
+        loop {
+            generator.fill_bytes(&mut bytes);
+            if let Some(first) = bytes.first_mut() {
+                *first &= 0xffu8 >> excess_bits;
+            }
+            let candidate = BigInt::from_bytes_be(Sign::Plus, &bytes);
+            if &candidate < bound {
+                return candidate;
+            }
+        }
     }
 
     /// Diese Methode gibt eine ungerade Zufallszahl im Bereich von a bis b zurück.
@@ -99,176 +186,30 @@ impl PseudoRandomNumberGenerator {
     pub fn take_uneven(&self, a: &BigInt, b: &BigInt, n_counter: &RelaxedCounter) -> BigInt {
         self.take(a, b, n_counter) | BigInt::one()
     }
-
-    /// Generiert eine Primzahl mit der angegebenen Breite.
-    ///
-    /// # Argumente
-    /// * `size` - Die Bit-Breite der Primzahl.
-    /// * `miller_rabin_iterations` - Die Anzahl der Iterationen für den Miller-Rabin-Test.
-    /// * `n_counter` - Der Zähler für den Zugriff auf die Zufallsfolge. Achtung: Der Zähler wird inkrementiert!
-    ///
-    /// # Rückgabe
-    /// Die generierte Primzahl.
-    pub fn generate_prime(
-        &self,
-        size: u32,
-        miller_rabin_iterations: u32,
-        n_counter: &RelaxedCounter,
-    ) -> BigInt {
-        debug!(
-            "Generiere eine Primzahl mit size {} und Miller-Rabin-Iterations {}",
-            size, miller_rabin_iterations
-        );
-
-        let upper_bound = &BigInt::from(2).pow(size);
-        let lower_bound = &BigInt::from(2).pow(size - 1);
-
-        let mut prime_candidate = self.take_uneven(lower_bound, upper_bound, n_counter);
-
-        while !self.number_theory_service.is_probably_prime(
-            &prime_candidate,
-            miller_rabin_iterations,
-            self, // Ggf sollte hier eine neue Instanz mit zufälligem Seed übergeben werden?
-        ) {
-            trace!(
-                "Generierter Primkandidat {} ist keine Primzahl",
-                prime_candidate
-            );
-            prime_candidate = self.take_uneven(lower_bound, upper_bound, n_counter);
-        }
-        debug!(
-            "Generierter Primkandidat {} ist eine Primzahl",
-            prime_candidate
-        );
-        prime_candidate
-    }
-
-    /// Generiert eine sichere Primzahl mit der angegebenen Breite und liefert eine passende
-    /// Primitivwurzel.
-    /// Eine sichere Primzahl ist eine Primzahl p, bei der auch (p-1)/2 eine Primzahl ist.
-    /// Eine Primitivwurzel ist ein Element g, welches jede Zahl aus der Menge {1, 2, ..., p-1}
-    /// als Potenz von g darstellen kann.
-    ///
-    /// # Argumente
-    /// * `size` - Die Bit-Breite der Primzahl.
-    /// * `miller_rabin_iterations` - Die Anzahl der Iterationen für die Miller-Rabin-Tests.
-    /// * `n_counter` - Der Zähler für den Zugriff auf die Zufallsfolge. Achtung: Der Zähler wird inkrementiert!
-    ///
-    /// # Rückgabe
-    /// Die generierte sichere Primzahl und die Primitivwurzel.
-    pub fn generate_secure_prime_with_primitive_root(
-        &self,
-        size: u32,
-        miller_rabin_iterations: u32,
-        n_counter: &RelaxedCounter,
-    ) -> (BigInt, BigInt) {
-        debug!(
-            "Generiere eine sichere Primzahl mit size {} und Miller-Rabin-Iterations {}",
-            size, miller_rabin_iterations
-        );
-
-        let mut prime_candidate: BigInt;
-        let mut source_prime: BigInt;
-        // Bestimmung der sicheren Primzahl
-        loop {
-            prime_candidate = self.generate_prime(size, miller_rabin_iterations, n_counter);
-            source_prime = prime_candidate.decrement().half();
-            if self.number_theory_service.is_probably_prime(
-                &source_prime,
-                miller_rabin_iterations,
-                self, // Ggf sollte hier eine neue Instanz mit zufälligem Seed übergeben werden?
-            ) {
-                debug!(
-                    "Generierter Primkandidat {} ist eine sichere Primzahl",
-                    prime_candidate
-                );
-                break;
-            }
-            trace!(
-                "Generierter Primkandidat {} ist keine sichere Primzahl",
-                prime_candidate
-            );
-        }
-
-        debug!(
-            "Generiere Primitivwurzel für die sichere Primzahl {}",
-            prime_candidate
-        );
-        let mut primitive_root_candidate: BigInt;
-        // Bestimmung der Primitivwurzel
-        loop {
-            primitive_root_candidate =
-                self.take(&2.into(), &(&prime_candidate - BigInt::from(2)), n_counter);
-            // Eine Zahl g ist eine Primitivwurzel, wenn g^(q) mod p = p - 1
-            // mit q = source_prime und p = prime_candidate
-            // Die Prüfung geschieht normalerweise mit -1, aber weil fast_exponentiation mit
-            // euklidischem Rest rechnet, muss hier p - 1 verwendet werden.
-            let is_primitive_root = self.number_theory_service.fast_exponentiation(
-                &primitive_root_candidate,
-                &source_prime,
-                &prime_candidate,
-            ) == prime_candidate.decrement();
-
-            if is_primitive_root {
-                debug!(
-                    "Generierter Primitivwurzelkandidat {} ist eine Primitivwurzel",
-                    primitive_root_candidate
-                );
-                break;
-            }
-            trace!(
-                "Generierter Primitivwurzelkandidat {} ist keine Primitivwurzel",
-                primitive_root_candidate
-            );
-        }
-
-        (prime_candidate, primitive_root_candidate)
-    }
-
-    /// Generiert zwei verschiedene Primzahlen mit der angegebenen Breite.
-    ///
-    /// # Argumente
-    /// * `size` - Die Bit-Breite der Primzahlen.
-    /// * `miller_rabin_iterations` - Die Anzahl der Iterationen für den Miller-Rabin-Test.
-    pub fn get_distinct_primes(&self, size: u32, miller_rabin_iterations: u32) -> (BigInt, BigInt) {
-        let (prim_size_one, prim_size_two) = if size.is_even() {
-            (size / 2, size / 2)
-        } else {
-            (size / 2 + 1, size / 2)
-        };
-        let n_counter = RelaxedCounter::new(1);
-        let prime_one = self.generate_prime(prim_size_one, miller_rabin_iterations, &n_counter);
-        let mut prime_two = self.generate_prime(prim_size_two, miller_rabin_iterations, &n_counter);
-
-        while prime_one == prime_two {
-            trace!(
-                "Generierter prime_one {} ist gleich prime_two {}. Starte neuen Versuch",
-                prime_one,
-                prime_two
-            );
-            prime_two = self.generate_prime(prim_size_two, miller_rabin_iterations, &n_counter);
-        }
-        (prime_one, prime_two)
-    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
     use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
-    use atomic_counter::RelaxedCounter;
+    use crate::math_core::number_theory::number_theory_service::{
+        NumberTheoryService, NumberTheoryServiceTrait,
+    };
+    use atomic_counter::{AtomicCounter, RelaxedCounter};
     use bigdecimal::num_bigint::BigInt;
 
-    use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+    use crate::math_core::prime_generator::PrimeGenerator;
+    use crate::math_core::pseudo_random_number_generator::{
+        PrngBackend, PseudoRandomNumberGenerator,
+    };
+    use crate::math_core::random_source::PrngRandomSource;
     use crate::math_core::traits::divisible::Divisible;
 
     #[test]
     fn test_happy_flow() {
-        let service: NumberTheoryService = NumberTheoryService::new(Fast);
         let a: BigInt = 1u32.into();
         let b: BigInt = 997u32.into();
 
-        let random = PseudoRandomNumberGenerator::new(13, service);
+        let random = PseudoRandomNumberGenerator::new(13);
 
         let n = RelaxedCounter::new(1);
 
@@ -286,7 +227,7 @@ mod tests {
         let a: BigInt = 500u32.into();
         let b: BigInt = 6000u32.into();
 
-        let random = PseudoRandomNumberGenerator::new(40, service);
+        let random = PseudoRandomNumberGenerator::new(40);
 
         for _ in 1..500 {
             let random = random.take(&a, &b, &n);
@@ -296,11 +237,10 @@ mod tests {
 
     #[test]
     fn test_take_uneven() {
-        let service: NumberTheoryService = NumberTheoryService::new(Fast);
         let a: BigInt = 500u32.into();
         let b: BigInt = 6000u32.into();
 
-        let random = PseudoRandomNumberGenerator::new(23, service);
+        let random = PseudoRandomNumberGenerator::new(23);
 
         let n = RelaxedCounter::new(1);
 
@@ -310,4 +250,60 @@ mod tests {
             assert!(random.is_not_divisible_by(&BigInt::from(2)));
         }
     }
+
+    #[test]
+    fn test_secure_backends() {
+        let service: NumberTheoryService = NumberTheoryService::new(Fast);
+        for backend in [
+            PrngBackend::HmacDrbg,
+            PrngBackend::ChaCha20,
+            PrngBackend::BlumBlumShub,
+        ] {
+            let random = PseudoRandomNumberGenerator::with_backend(backend);
+            let n = RelaxedCounter::new(1);
+
+            // Alle Werte aus dem kleinen Bereich werden getroffen, keiner liegt außerhalb.
+            let (a, b) = (BigInt::from(3), BigInt::from(12));
+            let mut seen = [false; 10];
+            for _ in 0..500 {
+                let value = random.take(&a, &b, &n);
+                assert!(value >= a && value <= b);
+                seen[usize::try_from(value - &a).unwrap()] = true;
+            }
+            assert!(seen.iter().all(|&hit| hit), "{:?}", backend);
+            assert_eq!(n.get(), 501);
+
+            let value = random.take_uneven(&500.into(), &6000.into(), &n);
+            assert!(value.is_not_divisible_by(&BigInt::from(2)));
+
+            let source = PrngRandomSource::new(PseudoRandomNumberGenerator::with_backend(backend));
+            let prime = PrimeGenerator::new(service).generate_prime(256, 20, &source);
+            assert_eq!(prime.bits(), 256);
+            assert!(service.is_probably_prime(&prime, 20, &source));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "leer")]
+    fn test_secure_backend_rejects_empty_range() {
+        let random = PseudoRandomNumberGenerator::with_backend(PrngBackend::HmacDrbg);
+        random.take(&1.into(), &0.into(), &RelaxedCounter::new(1));
+    }
+
+    #[test]
+    fn test_secure_backends_are_not_reproducible() {
+        let (a, b) = (BigInt::from(0), BigInt::from(2).pow(128));
+        let first = PseudoRandomNumberGenerator::with_backend(PrngBackend::ChaCha20);
+        let second = PseudoRandomNumberGenerator::with_backend(PrngBackend::ChaCha20);
+        let n = RelaxedCounter::new(1);
+        assert_ne!(first.take(&a, &b, &n), second.take(&a, &b, &n));
+
+        // Die Weyl-Folge bleibt dagegen zum selben Seed reproduzierbar.
+        let first = PseudoRandomNumberGenerator::with_backend(PrngBackend::Weyl(13));
+        let second = PseudoRandomNumberGenerator::new(13);
+        assert_eq!(
+            first.take(&a, &b, &RelaxedCounter::new(1)),
+            second.take(&a, &b, &RelaxedCounter::new(1))
+        );
+    }
 }
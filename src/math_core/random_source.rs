@@ -0,0 +1,222 @@
+use std::collections::VecDeque;
+use std::sync::{Mutex, PoisonError};
+
+use atomic_counter::RelaxedCounter;
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::One;
+
+use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+
+///
+/// Gemeinsame Schnittstelle aller Quellen von Zufallszahlen, aus denen Schlüssel, Nonces und
+/// Primzahlkandidaten gezogen werden. Die Verfahren hängen damit nicht mehr an einem konkreten
+/// Generator: Mit einem Seed sind die Ergebnisse reproduzierbar, in Tests können aufgezeichnete
+/// Werte eingespielt werden.
+///
+/// Da der Miller-Rabin-Test parallel läuft, muss eine Quelle über Threads hinweg nutzbar sein.
+///
+pub trait RandomSource: Sync {
+    /// Gibt eine Zufallszahl im Bereich von a bis b (inklusive) zurück.
+    ///
+    /// # Argumente
+    /// * `a` - Die untere Grenze des Bereichs.
+    /// * `b` - Die obere Grenze des Bereichs.
+    ///
+    /// # Rückgabe
+    /// Die Zufallszahl.
+    fn take(&self, a: &BigInt, b: &BigInt) -> BigInt;
+
+    /// Gibt eine ungerade Zufallszahl im Bereich von a bis b zurück.
+    ///
+    /// # Argumente
+    /// * `a` - Die untere Grenze des Bereichs.
+    /// * `b` - Die obere Grenze des Bereichs.
+    ///
+    /// # Rückgabe
+    /// Die ungerade Zufallszahl.
+    fn take_uneven(&self, a: &BigInt, b: &BigInt) -> BigInt {
+        self.take(a, b) | BigInt::one()
+    }
+}
+
+///
+/// Die Zufallsfolge eines `PseudoRandomNumberGenerator` zusammen mit dem Zähler, der den Index
+/// des nächsten Elements hält. Der Zähler beginnt wie beim `NumberTheoryWithPrngService` bei 1.
+///
+pub struct PrngRandomSource {
+    prng: PseudoRandomNumberGenerator,
+    counter: RelaxedCounter,
+}
+
+impl PrngRandomSource {
+    /// Erstellt die Quelle für den übergebenen Generator.
+    pub fn new(prng: PseudoRandomNumberGenerator) -> Self {
+        PrngRandomSource {
+            prng,
+            counter: RelaxedCounter::new(1),
+        }
+    }
+
+    /// Erstellt die Quelle für die reproduzierbare Weyl-Folge zum Seed.
+    ///
+    /// # Argumente
+    /// * `random_seed` - Seed für die Zufallszahlfolge.
+    pub fn seeded(random_seed: u32) -> Self {
+        Self::new(PseudoRandomNumberGenerator::new(random_seed))
+    }
+}
+
+impl RandomSource for PrngRandomSource {
+    fn take(&self, a: &BigInt, b: &BigInt) -> BigInt {
+        self.prng.take(a, b, &self.counter)
+    }
+}
+
+///
+/// Spielt zuvor aufgezeichnete Zufallszahlen in der gegebenen Reihenfolge ab, z.B. um in Tests
+/// eine bestimmte Nonce vorzugeben oder einen Lauf mit `RecordingRandomSource` zu wiederholen.
+///
+/// Ist die Aufzeichnung erschöpft oder liegt ein Wert außerhalb des angefragten Bereichs, liegt
+/// ein Fehler im Test vor und die Quelle bricht mit einem Panic ab.
+///
+pub struct RecordedRandomSource {
+    values: Mutex<VecDeque<BigInt>>,
+}
+
+impl RecordedRandomSource {
+    /// Erstellt die Quelle mit den abzuspielenden Werten.
+    pub fn new(values: impl IntoIterator<Item = BigInt>) -> Self {
+        RecordedRandomSource {
+            values: Mutex::new(values.into_iter().collect()),
+        }
+    }
+
+    /// Die Anzahl der noch nicht abgespielten Werte.
+    pub fn remaining(&self) -> usize {
+        self.values
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+}
+
+impl RandomSource for RecordedRandomSource {
+    fn take(&self, a: &BigInt, b: &BigInt) -> BigInt {
+        let value = self
+            .values
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .pop_front()
+            .expect("Die aufgezeichneten Zufallszahlen sind erschöpft");
+        assert!(
+            a <= &value && &value <= b,
+            "Die aufgezeichnete Zufallszahl {} liegt nicht im Bereich von {} bis {}",
+            value,
+            a,
+            b
+        );
+        value
+    }
+
+    /// Spielt den Wert unverändert ab, damit eine Aufzeichnung exakt wiederholt wird.
+    fn take_uneven(&self, a: &BigInt, b: &BigInt) -> BigInt {
+        self.take(a, b)
+    }
+}
+
+///
+/// Reicht die Zufallszahlen einer anderen Quelle durch und zeichnet sie dabei auf.
+///
+pub struct RecordingRandomSource<S: RandomSource> {
+    inner: S,
+    recorded: Mutex<Vec<BigInt>>,
+}
+
+impl<S: RandomSource> RecordingRandomSource<S> {
+    /// Erstellt die Aufzeichnung für die übergebene Quelle.
+    pub fn new(inner: S) -> Self {
+        RecordingRandomSource {
+            inner,
+            recorded: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Gibt die bisher gezogenen Werte in der Reihenfolge ihres Auftretens zurück.
+    pub fn recorded(&self) -> Vec<BigInt> {
+        self.recorded
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+impl<S: RandomSource> RandomSource for RecordingRandomSource<S> {
+    fn take(&self, a: &BigInt, b: &BigInt) -> BigInt {
+        self.record(self.inner.take(a, b))
+    }
+
+    fn take_uneven(&self, a: &BigInt, b: &BigInt) -> BigInt {
+        self.record(self.inner.take_uneven(a, b))
+    }
+}
+
+impl<S: RandomSource> RecordingRandomSource<S> {
+    fn record(&self, value: BigInt) -> BigInt {
+        self.recorded
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(value.clone());
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prng_source_matches_prng() {
+        let source = PrngRandomSource::seeded(13);
+        let (a, b) = (BigInt::from(1), BigInt::from(997));
+        assert_eq!(source.take(&a, &b), BigInt::from(604));
+        assert_eq!(source.take(&a, &b), BigInt::from(211));
+        assert_eq!(source.take_uneven(&a, &b), BigInt::from(815));
+    }
+
+    #[test]
+    fn test_record_and_replay() {
+        let recording = RecordingRandomSource::new(PrngRandomSource::seeded(5));
+        let (a, b) = (BigInt::from(100), BigInt::from(100_000));
+        let drawn = vec![
+            recording.take(&a, &b),
+            recording.take_uneven(&a, &b),
+            recording.take(&a, &b),
+        ];
+        assert_eq!(recording.recorded(), drawn);
+
+        let replay = RecordedRandomSource::new(recording.recorded());
+        assert_eq!(replay.remaining(), 3);
+        let replayed = vec![
+            replay.take(&a, &b),
+            replay.take_uneven(&a, &b),
+            replay.take(&a, &b),
+        ];
+        assert_eq!(replayed, drawn);
+        assert_eq!(replay.remaining(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "erschöpft")]
+    fn test_replay_panics_when_exhausted() {
+        let replay = RecordedRandomSource::new([BigInt::from(3)]);
+        replay.take(&1.into(), &5.into());
+        replay.take(&1.into(), &5.into());
+    }
+
+    #[test]
+    #[should_panic(expected = "nicht im Bereich")]
+    fn test_replay_panics_for_value_out_of_range() {
+        let replay = RecordedRandomSource::new([BigInt::from(7)]);
+        replay.take(&1.into(), &5.into());
+    }
+}
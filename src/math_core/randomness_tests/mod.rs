@@ -0,0 +1,404 @@
+pub mod special_functions;
+
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::ToPrimitive;
+
+use crate::math_core::random_source::RandomSource;
+use crate::math_core::randomness_tests::special_functions::{erfc, igamc};
+
+/// Das Signifikanzniveau, mit dem NIST SP 800-22 die Tests auswertet. Eine Folge besteht einen
+/// Test, wenn alle p-Werte mindestens so groß sind.
+pub const SIGNIFICANCE_LEVEL: f64 = 0.01;
+/// Die Mindestlänge der Bitfolge, ab der alle Tests der Suite anwendbar sind.
+pub const MIN_BIT_COUNT: usize = 128;
+
+///
+/// Das Ergebnis eines statistischen Tests. Einige Tests, z.B. der Serial-Test, liefern mehrere
+/// p-Werte.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct RandomnessTestResult {
+    pub name: &'static str,
+    pub p_values: Vec<f64>,
+}
+
+impl RandomnessTestResult {
+    /// Gibt an, ob alle p-Werte das Signifikanzniveau erreichen.
+    pub fn passed(&self) -> bool {
+        self.p_values.iter().all(|&p| p >= SIGNIFICANCE_LEVEL)
+    }
+}
+
+/// Zieht `count` Stichproben mit je `bits_per_sample` Bit aus der Quelle.
+///
+/// # Argumente
+/// * `random` - Die Quelle der Zufallszahlen.
+/// * `count` - Die Anzahl der Stichproben.
+/// * `bits_per_sample` - Die Breite einer Stichprobe, höchstens 64 Bit.
+///
+/// # Rückgabe
+/// * `Vec<u64>` - Die Stichproben aus [0, 2^bits_per_sample).
+pub fn draw_samples(random: &dyn RandomSource, count: usize, bits_per_sample: u32) -> Vec<u64> {
+    let upper_bound = (BigInt::from(1) << bits_per_sample) - 1;
+    (0..count)
+        .map(|_| {
+            // Das unwrap ist sicher, weil die Zahl aus [0, 2^bits_per_sample) stammt.
+            random
+                .take(&BigInt::from(0), &upper_bound)
+                .to_u64()
+                .unwrap()
+        })
+        .collect()
+}
+
+/// Hängt die Bits der Stichproben, jeweils beginnend mit dem höchstwertigen, aneinander.
+pub fn to_bits(samples: &[u64], bits_per_sample: u32) -> Vec<bool> {
+    samples
+        .iter()
+        .flat_map(|sample| {
+            (0..bits_per_sample)
+                .rev()
+                .map(move |i| (sample >> i) & 1 == 1)
+        })
+        .collect()
+}
+
+/// Führt alle Tests mit Parametern aus, die NIST SP 800-22 für die Länge der Folge empfiehlt.
+///
+/// # Argumente
+/// * `samples` - Die Stichproben.
+/// * `bits_per_sample` - Die Breite einer Stichprobe.
+///
+/// # Rückgabe
+/// * `Vec<RandomnessTestResult>` - Die Ergebnisse der einzelnen Tests.
+///
+/// # Fehler
+/// * Falls die Folge kürzer als `MIN_BIT_COUNT` Bit ist.
+pub fn run_test_suite(samples: &[u64], bits_per_sample: u32) -> Result<Vec<RandomnessTestResult>> {
+    let bits = to_bits(samples, bits_per_sample);
+    let n = bits.len();
+    ensure!(
+        n >= MIN_BIT_COUNT,
+        "The tests need at least {} bits, but only {} were generated",
+        MIN_BIT_COUNT,
+        n
+    );
+    let log_n = n.ilog2() as usize;
+    // Es gilt M >= 20, M > 0.01 · n und damit weniger als 100 Blöcke.
+    let block_length = (n / 100 + 1).max(20);
+    // Für den Serial-Test muss m < log2(n) - 2 sein, für die approximative Entropie m < log2(n) - 5.
+    let serial_length = (log_n - 3).clamp(2, 16);
+    let entropy_length = (log_n - 6).clamp(1, 14);
+
+    Ok(vec![
+        frequency_test(&bits),
+        block_frequency_test(&bits, block_length),
+        runs_test(&bits),
+        longest_run_test(&bits)?,
+        serial_test(&bits, serial_length),
+        approximate_entropy_test(&bits, entropy_length),
+        chi_square_test(samples, bits_per_sample),
+    ])
+}
+
+/// Frequency (Monobit) Test, NIST SP 800-22, Abschnitt 2.1: Sind Nullen und Einsen etwa gleich
+/// häufig?
+pub fn frequency_test(bits: &[bool]) -> RandomnessTestResult {
+    let n = bits.len() as f64;
+    let sum: i64 = bits.iter().map(|&bit| if bit { 1 } else { -1 }).sum();
+    let statistic = sum.unsigned_abs() as f64 / n.sqrt();
+    RandomnessTestResult {
+        name: "frequency",
+        p_values: vec![erfc(statistic / 2f64.sqrt())],
+    }
+}
+
+/// Frequency Test within a Block, Abschnitt 2.2: Ist der Anteil der Einsen in jedem Block der
+/// Länge M etwa 1/2? Bits nach dem letzten vollständigen Block werden ignoriert.
+pub fn block_frequency_test(bits: &[bool], block_length: usize) -> RandomnessTestResult {
+    let blocks = bits.chunks_exact(block_length);
+    let block_count = blocks.len() as f64;
+    let chi_square = 4.0
+        * block_length as f64
+        * blocks
+            .map(|block| {
+                let proportion = count_ones(block) as f64 / block_length as f64;
+                (proportion - 0.5).powi(2)
+            })
+            .sum::<f64>();
+    RandomnessTestResult {
+        name: "block_frequency",
+        p_values: vec![igamc(block_count / 2.0, chi_square / 2.0)],
+    }
+}
+
+/// Runs Test, Abschnitt 2.3: Entspricht die Anzahl der ununterbrochenen Folgen gleicher Bits der
+/// Erwartung? Besteht die Folge schon den Vortest auf die Häufigkeit nicht, ist der p-Wert 0.
+pub fn runs_test(bits: &[bool]) -> RandomnessTestResult {
+    let n = bits.len() as f64;
+    let proportion = count_ones(bits) as f64 / n;
+    let p_value = if (proportion - 0.5).abs() >= 2.0 / n.sqrt() {
+        0.0
+    } else {
+        let runs = 1 + bits.windows(2).filter(|pair| pair[0] != pair[1]).count();
+        let expected = 2.0 * n * proportion * (1.0 - proportion);
+        erfc(
+            (runs as f64 - expected).abs()
+                / (2.0 * (2.0 * n).sqrt() * proportion * (1.0 - proportion)),
+        )
+    };
+    RandomnessTestResult {
+        name: "runs",
+        p_values: vec![p_value],
+    }
+}
+
+/// Test for the Longest Run of Ones in a Block, Abschnitt 2.4: Wie lang ist die längste Folge
+/// von Einsen in jedem Block? Blocklänge, Klassen und deren Wahrscheinlichkeiten hängen von der
+/// Länge der Folge ab.
+///
+/// # Fehler
+/// * Falls die Folge kürzer als 128 Bit ist.
+pub fn longest_run_test(bits: &[bool]) -> Result<RandomnessTestResult> {
+    let n = bits.len();
+    ensure!(
+        n >= 128,
+        "The longest run test needs at least 128 bits, but got {}",
+        n
+    );
+    let (block_length, shortest_class, probabilities): (usize, usize, &[f64]) = if n < 6272 {
+        (8, 1, &[0.2148, 0.3672, 0.2305, 0.1875])
+    } else if n < 750_000 {
+        (128, 4, &[0.1174, 0.2430, 0.2493, 0.1752, 0.1027, 0.1124])
+    } else {
+        (
+            10_000,
+            10,
+            &[0.0882, 0.2092, 0.2483, 0.1933, 0.1208, 0.0675, 0.0727],
+        )
+    };
+
+    let mut frequencies = vec![0u64; probabilities.len()];
+    let blocks = bits.chunks_exact(block_length);
+    let block_count = blocks.len() as f64;
+    for block in blocks {
+        let (longest, _) = block.iter().fold((0, 0), |(longest, current), &bit| {
+            let current = if bit { current + 1 } else { 0 };
+            (longest.max(current), current)
+        });
+        let class = longest.clamp(shortest_class, shortest_class + probabilities.len() - 1);
+        frequencies[class - shortest_class] += 1;
+    }
+
+    let chi_square: f64 = frequencies
+        .iter()
+        .zip(probabilities)
+        .map(|(&frequency, probability)| {
+            let expected = block_count * probability;
+            (frequency as f64 - expected).powi(2) / expected
+        })
+        .sum();
+    let degrees_of_freedom = (probabilities.len() - 1) as f64;
+    Ok(RandomnessTestResult {
+        name: "longest_run",
+        p_values: vec![igamc(degrees_of_freedom / 2.0, chi_square / 2.0)],
+    })
+}
+
+/// Serial Test, Abschnitt 2.11: Kommen alle überlappenden Bitmuster der Länge m etwa gleich
+/// häufig vor? Liefert die beiden p-Werte für ∇ψ²_m und ∇²ψ²_m.
+pub fn serial_test(bits: &[bool], pattern_length: usize) -> RandomnessTestResult {
+    let psi_square = |length: usize| {
+        if length == 0 {
+            return 0.0;
+        }
+        let n = bits.len() as f64;
+        let sum: f64 = pattern_counts(bits, length)
+            .iter()
+            .map(|&count| (count as f64).powi(2))
+            .sum();
+        (1u64 << length) as f64 / n * sum - n
+    };
+    let psi_m = psi_square(pattern_length);
+    let psi_m1 = psi_square(pattern_length - 1);
+    let psi_m2 = psi_square(pattern_length.saturating_sub(2));
+
+    let delta = psi_m - psi_m1;
+    let delta_square = psi_m - 2.0 * psi_m1 + psi_m2;
+    let degrees = (1u64 << pattern_length) as f64;
+    RandomnessTestResult {
+        name: "serial",
+        p_values: vec![
+            igamc(degrees / 4.0, delta / 2.0),
+            igamc(degrees / 8.0, delta_square / 2.0),
+        ],
+    }
+}
+
+/// Approximate Entropy Test, Abschnitt 2.12: Vergleicht die Häufigkeiten der überlappenden
+/// Bitmuster der Längen m und m + 1 mit der Erwartung einer zufälligen Folge.
+pub fn approximate_entropy_test(bits: &[bool], pattern_length: usize) -> RandomnessTestResult {
+    let n = bits.len() as f64;
+    let phi = |length: usize| -> f64 {
+        pattern_counts(bits, length)
+            .iter()
+            .filter(|&&count| count > 0)
+            .map(|&count| {
+                let proportion = count as f64 / n;
+                proportion * proportion.ln()
+            })
+            .sum()
+    };
+    let approximate_entropy = phi(pattern_length) - phi(pattern_length + 1);
+    let chi_square = 2.0 * n * (2f64.ln() - approximate_entropy);
+    RandomnessTestResult {
+        name: "approximate_entropy",
+        p_values: vec![igamc(
+            (1u64 << (pattern_length - 1)) as f64,
+            chi_square / 2.0,
+        )],
+    }
+}
+
+/// Chi-Quadrat-Anpassungstest auf Gleichverteilung der Stichproben. Er gehört nicht zu
+/// NIST SP 800-22, prüft aber direkt die Ausgabe von `take`. Die Stichproben werden nach ihren
+/// höchstwertigen Bits in höchstens 256 Klassen eingeteilt, sodass jede Klasse mindestens fünf
+/// Stichproben erwarten lässt.
+pub fn chi_square_test(samples: &[u64], bits_per_sample: u32) -> RandomnessTestResult {
+    let mut class_bits = bits_per_sample.min(8);
+    while class_bits > 1 && samples.len() < 5 << class_bits {
+        class_bits -= 1;
+    }
+    let class_count = 1usize << class_bits;
+
+    let mut frequencies = vec![0u64; class_count];
+    for sample in samples {
+        frequencies[(sample >> (bits_per_sample - class_bits)) as usize] += 1;
+    }
+    let expected = samples.len() as f64 / class_count as f64;
+    let chi_square: f64 = frequencies
+        .iter()
+        .map(|&frequency| (frequency as f64 - expected).powi(2) / expected)
+        .sum();
+    RandomnessTestResult {
+        name: "chi_square",
+        p_values: vec![igamc((class_count - 1) as f64 / 2.0, chi_square / 2.0)],
+    }
+}
+
+fn count_ones(bits: &[bool]) -> usize {
+    bits.iter().filter(|&&bit| bit).count()
+}
+
+/// Zählt die überlappenden Bitmuster der Länge `length`. Wie in NIST SP 800-22 wird die Folge
+/// dafür zyklisch um ihre ersten `length - 1` Bits verlängert.
+fn pattern_counts(bits: &[bool], length: usize) -> Vec<u64> {
+    let mut counts = vec![0u64; 1 << length];
+    let mask = (1usize << length) - 1;
+    let mut pattern = bits[..length - 1]
+        .iter()
+        .fold(0usize, |pattern, &bit| (pattern << 1) | bit as usize);
+    for i in 0..bits.len() {
+        pattern = ((pattern << 1) | bits[(i + length - 1) % bits.len()] as usize) & mask;
+        counts[pattern] += 1;
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math_core::csprng::chacha20_rng::ChaCha20Rng;
+    use crate::math_core::csprng::RandomByteGenerator;
+
+    /// Die ersten 100 Binärstellen von e aus den Beispielen von NIST SP 800-22.
+    const EPSILON_100: &str = "1100100100001111110110101010001000100001011010001100001000110100\
+                               110001001100011001100010100010111000";
+
+    fn bits(value: &str) -> Vec<bool> {
+        value.chars().map(|c| c == '1').collect()
+    }
+
+    fn assert_p_values(result: RandomnessTestResult, expected: &[f64]) {
+        assert_eq!(result.p_values.len(), expected.len(), "{}", result.name);
+        for (actual, expected) in result.p_values.iter().zip(expected) {
+            assert!(
+                (actual - expected).abs() < 1e-5,
+                "{}: {} statt {}",
+                result.name,
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_nist_examples() {
+        assert_p_values(frequency_test(&bits("1011010101")), &[0.527089]);
+        assert_p_values(frequency_test(&bits(EPSILON_100)), &[0.109599]);
+        assert_p_values(block_frequency_test(&bits("0110011010"), 3), &[0.801252]);
+        assert_p_values(block_frequency_test(&bits(EPSILON_100), 10), &[0.706438]);
+        assert_p_values(runs_test(&bits("1001101011")), &[0.147232]);
+        assert_p_values(runs_test(&bits(EPSILON_100)), &[0.500798]);
+        assert_p_values(serial_test(&bits("0011011101"), 3), &[0.808792, 0.670320]);
+        assert_p_values(
+            approximate_entropy_test(&bits("0100110101"), 3),
+            &[0.261961],
+        );
+        assert_p_values(approximate_entropy_test(&bits(EPSILON_100), 2), &[0.235301]);
+    }
+
+    #[test]
+    fn test_longest_run_nist_example() {
+        let epsilon = bits(
+            "11001100000101010110110001001100111000000000001001\
+             00110101010001000100111101011010000000110101111100\
+             1100111001101101100010110010",
+        );
+        // NIST gibt für χ² = 4.882605 den gerundeten p-Wert 0.180609 an, exakt ist Q(3/2, χ²/2)
+        // aber 0.180598.
+        assert_p_values(longest_run_test(&epsilon).unwrap(), &[0.180598]);
+        assert!(longest_run_test(&epsilon[..127]).is_err());
+    }
+
+    #[test]
+    fn test_chi_square() {
+        // Jede der vier Klassen ist genau fünfmal besetzt.
+        let uniform: Vec<u64> = (0..20).map(|i| i % 4).collect();
+        assert_p_values(chi_square_test(&uniform, 2), &[1.0]);
+
+        let constant = vec![3u64; 1000];
+        assert!(!chi_square_test(&constant, 8).passed());
+    }
+
+    #[test]
+    fn test_to_bits() {
+        assert_eq!(to_bits(&[0b101, 0b011], 3), bits("101011"));
+    }
+
+    #[test]
+    fn test_suite_passes_for_chacha20() {
+        let mut rng = ChaCha20Rng::new([42; 32]);
+        let mut bytes = [0u8; 20_000];
+        rng.fill_bytes(&mut bytes);
+        let samples: Vec<u64> = bytes.iter().map(|&byte| byte as u64).collect();
+
+        let results = run_test_suite(&samples, 8).unwrap();
+        assert_eq!(results.len(), 7);
+        for result in results {
+            assert!(result.passed(), "{:?}", result);
+        }
+    }
+
+    #[test]
+    fn test_suite_detects_biased_sequence() {
+        // Jedes Byte hat genau eine gesetzte Stelle, Einsen sind also viel zu selten.
+        let samples: Vec<u64> = (0..2_000).map(|i| 1 << (i % 8)).collect();
+        let results = run_test_suite(&samples, 8).unwrap();
+        let frequency = results.iter().find(|result| result.name == "frequency");
+        assert!(!frequency.unwrap().passed());
+
+        assert!(run_test_suite(&[1, 2, 3], 8).is_err());
+    }
+}
@@ -0,0 +1,138 @@
+use std::f64::consts::PI;
+
+/// Die relative Genauigkeit, ab der Reihe und Kettenbruch abgebrochen werden.
+const EPSILON: f64 = 1e-15;
+/// Die maximale Anzahl an Iterationen für Reihe und Kettenbruch.
+const MAX_ITERATIONS: usize = 10_000;
+/// Ersetzt Nenner nahe 0 im Algorithmus von Lentz.
+const FLOATING_POINT_MIN: f64 = 1e-300;
+
+/// Die Koeffizienten der Lanczos-Approximation mit g = 7.
+const LANCZOS_COEFFICIENTS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+/// Berechnet ln Γ(x) für x > 0 mit der Lanczos-Approximation.
+pub fn ln_gamma(x: f64) -> f64 {
+    if x < 0.5 {
+        // Spiegelungsformel Γ(x) · Γ(1 - x) = π / sin(πx)
+        return (PI / (PI * x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+    let x = x - 1.0;
+    let sum = LANCZOS_COEFFICIENTS[1..]
+        .iter()
+        .enumerate()
+        .fold(LANCZOS_COEFFICIENTS[0], |sum, (i, coefficient)| {
+            sum + coefficient / (x + i as f64 + 1.0)
+        });
+    let t = x + 7.5;
+    0.5 * (2.0 * PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+}
+
+/// Berechnet die regularisierte obere unvollständige Gammafunktion Q(a, x) = Γ(a, x) / Γ(a),
+/// in der Notation von NIST SP 800-22 `igamc`. Für x < a + 1 wird die Reihe der unteren
+/// Funktion P(a, x) verwendet, sonst der Kettenbruch von Q(a, x) nach Lentz.
+///
+/// # Argumente
+/// * `a` - Der Parameter a > 0.
+/// * `x` - Die Stelle x >= 0.
+pub fn igamc(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 1.0;
+    }
+    let prefactor = (-x + a * x.ln() - ln_gamma(a)).exp();
+    if x < a + 1.0 {
+        let mut term = 1.0 / a;
+        let mut sum = term;
+        for n in 1..MAX_ITERATIONS {
+            term *= x / (a + n as f64);
+            sum += term;
+            if term.abs() < sum.abs() * EPSILON {
+                break;
+            }
+        }
+        return 1.0 - sum * prefactor;
+    }
+
+    let mut b = x + 1.0 - a;
+    let mut c = 1.0 / FLOATING_POINT_MIN;
+    let mut d = 1.0 / b;
+    let mut h = d;
+    for i in 1..MAX_ITERATIONS {
+        let an = -(i as f64) * (i as f64 - a);
+        b += 2.0;
+        d = an * d + b;
+        if d.abs() < FLOATING_POINT_MIN {
+            d = FLOATING_POINT_MIN;
+        }
+        c = b + an / c;
+        if c.abs() < FLOATING_POINT_MIN {
+            c = FLOATING_POINT_MIN;
+        }
+        d = 1.0 / d;
+        let delta = d * c;
+        h *= delta;
+        if (delta - 1.0).abs() < EPSILON {
+            break;
+        }
+    }
+    prefactor * h
+}
+
+/// Berechnet die komplementäre Fehlerfunktion über erfc(x) = Q(1/2, x^2) für x >= 0.
+pub fn erfc(x: f64) -> f64 {
+    let value = igamc(0.5, x * x);
+    if x < 0.0 {
+        2.0 - value
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "{} weicht von {} ab",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_ln_gamma() {
+        assert_close(ln_gamma(1.0), 0.0);
+        assert_close(ln_gamma(5.0), 24f64.ln());
+        assert_close(ln_gamma(0.5), PI.sqrt().ln());
+        assert_close(ln_gamma(0.25), 1.288_022_524_698_077_5);
+    }
+
+    #[test]
+    fn test_igamc() {
+        // Für a = 1 ist Q(1, x) = e^-x, für a = 2 ist Q(2, x) = e^-x · (1 + x).
+        for x in [0.1, 1.0, 2.5, 10.0] {
+            assert_close(igamc(1.0, x), (-x).exp());
+            assert_close(igamc(2.0, x), (-x).exp() * (1.0 + x));
+        }
+        assert_close(igamc(3.0, 0.0), 1.0);
+    }
+
+    #[test]
+    fn test_erfc() {
+        assert_close(erfc(0.0), 1.0);
+        assert_close(erfc(0.5), 0.479_500_122_186_953_5);
+        assert_close(erfc(1.0), 0.157_299_207_050_285_13);
+        assert_close(erfc(-1.0), 1.842_700_792_949_715);
+    }
+}
@@ -0,0 +1,323 @@
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+
+use crate::shared::errors::KeyEncodingError;
+
+const INTEGER_TAG: u8 = 0x02;
+const BIT_STRING_TAG: u8 = 0x03;
+const OCTET_STRING_TAG: u8 = 0x04;
+const NULL_TAG: u8 = 0x05;
+const OBJECT_IDENTIFIER_TAG: u8 = 0x06;
+const SEQUENCE_TAG: u8 = 0x30;
+const CLASS_MASK: u8 = 0xc0;
+const CONTEXT_SPECIFIC_CLASS: u8 = 0x80;
+const HIGH_TAG_NUMBER: u8 = 0x1f;
+
+/// Ein Wert in ASN.1 mit den Distinguished Encoding Rules (DER, ITU-T X.690).
+/// Es werden nur die Typen unterstützt, die für Schlüsselformate wie PKCS#1, PKCS#8 und
+/// SubjectPublicKeyInfo benötigt werden.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DerValue {
+    Integer(BigInt),
+    /// Ein Bit-String, dessen Länge ein Vielfaches von 8 ist (keine ungenutzten Bits).
+    BitString(Vec<u8>),
+    OctetString(Vec<u8>),
+    Null,
+    /// Ein Objektbezeichner, dargestellt durch seine Komponenten, z.B. `[1, 2, 840, 113549]`.
+    ObjectIdentifier(Vec<u64>),
+    Sequence(Vec<DerValue>),
+    /// Ein kontextspezifischer Wert wie `[0] IMPLICIT Attributes`, dargestellt durch das
+    /// vollständige Tag-Byte und den unveränderten Inhalt. Der Inhalt wird nicht dekodiert,
+    /// da seine Bedeutung vom umgebenden Typ abhängt.
+    ContextSpecific(u8, Vec<u8>),
+}
+
+impl DerValue {
+    /// Kodiert den Wert als DER-Bytefolge.
+    ///
+    /// # Rückgabe
+    /// * `Vec<u8>` - Die Kodierung `Tag || Länge || Inhalt`.
+    pub fn encode(&self) -> Vec<u8> {
+        let (tag, content) = match self {
+            DerValue::Integer(value) => (INTEGER_TAG, value.to_signed_bytes_be()),
+            DerValue::BitString(bytes) => {
+                let mut content = vec![0x00];
+                content.extend_from_slice(bytes);
+                (BIT_STRING_TAG, content)
+            }
+            DerValue::OctetString(bytes) => (OCTET_STRING_TAG, bytes.clone()),
+            DerValue::Null => (NULL_TAG, vec![]),
+            DerValue::ObjectIdentifier(arcs) => {
+                (OBJECT_IDENTIFIER_TAG, Self::encode_object_identifier(arcs))
+            }
+            DerValue::Sequence(values) => (
+                SEQUENCE_TAG,
+                values.iter().flat_map(|value| value.encode()).collect(),
+            ),
+            DerValue::ContextSpecific(tag, content) => (*tag, content.clone()),
+        };
+
+        let mut encoded = vec![tag];
+        encoded.extend(Self::encode_length(content.len()));
+        encoded.extend(content);
+        encoded
+    }
+
+    /// Dekodiert genau einen DER-Wert. Folgen auf den Wert weitere Bytes, ist das ein Fehler.
+    ///
+    /// # Argumente
+    /// * `bytes` - Die DER-Bytefolge.
+    ///
+    /// # Rückgabe
+    /// * `DerValue` - Der dekodierte Wert.
+    ///
+    /// # Fehler
+    /// * `KeyEncodingError` - Falls die Bytefolge kein gültiger DER-Wert ist.
+    pub fn decode(bytes: &[u8]) -> Result<DerValue> {
+        let (value, rest) = Self::decode_partial(bytes)?;
+        ensure!(
+            rest.is_empty(),
+            KeyEncodingError::InvalidStructureError("trailing data after DER value".to_string())
+        );
+        Ok(value)
+    }
+
+    /// Dekodiert den ersten DER-Wert der Bytefolge.
+    ///
+    /// # Rückgabe
+    /// * `(DerValue, &[u8])` - Der dekodierte Wert und die restlichen Bytes.
+    fn decode_partial(bytes: &[u8]) -> Result<(DerValue, &[u8])> {
+        let (&tag, rest) = bytes
+            .split_first()
+            .ok_or(KeyEncodingError::UnexpectedEndOfDataError)?;
+        let (length, rest) = Self::decode_length(rest)?;
+        ensure!(
+            rest.len() >= length,
+            KeyEncodingError::UnexpectedEndOfDataError
+        );
+        let (content, rest) = rest.split_at(length);
+
+        let value = match tag {
+            INTEGER_TAG => {
+                ensure!(!content.is_empty(), KeyEncodingError::InvalidLengthError);
+                // DER verlangt eine minimale Zweierkomplement-Darstellung.
+                let is_minimal = content.len() == 1
+                    || !((content[0] == 0x00 && content[1] & 0x80 == 0)
+                        || (content[0] == 0xff && content[1] & 0x80 != 0));
+                ensure!(
+                    is_minimal,
+                    KeyEncodingError::InvalidStructureError(
+                        "integer is not minimally encoded".to_string()
+                    )
+                );
+                DerValue::Integer(BigInt::from_signed_bytes_be(content))
+            }
+            BIT_STRING_TAG => {
+                ensure!(
+                    content.first() == Some(&0x00),
+                    KeyEncodingError::InvalidStructureError(
+                        "bit string with unused bits".to_string()
+                    )
+                );
+                DerValue::BitString(content[1..].to_vec())
+            }
+            OCTET_STRING_TAG => DerValue::OctetString(content.to_vec()),
+            NULL_TAG => {
+                ensure!(content.is_empty(), KeyEncodingError::InvalidLengthError);
+                DerValue::Null
+            }
+            OBJECT_IDENTIFIER_TAG => {
+                DerValue::ObjectIdentifier(Self::decode_object_identifier(content)?)
+            }
+            SEQUENCE_TAG => {
+                let mut values = Vec::new();
+                let mut remaining = content;
+                while !remaining.is_empty() {
+                    let (value, rest) = Self::decode_partial(remaining)?;
+                    values.push(value);
+                    remaining = rest;
+                }
+                DerValue::Sequence(values)
+            }
+            _ if tag & CLASS_MASK == CONTEXT_SPECIFIC_CLASS
+                && tag & HIGH_TAG_NUMBER != HIGH_TAG_NUMBER =>
+            {
+                DerValue::ContextSpecific(tag, content.to_vec())
+            }
+            _ => return Err(KeyEncodingError::UnexpectedTagError(tag).into()),
+        };
+        Ok((value, rest))
+    }
+
+    /// Kodiert eine Länge in der kurzen (< 128) oder langen Form.
+    fn encode_length(length: usize) -> Vec<u8> {
+        if length < 0x80 {
+            return vec![length as u8];
+        }
+        let bytes: Vec<u8> = length
+            .to_be_bytes()
+            .into_iter()
+            .skip_while(|byte| *byte == 0)
+            .collect();
+        let mut encoded = vec![0x80 | bytes.len() as u8];
+        encoded.extend(bytes);
+        encoded
+    }
+
+    /// Dekodiert eine Länge. Die unbestimmte Form und nicht minimale Kodierungen werden abgelehnt.
+    fn decode_length(bytes: &[u8]) -> Result<(usize, &[u8])> {
+        let (&first, rest) = bytes
+            .split_first()
+            .ok_or(KeyEncodingError::UnexpectedEndOfDataError)?;
+        if first < 0x80 {
+            return Ok((first as usize, rest));
+        }
+
+        let length_of_length = (first & 0x7f) as usize;
+        ensure!(
+            length_of_length > 0 && length_of_length <= std::mem::size_of::<usize>(),
+            KeyEncodingError::InvalidLengthError
+        );
+        ensure!(
+            rest.len() >= length_of_length,
+            KeyEncodingError::UnexpectedEndOfDataError
+        );
+        let (length_bytes, rest) = rest.split_at(length_of_length);
+        ensure!(length_bytes[0] != 0, KeyEncodingError::InvalidLengthError);
+
+        let length = length_bytes
+            .iter()
+            .fold(0usize, |length, byte| (length << 8) | *byte as usize);
+        ensure!(length >= 0x80, KeyEncodingError::InvalidLengthError);
+        Ok((length, rest))
+    }
+
+    /// Kodiert einen Objektbezeichner. Die ersten beiden Komponenten werden zu `40 * a + b`
+    /// zusammengefasst, alle Komponenten werden in Basis 128 kodiert.
+    fn encode_object_identifier(arcs: &[u64]) -> Vec<u8> {
+        let mut components = vec![arcs[0] * 40 + arcs[1]];
+        components.extend_from_slice(&arcs[2..]);
+
+        let mut encoded = Vec::new();
+        for component in components {
+            let mut base_128 = vec![(component & 0x7f) as u8];
+            let mut remaining = component >> 7;
+            while remaining > 0 {
+                base_128.push(0x80 | (remaining & 0x7f) as u8);
+                remaining >>= 7;
+            }
+            encoded.extend(base_128.into_iter().rev());
+        }
+        encoded
+    }
+
+    /// Dekodiert einen Objektbezeichner.
+    fn decode_object_identifier(content: &[u8]) -> Result<Vec<u64>> {
+        let mut components = Vec::new();
+        let mut current: u64 = 0;
+        for (index, byte) in content.iter().enumerate() {
+            ensure!(
+                current >> 57 == 0,
+                KeyEncodingError::InvalidStructureError(
+                    "object identifier component too large".to_string()
+                )
+            );
+            current = (current << 7) | (byte & 0x7f) as u64;
+            if byte & 0x80 == 0 {
+                components.push(current);
+                current = 0;
+            } else {
+                ensure!(
+                    index + 1 < content.len(),
+                    KeyEncodingError::UnexpectedEndOfDataError
+                );
+            }
+        }
+        ensure!(!components.is_empty(), KeyEncodingError::InvalidLengthError);
+
+        let first = components[0];
+        let (a, b) = match first {
+            0..=39 => (0, first),
+            40..=79 => (1, first - 40),
+            _ => (2, first - 80),
+        };
+        let mut arcs = vec![a, b];
+        arcs.extend_from_slice(&components[1..]);
+        Ok(arcs)
+    }
+
+    /// Gibt die Elemente zurück, falls der Wert eine Sequenz ist.
+    pub fn as_sequence(&self) -> Result<&[DerValue]> {
+        match self {
+            DerValue::Sequence(values) => Ok(values),
+            _ => {
+                Err(KeyEncodingError::InvalidStructureError("expected SEQUENCE".to_string()).into())
+            }
+        }
+    }
+
+    /// Gibt die Zahl zurück, falls der Wert ein Integer ist.
+    pub fn as_integer(&self) -> Result<&BigInt> {
+        match self {
+            DerValue::Integer(value) => Ok(value),
+            _ => {
+                Err(KeyEncodingError::InvalidStructureError("expected INTEGER".to_string()).into())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_known_values() {
+        assert_eq!(DerValue::Integer(0.into()).encode(), vec![0x02, 0x01, 0x00]);
+        assert_eq!(
+            DerValue::Integer(128.into()).encode(),
+            vec![0x02, 0x02, 0x00, 0x80]
+        );
+        assert_eq!(
+            DerValue::Integer((-129).into()).encode(),
+            vec![0x02, 0x02, 0xff, 0x7f]
+        );
+        assert_eq!(DerValue::Null.encode(), vec![0x05, 0x00]);
+        // rsaEncryption
+        assert_eq!(
+            DerValue::ObjectIdentifier(vec![1, 2, 840, 113549, 1, 1, 1]).encode(),
+            vec![0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x01]
+        );
+    }
+
+    #[test]
+    fn test_encode_decode_roundtrip_with_long_length() {
+        let value = DerValue::Sequence(vec![
+            DerValue::Integer(BigInt::from(2).pow(2048) - 1),
+            DerValue::BitString(vec![1, 2, 3]),
+            DerValue::OctetString(vec![0xab; 300]),
+            DerValue::Null,
+            DerValue::ObjectIdentifier(vec![2, 999, 3]),
+            DerValue::ContextSpecific(0xa0, vec![0x05, 0x00]),
+        ]);
+        let encoded = value.encode();
+        assert_eq!(&encoded[..2], &[0x30, 0x82]);
+        assert_eq!(DerValue::decode(&encoded).unwrap(), value);
+    }
+
+    #[test]
+    fn test_decode_rejects_invalid_data() {
+        // Nicht minimal kodierter Integer
+        assert!(DerValue::decode(&[0x02, 0x02, 0x00, 0x01]).is_err());
+        // Nicht minimal kodierte Länge
+        assert!(DerValue::decode(&[0x05, 0x81, 0x00]).is_err());
+        // Zu kurze Daten
+        assert!(DerValue::decode(&[0x04, 0x03, 0x01]).is_err());
+        // Überzählige Daten
+        assert!(DerValue::decode(&[0x05, 0x00, 0x00]).is_err());
+        // Nicht unterstützter Tag
+        assert!(DerValue::decode(&[0x0c, 0x00]).is_err());
+        // Kontextspezifischer Tag in der langen Form
+        assert!(DerValue::decode(&[0xbf, 0x1f, 0x00]).is_err());
+    }
+}
@@ -1,5 +1,4 @@
 use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
-use crate::math_core::ecc::secure_finite_field_elliptic_curve::SecureFiniteFieldEllipticCurve;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -35,9 +34,280 @@ pub enum MenezesVanstoneError {
 
 #[derive(Error, Debug)]
 pub enum EllipticCurveError {
+    /// Wird geworfen, wenn ein Punkt nicht auf der Kurve liegt.
+    ///
+    /// # Argumente
+    /// * `point` - Der Punkt.
+    /// * `curve` - Die Darstellung der Kurve.
     #[error("Point {0} is not on curve {1}")]
-    PointNotOnCurveError(
-        FiniteFieldEllipticCurvePoint,
-        SecureFiniteFieldEllipticCurve,
-    ),
+    PointNotOnCurveError(FiniteFieldEllipticCurvePoint, String),
+
+    /// Wird geworfen, wenn die Diskriminante 4a^3 + 27b^2 modulo p verschwindet und die Kurve
+    /// damit singulär ist.
+    ///
+    /// # Argumente
+    /// * `a` - Der Koeffizient a.
+    /// * `b` - Der Koeffizient b.
+    /// * `prime` - Der Modulus p.
+    #[error("Curve with a = {0} and b = {1} is singular modulo {2}")]
+    SingularCurveError(String, String, String),
+
+    /// Wird geworfen, wenn der Modulus keine Primzahl größer als 3 ist.
+    ///
+    /// # Argumente
+    /// * `prime` - Der ungültige Modulus.
+    #[error("Modulus must be a prime greater than 3, but it is {0}")]
+    InvalidPrimeError(String),
+
+    /// Wird geworfen, wenn der Generator im Unendlichen oder nicht auf der Kurve liegt.
+    ///
+    /// # Argumente
+    /// * `generator` - Der ungültige Generator.
+    #[error("Invalid generator {0}")]
+    InvalidGeneratorError(FiniteFieldEllipticCurvePoint),
+
+    /// Wird geworfen, wenn keine Standardkurve mit dem Namen bekannt ist.
+    ///
+    /// # Argumente
+    /// * `name` - Der unbekannte Name.
+    #[error("Unknown curve {0}")]
+    UnknownCurveError(String),
+
+    /// Wird geworfen, wenn ein Oktett-String keine gültige SEC1-Kodierung eines Punktes ist.
+    ///
+    /// # Argumente
+    /// * `reason` - Die Beschreibung des Fehlers.
+    #[error("Invalid point encoding: {0}")]
+    InvalidPointEncodingError(String),
+}
+
+#[derive(Debug, Error)]
+pub enum RsaError {
+    /// Wird geworfen, wenn eine Nachricht für die Kodierung zu lang ist.
+    ///
+    /// # Argumente
+    /// * `length` - Die Länge der Nachricht in Bytes.
+    /// * `max_length` - Die maximal erlaubte Länge in Bytes.
+    #[error("Message too long: {0} bytes, but at most {1} bytes are allowed")]
+    MessageTooLongError(usize, usize),
+
+    /// Wird geworfen, wenn der Modulus für das Kodierungsverfahren zu klein ist.
+    ///
+    /// # Argumente
+    /// * `modulus_length` - Die Länge des Modulus in Bytes.
+    #[error("Modulus too short for encoding: {0} bytes")]
+    ModulusTooShortError(usize),
+
+    /// Wird geworfen, wenn die Anzahl der Primfaktoren für die Schlüsselgenerierung ungültig ist.
+    ///
+    /// # Argumente
+    /// * `prime_count` - Die angefragte Anzahl an Primfaktoren.
+    #[error("Prime count must be between 2 and 5, but it is {0}")]
+    InvalidPrimeCountError(u32),
+
+    /// Wird geworfen, wenn der Modulus für die Anzahl der Primfaktoren zu schmal ist.
+    ///
+    /// # Argumente
+    /// * `modulus_width` - Die angefragte Breite des Modulus in Bits.
+    /// * `prime_count` - Die Anzahl der Primfaktoren.
+    /// * `min_width` - Die minimale Breite des Modulus für diese Anzahl in Bits.
+    #[error("Modulus width {0} is too small for {1} primes, at least {2} bits are required")]
+    InvalidModulusWidthError(u32, u32, u32),
+
+    /// Wird geworfen, wenn ein Chiffretext nicht entschlüsselt werden kann.
+    /// Absichtlich ohne Details, um keine Informationen über die Ursache preiszugeben.
+    #[error("Decryption error")]
+    DecryptionError,
+}
+
+#[derive(Debug, Error)]
+pub enum KeyEncodingError {
+    /// Wird geworfen, wenn die DER-Daten vor dem Ende eines Elements aufhören.
+    #[error("Unexpected end of DER data")]
+    UnexpectedEndOfDataError,
+
+    /// Wird geworfen, wenn ein DER-Element einen unerwarteten oder nicht unterstützten Typ hat.
+    ///
+    /// # Argumente
+    /// * `tag` - Der gefundene Tag.
+    #[error("Unexpected or unsupported DER tag 0x{0:02x}")]
+    UnexpectedTagError(u8),
+
+    /// Wird geworfen, wenn die Länge eines DER-Elements ungültig oder nicht minimal kodiert ist.
+    #[error("Invalid DER length")]
+    InvalidLengthError,
+
+    /// Wird geworfen, wenn die Struktur nicht dem erwarteten ASN.1-Schema entspricht.
+    ///
+    /// # Argumente
+    /// * `description` - Eine Beschreibung der erwarteten Struktur.
+    #[error("Invalid key structure: {0}")]
+    InvalidStructureError(String),
+
+    /// Wird geworfen, wenn ein PEM-Block nicht gelesen werden kann.
+    ///
+    /// # Argumente
+    /// * `description` - Eine Beschreibung des Fehlers.
+    #[error("Invalid PEM: {0}")]
+    InvalidPemError(String),
+}
+
+#[derive(Debug, Error)]
+pub enum PaillierError {
+    /// Wird geworfen, wenn der Modulus zu schmal ist, um zwei verschiedene Primfaktoren zu finden.
+    ///
+    /// # Argumente
+    /// * `modulus_width` - Die angefragte Breite des Modulus in Bits.
+    /// * `min_width` - Die minimale Breite des Modulus in Bits.
+    #[error("Modulus width {0} is too small, at least {1} bits are required")]
+    InvalidModulusWidthError(u32, u32),
+}
+
+#[derive(Debug, Error)]
+pub enum RabinError {
+    /// Wird geworfen, wenn eine Nachricht negativ ist oder nach dem Anhängen der Redundanz nicht
+    /// kleiner als der Modulus ist.
+    ///
+    /// # Argumente
+    /// * `message` - Die Nachricht.
+    /// * `modulus` - Der Modulus `n`.
+    #[error("Message {0} is negative or too large for modulus {1}")]
+    MessageTooLargeError(String, String),
+
+    /// Wird geworfen, wenn der Chiffretext kein quadratischer Rest modulo `n` ist und somit keine
+    /// Quadratwurzeln besitzt.
+    ///
+    /// # Argumente
+    /// * `ciphertext` - Der Chiffretext.
+    #[error("Ciphertext {0} is not a quadratic residue")]
+    NotQuadraticResidueError(String),
+
+    /// Wird geworfen, wenn nicht genau eine der vier Quadratwurzeln die Redundanz enthält.
+    ///
+    /// # Argumente
+    /// * `matching_roots` - Die Anzahl der Wurzeln mit passender Redundanz.
+    #[error("Expected exactly one root with valid redundancy, but found {0}")]
+    AmbiguousPlaintextError(usize),
+
+    /// Wird geworfen, wenn der Modulus zu schmal ist, um zwei verschiedene Blum-Primzahlen zu
+    /// finden.
+    ///
+    /// # Argumente
+    /// * `modulus_width` - Die angefragte Breite des Modulus in Bits.
+    /// * `min_width` - Die minimale Breite des Modulus in Bits.
+    #[error("Modulus width {0} is too small, at least {1} bits are required")]
+    InvalidModulusWidthError(u32, u32),
+}
+
+#[derive(Debug, Error)]
+pub enum ElGamalError {
+    /// Wird geworfen, wenn der Modulus zu klein ist, um mindestens ein Zeichen pro Block
+    /// aufzunehmen.
+    ///
+    /// # Argumente
+    /// * `modulus` - Der Modulus `p`.
+    /// * `radix` - Die Basis der Decimal-Unicode-Abbildung.
+    #[error("Modulus {0} is too small for number system base {1}")]
+    ModulusTooSmallError(String, u32),
+
+    /// Wird geworfen, wenn ein Chiffretext keine gerade Anzahl an Blöcken enthält und daher
+    /// nicht in Paare `(a, b)` aufgeteilt werden kann.
+    ///
+    /// # Argumente
+    /// * `block_count` - Die Anzahl der Blöcke.
+    #[error("Ciphertext must consist of pairs (a, b), but it has {0} blocks")]
+    InvalidCiphertextLengthError(usize),
+}
+
+#[derive(Debug, Error)]
+pub enum DsaError {
+    /// Wird geworfen, wenn die Bit-Breiten für die Domain-Parameter ungültig sind.
+    /// Es muss `8 <= N <= 256` (Länge von SHA256) und `N < L` gelten.
+    ///
+    /// # Argumente
+    /// * `modulus_width` - Die Bit-Breite `L` von `p`.
+    /// * `subgroup_width` - Die Bit-Breite `N` von `q`.
+    #[error("Invalid DSA parameter sizes: L = {0}, N = {1}. Required: 8 <= N <= 256 and N < L")]
+    InvalidParameterSizesError(u32, u32),
+
+    /// Wird geworfen, wenn die Domain-Parameter keine Untergruppe primer Ordnung beschreiben,
+    /// in der sich signieren lässt.
+    ///
+    /// # Argumente
+    /// * `reason` - Die verletzte Bedingung.
+    #[error("Invalid DSA domain parameters: {0}")]
+    InvalidDomainParametersError(String),
+}
+
+#[derive(Debug, Error)]
+pub enum SchnorrError {
+    /// Wird geworfen, wenn ein Gruppenelement nicht zum Typ der Gruppe passt, etwa eine Zahl
+    /// für eine elliptische Kurve.
+    ///
+    /// # Argumente
+    /// * `group_type` - Der Typ der Gruppe.
+    #[error("Group element does not match group type {0}")]
+    ElementTypeMismatchError(String),
+
+    /// Wird geworfen, wenn die Ordnung der Gruppe kleiner als 2 ist und es daher keinen
+    /// Geheimwert aus `[1, q - 1]` gibt.
+    ///
+    /// # Argumente
+    /// * `order` - Die Ordnung `q` der Gruppe.
+    #[error("Group order must be at least 2, but is {0}")]
+    InvalidGroupOrderError(String),
+}
+
+#[derive(Debug, Error)]
+pub enum KeyExchangeError {
+    /// Wird geworfen, wenn der öffentliche Wert der Gegenseite ungültig ist, z.B. außerhalb
+    /// von `(1, p - 1)` liegt oder kein Punkt der Untergruppe ist.
+    ///
+    /// # Argumente
+    /// * `public_value` - Der ungültige öffentliche Wert.
+    #[error("Invalid public value {0}")]
+    InvalidPublicValueError(String),
+
+    /// Wird geworfen, wenn das gemeinsame Geheimnis der Punkt im Unendlichen ist.
+    #[error("Shared secret is the point at infinity")]
+    InfiniteSharedSecretError,
+
+    /// Wird geworfen, wenn die gewünschte Schlüssellänge 0 ist oder die Grenze der
+    /// Schlüsselableitung überschreitet.
+    ///
+    /// # Argumente
+    /// * `key_length` - Die ungültige Schlüssellänge in Bytes.
+    /// * `max_key_length` - Die maximale Schlüssellänge in Bytes.
+    #[error("Key length must be between 1 and {1} bytes, but is {0}")]
+    InvalidKeyLengthError(usize, u64),
+}
+
+#[derive(Debug, Error)]
+pub enum BlumBlumShubError {
+    /// Wird geworfen, wenn die Faktoren des Modulus keine zwei verschiedenen Blum-Primzahlen
+    /// (`p ≡ q ≡ 3 mod 4`) sind.
+    ///
+    /// # Argumente
+    /// * `p` - Der erste Faktor.
+    /// * `q` - Der zweite Faktor.
+    #[error("Factors {0} and {1} must be distinct Blum primes")]
+    NonBlumPrimesError(String, String),
+
+    /// Wird geworfen, wenn der Modulus zu schmal ist, um zwei verschiedene Blum-Primzahlen der
+    /// halben Breite zu enthalten.
+    ///
+    /// # Argumente
+    /// * `modulus_width` - Die Bit-Breite des Modulus.
+    /// * `min_width` - Die minimale Bit-Breite.
+    #[error("Modulus width must be at least {1}, but it is {0}")]
+    InvalidModulusWidthError(u32, u32),
+
+    /// Wird geworfen, wenn der Seed nicht teilerfremd zum Modulus ist oder der Startwert
+    /// `seed^2 mod n` die Folge entarten lässt.
+    ///
+    /// # Argumente
+    /// * `seed` - Der ungültige Seed.
+    /// * `modulus` - Der Modulus `n`.
+    #[error("Invalid seed {0} for modulus {1}")]
+    InvalidSeedError(String, String),
 }
@@ -1,7 +1,19 @@
+use anyhow::{ensure, Result};
 use bigdecimal::num_bigint::{BigInt, Sign};
 use log::debug;
 use sha2::{Digest, Sha256};
 
+use crate::shared::errors::KeyExchangeError;
+
+/// Die Länge eines SHA256-Hashes in Bytes.
+pub const SHA256_LENGTH: usize = 32;
+
+/// Die Blocklänge von SHA256 in Bytes.
+const SHA256_BLOCK_LENGTH: usize = 64;
+
+/// Die maximale Schlüssellänge der Concatenation KDF in Bytes, `SHA256_LENGTH · (2^32 - 1)`.
+pub const MAX_KDF_KEY_LENGTH: u64 = SHA256_LENGTH as u64 * u32::MAX as u64;
+
 /// Diese Methode berechnet den SHA256-Hash einer Nachricht.
 ///
 /// # Argumente
@@ -11,11 +23,186 @@ use sha2::{Digest, Sha256};
 /// * `BigInt` - Der Hash.
 pub fn sha256(message: &str) -> BigInt {
     debug!("Hashen der Nachricht {} mit SHA256", message);
-    let mut hasher = Sha256::new();
-    hasher.update(message.as_bytes());
-    let hashed_message = hasher.finalize();
+    let hashed_message = sha256_bytes(message.as_bytes());
 
     // Hash Nachricht in einen BigInt umwandeln
     let message_big_int = BigInt::from_bytes_be(Sign::Plus, &hashed_message);
     message_big_int
 }
+
+/// Diese Methode berechnet den SHA256-Hash einer beliebigen Bytefolge.
+///
+/// # Argumente
+/// * `data` - Die Bytefolge.
+///
+/// # Rückgabe
+/// * `Vec<u8>` - Der Hash als Bytefolge der Länge `SHA256_LENGTH`.
+pub fn sha256_bytes(data: &[u8]) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update(data);
+    hasher.finalize().to_vec()
+}
+
+/// Die Maskengenerierungsfunktion MGF1 aus RFC 8017 (B.2.1) mit SHA256 als Hashfunktion.
+/// Es werden so lange `Hash(seed || counter)` aneinandergehängt, bis die gewünschte Länge
+/// erreicht ist.
+///
+/// # Argumente
+/// * `seed` - Der Seed, aus dem die Maske erzeugt wird.
+/// * `mask_length` - Die gewünschte Länge der Maske in Bytes.
+///
+/// # Rückgabe
+/// * `Vec<u8>` - Die Maske der Länge `mask_length`.
+pub fn mgf1_sha256(seed: &[u8], mask_length: usize) -> Vec<u8> {
+    let mut mask = Vec::with_capacity(mask_length + SHA256_LENGTH);
+    let mut counter: u32 = 0;
+    while mask.len() < mask_length {
+        let mut hasher = Sha256::new();
+        hasher.update(seed);
+        hasher.update(counter.to_be_bytes());
+        mask.extend_from_slice(&hasher.finalize());
+        counter += 1;
+    }
+    mask.truncate(mask_length);
+    mask
+}
+
+/// Die Ein-Schritt-Schlüsselableitung (Concatenation KDF) aus NIST SP 800-56A (5.8.2.1) mit
+/// SHA256. Es werden so lange `Hash(counter || Z || OtherInfo)` mit einem bei 1 beginnenden
+/// Zähler aneinandergehängt, bis die gewünschte Schlüssellänge erreicht ist.
+///
+/// # Argumente
+/// * `shared_secret` - Das gemeinsame Geheimnis `Z` aus dem Schlüsselaustausch.
+/// * `other_info` - Kontextinformationen, die in die Ableitung eingehen.
+/// * `key_length` - Die gewünschte Länge des Schlüssels in Bytes.
+///
+/// # Rückgabe
+/// * `Vec<u8>` - Der abgeleitete Schlüssel der Länge `key_length`.
+///
+/// # Fehler
+/// * `KeyExchangeError::InvalidKeyLengthError` - Falls `key_length` 0 ist oder größer als
+///   `MAX_KDF_KEY_LENGTH`, weil der 32-Bit-Zähler sonst überläuft.
+pub fn concat_kdf_sha256(
+    shared_secret: &[u8],
+    other_info: &[u8],
+    key_length: usize,
+) -> Result<Vec<u8>> {
+    ensure!(
+        key_length > 0 && key_length as u64 <= MAX_KDF_KEY_LENGTH,
+        KeyExchangeError::InvalidKeyLengthError(key_length, MAX_KDF_KEY_LENGTH)
+    );
+    let mut key = Vec::with_capacity(key_length + SHA256_LENGTH);
+    let mut counter: u32 = 1;
+    while key.len() < key_length {
+        let mut hasher = Sha256::new();
+        hasher.update(counter.to_be_bytes());
+        hasher.update(shared_secret);
+        hasher.update(other_info);
+        key.extend_from_slice(&hasher.finalize());
+        counter += 1;
+    }
+    key.truncate(key_length);
+    Ok(key)
+}
+
+/// HMAC nach RFC 2104 mit SHA256 als Hashfunktion:
+/// `H((K ^ opad) || H((K ^ ipad) || data))`. Schlüssel, die länger als ein Block sind, werden
+/// zuvor gehasht.
+///
+/// # Argumente
+/// * `key` - Der Schlüssel beliebiger Länge.
+/// * `data` - Die Daten.
+///
+/// # Rückgabe
+/// * `Vec<u8>` - Der MAC der Länge `SHA256_LENGTH`.
+pub fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut block_key = if key.len() > SHA256_BLOCK_LENGTH {
+        sha256_bytes(key)
+    } else {
+        key.to_vec()
+    };
+    block_key.resize(SHA256_BLOCK_LENGTH, 0);
+
+    let mut inner = Sha256::new();
+    inner.update(
+        block_key
+            .iter()
+            .map(|byte| byte ^ 0x36)
+            .collect::<Vec<u8>>(),
+    );
+    inner.update(data);
+
+    let mut outer = Sha256::new();
+    outer.update(
+        block_key
+            .iter()
+            .map(|byte| byte ^ 0x5c)
+            .collect::<Vec<u8>>(),
+    );
+    outer.update(inner.finalize());
+    outer.finalize().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha256_bytes_known_value() {
+        let hash = sha256_bytes(b"abc");
+        assert_eq!(
+            BigInt::from_bytes_be(Sign::Plus, &hash).to_str_radix(16),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(BigInt::from_bytes_be(Sign::Plus, &hash), sha256("abc"));
+    }
+
+    #[test]
+    fn test_mgf1_sha256_length_and_prefix() {
+        let seed = b"seed";
+        let long_mask = mgf1_sha256(seed, 100);
+        let short_mask = mgf1_sha256(seed, 10);
+        assert_eq!(long_mask.len(), 100);
+        assert_eq!(short_mask.len(), 10);
+        // Die kürzere Maske ist immer ein Präfix der längeren.
+        assert_eq!(&long_mask[..10], &short_mask[..]);
+        // Der erste Block ist Hash(seed || 0x00000000).
+        assert_eq!(
+            &long_mask[..SHA256_LENGTH],
+            &sha256_bytes(b"seed\x00\x00\x00\x00")[..]
+        );
+        assert!(mgf1_sha256(seed, 0).is_empty());
+    }
+
+    #[test]
+    fn test_concat_kdf_sha256() {
+        let key = concat_kdf_sha256(b"secret", b"info", 40).unwrap();
+        assert_eq!(key.len(), 40);
+        // Der erste Block ist Hash(0x00000001 || Z || OtherInfo).
+        assert_eq!(
+            &key[..SHA256_LENGTH],
+            &sha256_bytes(b"\x00\x00\x00\x01secretinfo")[..]
+        );
+        assert_ne!(key, concat_kdf_sha256(b"secret", b"other", 40).unwrap());
+        assert!(concat_kdf_sha256(b"secret", b"info", 0).is_err());
+    }
+
+    #[test]
+    fn test_hmac_sha256_rfc4231() {
+        // Testfall 2 aus RFC 4231
+        let mac = hmac_sha256(b"Jefe", b"what do ya want for nothing?");
+        assert_eq!(
+            BigInt::from_bytes_be(Sign::Plus, &mac).to_str_radix(16),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+        // Testfall 6 aus RFC 4231 mit einem Schlüssel, der länger als ein Block ist
+        let mac = hmac_sha256(
+            &[0xaa; 131],
+            b"Test Using Larger Than Block-Size Key - Hash Key First",
+        );
+        assert_eq!(
+            BigInt::from_bytes_be(Sign::Plus, &mac).to_str_radix(16),
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+        );
+    }
+}
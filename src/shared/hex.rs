@@ -0,0 +1,59 @@
+use anyhow::{ensure, Context, Result};
+
+/// Stellt eine Bytefolge als hexadezimale Zeichenkette mit Kleinbuchstaben dar.
+///
+/// # Argumente
+/// * `bytes` - Die Bytefolge.
+///
+/// # Rückgabe
+/// * `String` - Zwei Hexadezimalziffern pro Byte.
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Liest eine hexadezimale Zeichenkette als Bytefolge ein. Groß- und Kleinschreibung wird
+/// nicht unterschieden.
+///
+/// # Argumente
+/// * `hex` - Die Zeichenkette mit einer geraden Anzahl von Hexadezimalziffern.
+///
+/// # Rückgabe
+/// * `Vec<u8>` - Die Bytefolge.
+///
+/// # Fehler
+/// * Falls die Länge ungerade ist oder ein Zeichen keine Hexadezimalziffer ist.
+pub fn from_hex(hex: &str) -> Result<Vec<u8>> {
+    ensure!(
+        hex.len().is_multiple_of(2),
+        "Hex string must have an even length, but has length {}",
+        hex.len()
+    );
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            hex.get(i..i + 2)
+                .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+                .with_context(|| format!("Invalid hex digits at position {}", i))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let bytes = vec![0x00, 0x0f, 0xa5, 0xff];
+        assert_eq!(to_hex(&bytes), "000fa5ff");
+        assert_eq!(from_hex("000FA5ff").unwrap(), bytes);
+        assert!(from_hex("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(from_hex("abc").is_err());
+        assert!(from_hex("zz").is_err());
+        assert!(from_hex("äa").is_err());
+    }
+}
@@ -1,2 +1,6 @@
+pub mod der;
 pub mod errors;
 pub mod hashing;
+pub mod hex;
+pub mod pem;
+pub mod rfc6979;
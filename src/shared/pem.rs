@@ -0,0 +1,96 @@
+use anyhow::{ensure, Result};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+use crate::shared::errors::KeyEncodingError;
+
+/// Die maximale Zeilenlänge des Base64-Inhalts eines PEM-Blocks (RFC 7468).
+const PEM_LINE_LENGTH: usize = 64;
+
+/// Verpackt DER-Daten in einen PEM-Block (RFC 7468).
+///
+/// # Argumente
+/// * `label` - Das Label des Blocks, z.B. `PUBLIC KEY`.
+/// * `der` - Die DER-kodierten Daten.
+///
+/// # Rückgabe
+/// * `String` - Der PEM-Block inklusive Kopf- und Fußzeile.
+pub fn pem_encode(label: &str, der: &[u8]) -> String {
+    let base64 = STANDARD.encode(der);
+    let mut pem = format!("-----BEGIN {}-----\n", label);
+    // Base64 besteht nur aus ASCII-Zeichen, daher kann byteweise aufgeteilt werden.
+    for line in base64.as_bytes().chunks(PEM_LINE_LENGTH) {
+        pem.push_str(std::str::from_utf8(line).unwrap());
+        pem.push('\n');
+    }
+    pem.push_str(&format!("-----END {}-----\n", label));
+    pem
+}
+
+/// Liest den ersten PEM-Block aus einem Text. Text vor dem Block wird ignoriert.
+///
+/// # Argumente
+/// * `pem` - Der Text, der den PEM-Block enthält.
+///
+/// # Rückgabe
+/// * `(String, Vec<u8>)` - Das Label und die DER-kodierten Daten.
+///
+/// # Fehler
+/// * `KeyEncodingError::InvalidPemError` - Falls kein gültiger PEM-Block gefunden wird.
+pub fn pem_decode(pem: &str) -> Result<(String, Vec<u8>)> {
+    let mut lines = pem.lines().map(str::trim);
+    let label = lines
+        .by_ref()
+        .find_map(|line| {
+            line.strip_prefix("-----BEGIN ")
+                .and_then(|line| line.strip_suffix("-----"))
+        })
+        .ok_or_else(|| KeyEncodingError::InvalidPemError("missing BEGIN line".to_string()))?
+        .to_string();
+
+    let end_line = format!("-----END {}-----", label);
+    let mut base64 = String::new();
+    let mut has_end_line = false;
+    for line in lines {
+        if line == end_line {
+            has_end_line = true;
+            break;
+        }
+        base64.push_str(line);
+    }
+    ensure!(
+        has_end_line,
+        KeyEncodingError::InvalidPemError(format!("missing END line for {}", label))
+    );
+
+    let der = STANDARD
+        .decode(base64)
+        .map_err(|e| KeyEncodingError::InvalidPemError(e.to_string()))?;
+    Ok((label, der))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pem_encode_decode_roundtrip() {
+        let der: Vec<u8> = (0..=255).collect();
+        let pem = pem_encode("TEST DATA", &der);
+
+        assert!(pem.starts_with("-----BEGIN TEST DATA-----\n"));
+        assert!(pem.ends_with("-----END TEST DATA-----\n"));
+        assert!(pem.lines().all(|line| line.len() <= PEM_LINE_LENGTH));
+
+        let (label, decoded) = pem_decode(&format!("Kommentar\r\n{}", pem)).unwrap();
+        assert_eq!(label, "TEST DATA");
+        assert_eq!(decoded, der);
+    }
+
+    #[test]
+    fn test_pem_decode_rejects_invalid_blocks() {
+        assert!(pem_decode("keine PEM-Daten").is_err());
+        assert!(pem_decode("-----BEGIN A-----\nAAAA\n-----END B-----\n").is_err());
+        assert!(pem_decode("-----BEGIN A-----\n!!!!\n-----END A-----\n").is_err());
+    }
+}
@@ -0,0 +1,147 @@
+use bigdecimal::num_bigint::{BigInt, Sign};
+use bigdecimal::Zero;
+
+use crate::math_core::csprng::hmac_drbg::HmacDrbg;
+
+///
+/// Deterministische Erzeugung von Signatur-Nonces nach RFC 6979 (Abschnitt 3.2) mit
+/// HMAC-SHA256. Die Nonce k hängt nur vom privaten Schlüssel und vom Hash der Nachricht ab,
+/// dieselbe Nachricht wird mit demselben Schlüssel also immer mit derselben Nonce signiert und
+/// ein schwacher oder wiederverwendeter Zufallswert kann den Schlüssel nicht mehr preisgeben.
+///
+/// Wird eine Nonce verworfen, z.B. weil r oder s null ist, liefert `next_nonce` den nächsten
+/// Kandidaten aus demselben HMAC-DRBG nach NIST SP 800-90A.
+///
+pub struct DeterministicNonceGenerator {
+    /// Der HMAC-DRBG, instanziiert mit int2octets(x) als Entropie und bits2octets(H(m)) als
+    /// Nonce (RFC 6979, Abschnitt 3.3)
+    drbg: HmacDrbg,
+    /// Die Gruppenordnung q
+    order: BigInt,
+}
+
+impl DeterministicNonceGenerator {
+    /// Initialisiert den HMAC-DRBG mit dem privaten Schlüssel und dem Hash der Nachricht.
+    ///
+    /// # Argumente
+    /// * `private_key` - Der private Schlüssel x mit 0 < x < q.
+    /// * `hashed_message` - Der Hash H(m) der Nachricht.
+    /// * `order` - Die Gruppenordnung q.
+    ///
+    /// # Rückgabe
+    /// * `DeterministicNonceGenerator` - Der Generator.
+    pub fn new(private_key: &BigInt, hashed_message: &[u8], order: &BigInt) -> Self {
+        DeterministicNonceGenerator {
+            drbg: HmacDrbg::new(
+                &int_to_octets(private_key, order),
+                &bits_to_octets(hashed_message, order),
+                &[],
+            ),
+            order: order.clone(),
+        }
+    }
+
+    /// Erzeugt die nächste Nonce k mit 1 <= k < q. Die Zustandsfortschaltung nach jeder
+    /// Ausgabe des HMAC-DRBG entspricht dabei genau dem Schritt K = HMAC_K(V || 0x00),
+    /// V = HMAC_K(V) aus RFC 6979 vor dem nächsten Kandidaten.
+    pub fn next_nonce(&mut self) -> BigInt {
+        let length = self.order.bits().div_ceil(8) as usize;
+        loop {
+            let k = bits_to_int(&self.drbg.generate(length, &[]), &self.order);
+            if !k.is_zero() && k < self.order {
+                return k;
+            }
+        }
+    }
+}
+
+/// bits2int: Interpretiert die Bytes als Zahl und behält davon die obersten qlen Bits.
+fn bits_to_int(bytes: &[u8], order: &BigInt) -> BigInt {
+    let value = BigInt::from_bytes_be(Sign::Plus, bytes);
+    let bit_length = bytes.len() as u64 * 8;
+    let order_bit_length = order.bits();
+    if bit_length > order_bit_length {
+        value >> (bit_length - order_bit_length)
+    } else {
+        value
+    }
+}
+
+/// int2octets: Stellt die Zahl als Big-Endian-Bytes mit der Bytelänge von q dar.
+fn int_to_octets(value: &BigInt, order: &BigInt) -> Vec<u8> {
+    let length = order.bits().div_ceil(8) as usize;
+    let (_, bytes) = value.to_bytes_be();
+    let mut octets = vec![0u8; length.saturating_sub(bytes.len())];
+    octets.extend(&bytes[bytes.len().saturating_sub(length)..]);
+    octets
+}
+
+/// bits2octets: bits2int, anschließend modulo q reduziert und als Bytes dargestellt.
+fn bits_to_octets(bytes: &[u8], order: &BigInt) -> Vec<u8> {
+    let value = bits_to_int(bytes, order);
+    let reduced = if &value >= order {
+        value - order
+    } else {
+        value
+    };
+    int_to_octets(&reduced, order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::hashing::sha256_bytes;
+    use bigdecimal::Num;
+
+    fn hex(value: &str) -> BigInt {
+        BigInt::from_str_radix(value, 16).unwrap()
+    }
+
+    /// Testvektoren aus RFC 6979, Anhang A.2.5 (P-256 mit SHA-256).
+    #[test]
+    fn test_rfc6979_p256_vectors() {
+        let order = hex("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551");
+        let private_key = hex("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721");
+
+        let mut generator =
+            DeterministicNonceGenerator::new(&private_key, &sha256_bytes(b"sample"), &order);
+        assert_eq!(
+            generator.next_nonce(),
+            hex("A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60")
+        );
+
+        let mut generator =
+            DeterministicNonceGenerator::new(&private_key, &sha256_bytes(b"test"), &order);
+        assert_eq!(
+            generator.next_nonce(),
+            hex("D16B6AE827F17175E040871A1C7EC3500192C4C92677336EC2537ACAEE0008E0")
+        );
+    }
+
+    /// Testvektor aus RFC 6979, Anhang A.1.2: q hat nur 163 Bit, der Hash wird gekürzt.
+    #[test]
+    fn test_rfc6979_short_order() {
+        let order = hex("04000000000000000000020108A2E0CC0D99F8A5EF");
+        let private_key = hex("009A4D6792295A7F730FC3F2B49CBC0F62E862272F");
+        let mut generator =
+            DeterministicNonceGenerator::new(&private_key, &sha256_bytes(b"sample"), &order);
+        assert_eq!(
+            generator.next_nonce(),
+            hex("023AF4074C90A02B3FE61D286D5C87F425E6BDD81B")
+        );
+    }
+
+    #[test]
+    fn test_next_nonce_changes_and_stays_in_range() {
+        let order = BigInt::from(1009);
+        let mut generator =
+            DeterministicNonceGenerator::new(&BigInt::from(123), &sha256_bytes(b"msg"), &order);
+        let nonces: Vec<BigInt> = (0..20).map(|_| generator.next_nonce()).collect();
+        assert!(nonces.iter().all(|k| !k.is_zero() && k < &order));
+        assert_ne!(nonces[0], nonces[1]);
+
+        let mut repeated =
+            DeterministicNonceGenerator::new(&BigInt::from(123), &sha256_bytes(b"msg"), &order);
+        assert_eq!(repeated.next_nonce(), nonces[0]);
+    }
+}